@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+# Self-signed CA, server and client certificates for local TLS / mutual TLS tests.
+# Test only: the tests generate their own in a temporary directory, nothing here is committed.
+# usage: bash gen.sh [server name, default localhost] [output directory, default this directory]
+set -e
+NAME=${1:-localhost}
+OUT=${2:-$(dirname "$0")}
+DAYS=${DAYS:-30}
+mkdir -p "$OUT"
+cd "$OUT"
+
+openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:P-256 -out ca.key
+openssl req -x509 -new -key ca.key -days $DAYS -subj "/CN=viz test CA" \
+    -addext "basicConstraints=critical,CA:TRUE" -addext "keyUsage=critical,keyCertSign,cRLSign" -out ca.pem
+
+issue() {
+    local name=$1 subject=$2 ext=$3
+    openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:P-256 -out $name.key
+    openssl req -new -key $name.key -subj "/CN=$subject" -out $name.csr
+    printf "%b" "$ext" > $name.ext
+    openssl x509 -req -in $name.csr -CA ca.pem -CAkey ca.key -CAcreateserial -days $DAYS -extfile $name.ext -out $name.pem
+    rm -f $name.csr $name.ext
+}
+
+issue server $NAME "basicConstraints=CA:FALSE\nkeyUsage=critical,digitalSignature\nextendedKeyUsage=serverAuth\nsubjectAltName=DNS:$NAME,IP:127.0.0.1,IP:::1\n"
+issue client publisher "basicConstraints=CA:FALSE\nkeyUsage=critical,digitalSignature\nextendedKeyUsage=clientAuth\n"
+rm -f ca.srl ca.key
@@ -0,0 +1,42 @@
+use tonic::{Code, Status};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    // unknown subscription
+    NotFound,
+    AlreadyExists,
+    // malformed request, e.g. publish without a message
+    InvalidArgument(String),
+    // certificates and keys
+    Tls(String),
+    Address(String),
+    // connection to the server
+    Transport(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotFound => write!(f, "NotFound"),
+            Error::AlreadyExists => write!(f, "AlreadyExists"),
+            Error::InvalidArgument(message)
+            | Error::Tls(message)
+            | Error::Address(message)
+            | Error::Transport(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for Status {
+    fn from(err: Error) -> Self {
+        let code = match err {
+            Error::NotFound => Code::NotFound,
+            Error::AlreadyExists => Code::AlreadyExists,
+            Error::InvalidArgument(_) | Error::Address(_) => Code::InvalidArgument,
+            Error::Tls(_) | Error::Transport(_) => Code::Unavailable,
+        };
+        Status::new(code, err.to_string())
+    }
+}
@@ -1,2 +1,4 @@
+pub mod error;
+pub mod metrics;
 pub mod pubsub_service;
 pub mod proto;
\ No newline at end of file
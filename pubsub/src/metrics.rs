@@ -0,0 +1,76 @@
+use prometheus::core::Collector;
+use prometheus::{IntCounterVec, IntGaugeVec, Opts};
+use std::collections::HashSet;
+use std::sync::{OnceLock, RwLock};
+
+// Label of topics which are not known, clients can't add labels by publishing to new topics
+pub const OTHER_TOPIC: &str = "other";
+
+// Broker metrics, in prometheus::default_registry() next to the metrics of the embedding server
+pub struct BrokerMetrics {
+    pub published_messages: IntCounterVec,
+    pub published_bytes: IntCounterVec,
+    // messages waiting to be pulled
+    pub queue_depth: IntGaugeVec,
+    // messages dropped because the subscriber fell behind
+    pub dropped_messages: IntCounterVec,
+    // topics with their own label
+    topics: RwLock<HashSet<String>>,
+}
+
+impl BrokerMetrics {
+    fn new() -> Self {
+        Self {
+            published_messages: register(IntCounterVec::new(
+                Opts::new("pubsub_published_messages_total", "Messages published to a topic"),
+                &["topic"],
+            )),
+            published_bytes: register(IntCounterVec::new(
+                Opts::new("pubsub_published_bytes_total", "Data bytes published to a topic"),
+                &["topic"],
+            )),
+            queue_depth: register(IntGaugeVec::new(
+                Opts::new("pubsub_subscription_queue_depth", "Messages waiting in a subscription"),
+                &["subscription"],
+            )),
+            dropped_messages: register(IntCounterVec::new(
+                Opts::new("pubsub_subscription_dropped_messages_total", "Messages dropped because the subscription queue was full"),
+                &["subscription"],
+            )),
+            topics: RwLock::new(HashSet::new()),
+        }
+    }
+
+    // Topics of the embedding server, e.g. the topics of its schemas
+    pub fn add_topics<I: IntoIterator<Item = String>>(&self, topics: I) {
+        if let Ok(mut known) = self.topics.write() {
+            known.extend(topics);
+        }
+    }
+
+    pub fn topic_label<'a>(&self, topic: &'a str) -> &'a str {
+        match self.topics.read() {
+            Ok(known) if known.contains(topic) => topic,
+            _ => OTHER_TOPIC,
+        }
+    }
+
+    pub fn remove_subscription(&self, subscription: &str) {
+        let _ = self.queue_depth.remove_label_values(&[subscription]);
+        let _ = self.dropped_messages.remove_label_values(&[subscription]);
+    }
+}
+
+pub fn metrics() -> &'static BrokerMetrics {
+    static METRICS: OnceLock<BrokerMetrics> = OnceLock::new();
+    METRICS.get_or_init(BrokerMetrics::new)
+}
+
+// Metric names are constants, an error here is a typo
+pub fn register<M: Collector + Clone + 'static>(metric: prometheus::Result<M>) -> M {
+    let metric = metric.expect("invalid metric");
+    if let Err(err) = prometheus::register(Box::new(metric.clone())) {
+        tracing::warn!("metrics: {}", err);
+    }
+    metric
+}
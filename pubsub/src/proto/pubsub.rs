@@ -59,6 +59,10 @@ pub struct PubsubMessage {
     /// contents
     #[prost(bytes = "vec", tag = "2")]
     pub data: ::prost::alloc::vec::Vec<u8>,
+    /// trace of the message from the publisher to the websocket clients,
+    /// 32 hex digits, the broker assigns one if the publisher does not
+    #[prost(string, tag = "3")]
+    pub trace_id: ::prost::alloc::string::String,
 }
 #[doc = r" Generated client implementations."]
 pub mod pubsub_client {
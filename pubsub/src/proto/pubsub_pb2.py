@@ -19,7 +19,7 @@ DESCRIPTOR = _descriptor.FileDescriptor(
   syntax='proto3',
   serialized_options=None,
   create_key=_descriptor._internal_create_key,
-  serialized_pb=b'\n\x0cpubsub.proto\x12\x06pubsub\"\x07\n\x05\x45mpty\"\x15\n\x05Topic\x12\x0c\n\x04name\x18\x01 \x01(\t\"+\n\x0cSubscription\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\r\n\x05topic\x18\x02 \x01(\t\")\n\x19\x44\x65leteSubscriptionRequest\x12\x0c\n\x04name\x18\x01 \x01(\t\"G\n\x0ePublishRequest\x12\r\n\x05topic\x18\x01 \x01(\t\x12&\n\x07message\x18\x02 \x01(\x0b\x32\x15.pubsub.PubsubMessage\"%\n\x0fPublishResponse\x12\x12\n\nmessage_id\x18\x01 \x01(\t\"#\n\x0bPullRequest\x12\x14\n\x0csubscription\x18\x01 \x01(\t\"6\n\x0cPullResponse\x12&\n\x07message\x18\x01 \x01(\x0b\x32\x15.pubsub.PubsubMessage\",\n\x14StreamingPullRequest\x12\x14\n\x0csubscription\x18\x01 \x01(\t\"?\n\x15StreamingPullResponse\x12&\n\x07message\x18\x01 \x01(\x0b\x32\x15.pubsub.PubsubMessage\"0\n\rPubsubMessage\x12\x11\n\ttimestamp\x18\x01 \x01(\x04\x12\x0c\n\x04\x64\x61ta\x18\x02 \x01(\x0c\x32\xdb\x02\n\x06Pubsub\x12\x42\n\x12\x43reateSubscription\x12\x14.pubsub.Subscription\x1a\x14.pubsub.Subscription\"\x00\x12H\n\x12\x44\x65leteSubscription\x12!.pubsub.DeleteSubscriptionRequest\x1a\r.pubsub.Empty\"\x00\x12<\n\x07Publish\x12\x16.pubsub.PublishRequest\x1a\x17.pubsub.PublishResponse\"\x00\x12\x33\n\x04Pull\x12\x13.pubsub.PullRequest\x1a\x14.pubsub.PullResponse\"\x00\x12P\n\rStreamingPull\x12\x1c.pubsub.StreamingPullRequest\x1a\x1d.pubsub.StreamingPullResponse\"\x00\x30\x01\x62\x06proto3'
+  serialized_pb=b'\n\x0cpubsub.proto\x12\x06pubsub\"\x07\n\x05\x45mpty\"\x15\n\x05Topic\x12\x0c\n\x04name\x18\x01 \x01(\t\"+\n\x0cSubscription\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\r\n\x05topic\x18\x02 \x01(\t\")\n\x19\x44\x65leteSubscriptionRequest\x12\x0c\n\x04name\x18\x01 \x01(\t\"G\n\x0ePublishRequest\x12\r\n\x05topic\x18\x01 \x01(\t\x12&\n\x07message\x18\x02 \x01(\x0b\x32\x15.pubsub.PubsubMessage\"%\n\x0fPublishResponse\x12\x12\n\nmessage_id\x18\x01 \x01(\t\"#\n\x0bPullRequest\x12\x14\n\x0csubscription\x18\x01 \x01(\t\"6\n\x0cPullResponse\x12&\n\x07message\x18\x01 \x01(\x0b\x32\x15.pubsub.PubsubMessage\",\n\x14StreamingPullRequest\x12\x14\n\x0csubscription\x18\x01 \x01(\t\"?\n\x15StreamingPullResponse\x12&\n\x07message\x18\x01 \x01(\x0b\x32\x15.pubsub.PubsubMessage\"B\n\rPubsubMessage\x12\x11\n\ttimestamp\x18\x01 \x01(\x04\x12\x0c\n\x04\x64\x61ta\x18\x02 \x01(\x0c\x12\x10\n\x08trace_id\x18\x03 \x01(\t2\xdb\x02\n\x06Pubsub\x12\x42\n\x12\x43reateSubscription\x12\x14.pubsub.Subscription\x1a\x14.pubsub.Subscription\"\x00\x12H\n\x12\x44\x65leteSubscription\x12!.pubsub.DeleteSubscriptionRequest\x1a\r.pubsub.Empty\"\x00\x12<\n\x07Publish\x12\x16.pubsub.PublishRequest\x1a\x17.pubsub.PublishResponse\"\x00\x12\x33\n\x04Pull\x12\x13.pubsub.PullRequest\x1a\x14.pubsub.PullResponse\"\x00\x12P\n\rStreamingPull\x12\x1c.pubsub.StreamingPullRequest\x1a\x1d.pubsub.StreamingPullResponse\"\x00\x30\x01\x62\x06proto3'
 )
 
 
@@ -374,6 +374,13 @@ _PUBSUBMESSAGE = _descriptor.Descriptor(
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='trace_id', full_name='pubsub.PubsubMessage.trace_id', index=2,
+      number=3, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
   ],
   extensions=[
   ],
@@ -387,7 +394,7 @@ _PUBSUBMESSAGE = _descriptor.Descriptor(
   oneofs=[
   ],
   serialized_start=460,
-  serialized_end=508,
+  serialized_end=526,
 )
 
 _PUBLISHREQUEST.fields_by_name['message'].message_type = _PUBSUBMESSAGE
@@ -492,8 +499,8 @@ _PUBSUB = _descriptor.ServiceDescriptor(
   index=0,
   serialized_options=None,
   create_key=_descriptor._internal_create_key,
-  serialized_start=511,
-  serialized_end=858,
+  serialized_start=529,
+  serialized_end=876,
   methods=[
   _descriptor.MethodDescriptor(
     name='CreateSubscription',
@@ -4,8 +4,12 @@ use tokio::sync::{mpsc, Mutex, Notify, RwLock};
 pub use tonic::{Request, Response, Status};
 use tokio_stream;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::{debug, error, info, info_span, trace, warn, Instrument};
 use uuid::Uuid;
 
+pub use crate::error::Error;
+use crate::metrics::metrics;
 use crate::proto::pubsub as rpc;
 use rpc::pubsub_client::PubsubClient;
 use rpc::pubsub_server::Pubsub;
@@ -39,9 +43,7 @@ impl Pubsub for PubsubService {
             .await
         {
             Ok(_) => Ok(Response::new(subscription)),
-            Err(e) => {
-                Err(Status::new(tonic::Code::AlreadyExists, e.to_string()))
-            }
+            Err(e) => Err(e.into()),
         }
     }
 
@@ -66,12 +68,20 @@ impl Pubsub for PubsubService {
 
         let req = request.into_inner();
 
-        let res = self.ctx.publish(&req.topic, req.message.unwrap()).await;
+        let topic = req.topic;
+        let mut message = req
+            .message
+            .ok_or_else(|| Error::InvalidArgument(format!("publish to {} without a message", topic)))?;
+        if message.trace_id.is_empty() {
+            message.trace_id = new_trace_id();
+        }
+        trace!(topic = %topic, trace_id = %message.trace_id, timestamp = message.timestamp, "publish");
+        let res = self.ctx.publish(&topic, message).await;
         match res {
             Ok(_) => Ok(Response::new(rpc::PublishResponse {
                 message_id: Uuid::new_v4().to_hyphenated().to_string(),
             })),
-            Err(e) => Err(Status::new(tonic::Code::NotFound, e.to_string())),
+            Err(e) => Err(e.into()),
         }
     }
 
@@ -86,7 +96,7 @@ impl Pubsub for PubsubService {
         let res = self.ctx.pull(&req.subscription).await;
         match res {
             Ok(data) => Ok(Response::new(rpc::PullResponse { message: data })),
-            Err(e) => Err(Status::new(tonic::Code::NotFound, e.to_string())),
+            Err(e) => Err(e.into()),
         }
     }
 
@@ -109,16 +119,17 @@ impl Pubsub for PubsubService {
 
         let (tx, rx) = mpsc::channel(10);
         let ctx = Arc::clone(&self.ctx);
+        let span = info_span!("subscription", id = %req.subscription);
         tokio::spawn(async move {
             loop {
-                let res = ctx.pull(&req.subscription).await;
-                if res.is_err() {
-                    // NotFound
-                    println!("subscription not found");
-                    break;
-                }
-
-                let data = res.unwrap();
+                let data = match ctx.pull(&req.subscription).await {
+                    Ok(data) => data,
+                    Err(_) => {
+                        // NotFound
+                        warn!("subscription not found");
+                        break;
+                    }
+                };
                 if data.is_none() {
                     // data is none when
                     // - subscription.detached is true
@@ -127,7 +138,7 @@ impl Pubsub for PubsubService {
                 } else {
                     let response = rpc::StreamingPullResponse { message: data };
                     let res = tx.send(Ok(response)).await.map_err(|err| {
-                        eprintln!("StreamingPull: Send Err");
+                        debug!("StreamingPull: client went away");
                         err
                     });
                     if res.is_err() {
@@ -135,9 +146,9 @@ impl Pubsub for PubsubService {
                     }
                 }
             }
-        });
+        }.instrument(span));
 
-        println!("StremingPull: Response");
+        debug!("StreamingPull: Response");
         Ok(Response::new(tokio_stream::wrappers::ReceiverStream::new(
             rx,
         )))
@@ -159,20 +170,11 @@ impl PubsubService {
 pub type TopicId = String;
 pub type SubscriptionId = String;
 
-
-#[derive(Debug)]
-pub enum Error {
-    NotFound,
-    AlreadyExists,
-}
-
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
-    }
+// 32 hex digits, PubsubMessage.trace_id
+pub fn new_trace_id() -> String {
+    Uuid::new_v4().to_simple().to_string()
 }
 
-impl std::error::Error for Error {}
 
 pub struct Subscription {
     topic: TopicId,
@@ -253,6 +255,7 @@ impl PubsubContext {
         };
 
         self.subscriptions.write().await.remove(subscription_id);
+        metrics().remove_subscription(subscription_id);
         
         if let Some(sub_ids) = self.topics.write().await.get_mut(&topic) {
             sub_ids.remove(subscription_id);
@@ -278,6 +281,9 @@ impl PubsubContext {
     }
 
     pub async fn publish(&self, topic_id: &str, message: rpc::PubsubMessage) -> Result<(), Error> {
+        let label = metrics().topic_label(topic_id);
+        metrics().published_messages.with_label_values(&[label]).inc();
+        metrics().published_bytes.with_label_values(&[label]).inc_by(message.data.len() as u64);
 
         if let Some(sub_ids) = self.topics.read().await.get(topic_id) {
             //sub_ids.to_owned().into_iter().collect::<Vec<String>>()
@@ -290,10 +296,12 @@ impl PubsubContext {
     
                 let mut messages = sub.messages.lock().await;
                 if messages.len() > 100 {
+                    metrics().dropped_messages.with_label_values(&[id]).inc();
                     continue;
                 }
     
                 messages.push_back(message.clone());
+                metrics().queue_depth.with_label_values(&[id]).set(messages.len() as i64);
                 sub.notify.notify_one();    
             }
         }
@@ -311,9 +319,12 @@ impl PubsubContext {
                 return Ok(None);
             }
 
-            if let Some(message) = sub.messages.lock().await.pop_front() {
+            let mut messages = sub.messages.lock().await;
+            if let Some(message) = messages.pop_front() {
+                metrics().queue_depth.with_label_values(&[subscription_id]).set(messages.len() as i64);
                 return Ok(Some(message));
             }
+            drop(messages);
 
             sub.notify.notified().await;
         }
@@ -322,28 +333,118 @@ impl PubsubContext {
 
 
 
+// PEM files of the gRPC server
+#[derive(Debug, Clone)]
+pub struct ServerTls {
+    pub cert: String,
+    pub key: String,
+    // require client certificates signed by this CA (mutual TLS)
+    pub client_ca: Option<String>,
+}
+
+impl ServerTls {
+    fn load(&self) -> Result<tonic::transport::ServerTlsConfig, Error> {
+        let identity = tonic::transport::Identity::from_pem(read_pem(&self.cert)?, read_pem(&self.key)?);
+        let mut config = tonic::transport::ServerTlsConfig::new().identity(identity);
+        if let Some(client_ca) = &self.client_ca {
+            config = config.client_ca_root(tonic::transport::Certificate::from_pem(read_pem(client_ca)?));
+        }
+        Ok(config)
+    }
+}
+
+// PEM files of a gRPC client
+#[derive(Debug, Clone)]
+pub struct ClientTls {
+    // CA of the server certificate
+    pub ca: String,
+    // name in the server certificate
+    pub domain: String,
+    // certificate and key, for servers which require mutual TLS
+    pub identity: Option<(String, String)>,
+}
+
+impl ClientTls {
+    fn load(&self) -> Result<tonic::transport::ClientTlsConfig, Error> {
+        let mut config = tonic::transport::ClientTlsConfig::new()
+            .ca_certificate(tonic::transport::Certificate::from_pem(read_pem(&self.ca)?))
+            .domain_name(self.domain.clone());
+        if let Some((cert, key)) = &self.identity {
+            config = config.identity(tonic::transport::Identity::from_pem(read_pem(cert)?, read_pem(key)?));
+        }
+        Ok(config)
+    }
+}
+
+fn read_pem(path: &str) -> Result<Vec<u8>, Error> {
+    std::fs::read(path).map_err(|err| Error::Tls(format!("Error: {} {}", path, err)))
+}
+
 pub struct Server;
 
 impl Server {
-    pub fn start(address: &str) {
-        let addr = address.parse().expect("Err: parse address");
+    pub fn start(address: &str) -> Result<(), Error> {
+        Self::serve(address, tonic::transport::Server::builder())
+    }
+
+    // TLS, mutual TLS if tls.client_ca is set
+    pub fn start_tls(address: &str, tls: &ServerTls) -> Result<(), Error> {
+        let builder = tonic::transport::Server::builder()
+            .tls_config(tls.load()?)
+            .map_err(|err| Error::Tls(format!("Error: grpc tls config {}", err)))?;
+        Self::serve(address, builder)
+    }
+
+    fn serve(address: &str, builder: tonic::transport::Server) -> Result<(), Error> {
+        let addr = address
+            .parse()
+            .map_err(|err| Error::Address(format!("Error: address {} {}", address, err)))?;
         let service = PubsubService::new();
 
         let _jh = tokio::spawn(async move {
-            println!("start grpc server thread");
-            let _res = tonic::transport::Server::builder()
+            info!("start grpc server on {}", addr);
+            let res = builder
                 .http2_keepalive_interval(Some(std::time::Duration::from_secs(10)))
                 .add_service(rpc::pubsub_server::PubsubServer::new(service))
                 .serve(addr)
-                .await
-                .expect("Error: Start grpc server");
+                .await;
+            if let Err(err) = res {
+                error!("grpc server {}", err);
+            }
         });
+        Ok(())
+    }
+}
+
+// Connection of a client as seen by its subscribe streams
+#[derive(Default)]
+struct ClientState {
+    connected: AtomicBool,
+    // subscriptions with a running stream
+    streaming: std::sync::Mutex<HashSet<String>>,
+}
+
+impl ClientState {
+    fn connected() -> Arc<Self> {
+        let state = Self::default();
+        state.connected.store(true, Ordering::SeqCst);
+        Arc::new(state)
+    }
+
+    fn set_streaming(&self, subscription: &str, streaming: bool) {
+        let mut subs = self.streaming.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        if streaming {
+            subs.insert(subscription.into());
+        } else {
+            subs.remove(subscription);
+        }
     }
 }
 
 pub struct Client {
     client: PubsubClient<tonic::transport::Channel>,
     subs: Vec<String>,
+    state: Arc<ClientState>,
 }
 
 impl Client {
@@ -353,9 +454,36 @@ impl Client {
         Ok(Self {
             client,
             subs: Vec::new(),
+            state: ClientState::connected(),
         })
     }
 
+    // address: https://host:port
+    pub async fn connect_tls(address: String, tls: &ClientTls) -> Result<Client, Error> {
+        let channel = tonic::transport::Channel::from_shared(address.clone())
+            .map_err(|err| Error::Address(format!("Error: {} {}", address, err)))?
+            .tls_config(tls.load()?)
+            .map_err(|err| Error::Tls(format!("Error: grpc tls config {}", err)))?
+            .connect()
+            .await
+            .map_err(|err| Error::Transport(format!("Error: connect {} {}", address, err)))?;
+
+        Ok(Self {
+            client: PubsubClient::new(channel),
+            subs: Vec::new(),
+            state: ClientState::connected(),
+        })
+    }
+
+    // Client on the same connection without the subscriptions of this one, e.g. to publish from another task
+    pub fn handle(&self) -> Client {
+        Self {
+            client: self.client.clone(),
+            subs: Vec::new(),
+            state: self.state.clone(),
+        }
+    }
+
     /* 
     pub async fn create_topic(&mut self, name: &str) -> Result<(), tonic::Status> {
         // create topic
@@ -442,28 +570,57 @@ impl Client {
             subscription: subscription.into(),
         });
 
-        let mut stream = self.client.streaming_pull(request).await?.into_inner();
+        let mut stream = match self.client.streaming_pull(request).await {
+            Ok(res) => res.into_inner(),
+            Err(status) => {
+                self.state.connected.store(false, Ordering::SeqCst);
+                return Err(status);
+            }
+        };
+        let state = self.state.clone();
+        let subscription = subscription.to_string();
+        state.connected.store(true, Ordering::SeqCst);
+        state.set_streaming(&subscription, true);
         
+        // the callback runs in the span of the caller, e.g. with the topic
         let _jh = tokio::spawn({ 
             async move {        
                 while let Some(stream) = stream.next().await {
                     match stream {
                         Ok(response) => {
+                            state.connected.store(true, Ordering::SeqCst);
                             if let Some(message) = response.message {
                                 callback(Ok(message));
                             }
                         }
                         Err(e) => {
+                            state.connected.store(false, Ordering::SeqCst);
                             callback(Err(e));
                         }
                     }
                 }
+                // deleted subscription or lost connection
+                state.set_streaming(&subscription, false);
             }
-        });
+        }.in_current_span());
 
         Ok(())
     }
 
+    // false after a subscribe stream failed, until a stream gets a message again
+    pub fn is_connected(&self) -> bool {
+        self.state.connected.load(Ordering::SeqCst)
+    }
+
+    // a subscribe stream of the subscription is running
+    pub fn is_streaming(&self, subscription: &str) -> bool {
+        self.state
+            .streaming
+            .lock()
+            .map(|subs| subs.contains(subscription))
+            .unwrap_or(false)
+    }
+
     pub async fn close(&mut self) -> Result<(), tonic::Status>{
         while let Some(name) = self.subs.pop() {
             let _res = self.delete_subscription(&name).await;
@@ -477,9 +634,62 @@ mod tests {
     use super::*;
     use std::time;
 
+    // certs/gen.sh output in a temporary directory, once per test run
+    fn cert(name: &str) -> String {
+        static DIR: std::sync::OnceLock<std::path::PathBuf> = std::sync::OnceLock::new();
+        let dir = DIR.get_or_init(|| {
+            let dir = std::env::temp_dir().join(format!("pubsub-certs-{}", std::process::id()));
+            let output = std::process::Command::new("bash")
+                .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/certs/gen.sh"))
+                .arg("localhost")
+                .arg(&dir)
+                .output()
+                .expect("bash");
+            assert!(output.status.success(), "gen.sh: {}", String::from_utf8_lossy(&output.stderr));
+            dir
+        });
+        dir.join(name).to_string_lossy().into_owned()
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn mutual_tls_test() {
+        let tls = ServerTls {
+            cert: cert("server.pem"),
+            key: cert("server.key"),
+            client_ca: Some(cert("ca.pem")),
+        };
+        Server::start_tls("[::1]:50052", &tls).unwrap();
+        tokio::time::sleep(time::Duration::from_millis(100)).await;
+
+        let mut client_tls = ClientTls {
+            ca: cert("ca.pem"),
+            domain: "localhost".into(),
+            identity: Some((cert("client.pem"), cert("client.key"))),
+        };
+        let mut client = Client::connect_tls("https://[::1]:50052".into(), &client_tls).await.unwrap();
+        assert!(client.create_subscription("topic_tls", "sub_tls").await.is_ok());
+
+        // no client certificate: rejected at the handshake or the first request
+        client_tls.identity = None;
+        let accepted = match Client::connect_tls("https://[::1]:50052".into(), &client_tls).await {
+            Ok(mut client) => client.create_subscription("topic_tls", "sub_tls_2").await.is_ok(),
+            Err(_) => false,
+        };
+        assert!(!accepted);
+
+        // plaintext client
+        let accepted = match Client::connect("http://[::1]:50052".into()).await {
+            Ok(mut client) => client.create_subscription("topic_tls", "sub_tls_3").await.is_ok(),
+            Err(_) => false,
+        };
+        assert!(!accepted);
+
+        assert!(Server::start_tls("[::1]:50053", &ServerTls { cert: cert("missing.pem"), ..tls }).is_err());
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn client_test() {
-        Server::start("[::1]:50051");
+        Server::start("[::1]:50051").unwrap();
         tokio::time::sleep(time::Duration::from_millis(100)).await;
 
         let mut client = Client::connect("http://[::1]:50051".to_string())
@@ -524,6 +734,7 @@ mod tests {
                 rpc::PubsubMessage {
                     timestamp: 0,
                     data: "topic_1 message".as_bytes().to_owned(),
+                    ..Default::default()
                 },
             )
             .await;
@@ -539,6 +750,8 @@ mod tests {
             "topic_1 message",
             std::str::from_utf8(&message.data).unwrap()
         );
+        // assigned by the broker
+        assert_eq!(message.trace_id.len(), 32);
 
         let res = client.pull(sub_2).await;
         assert!(res.is_ok());
@@ -558,6 +771,7 @@ mod tests {
                     rpc::PubsubMessage {
                         timestamp: 0,
                         data: format!("topic_1 message {}", i).as_bytes().to_owned(),
+                        ..Default::default()
                     },
                 )
                 .await;
@@ -581,6 +795,16 @@ mod tests {
             })
             .await;
         assert!(res.is_ok());
+        assert!(client.is_connected());
+        assert!(client.is_streaming(sub_1));
+        assert!(!client.is_streaming(sub_2));
+
+        // publish on another handle of the connection
+        let res = client
+            .handle()
+            .publish(topic_2, rpc::PubsubMessage { timestamp: 1, ..Default::default() })
+            .await;
+        assert!(res.is_ok());
 
         // nop 
         tokio::time::sleep(time::Duration::from_millis(300)).await;
@@ -590,6 +814,8 @@ mod tests {
         assert!(res.is_ok());
         let res = client.delete_subscription(sub_2).await;
         assert!(res.is_ok());
+        tokio::time::sleep(time::Duration::from_millis(100)).await;
+        assert!(!client.is_streaming(sub_1));
 
         // streaming pull, cancel test 
         let res = client
@@ -654,6 +880,7 @@ mod tests {
             let message = rpc::PubsubMessage {
                 timestamp: 0,
                 data: format!("Hello {}", i).as_bytes().to_owned(),
+                trace_id: new_trace_id(),
             };
             let _res = service.ctx.publish(topic_1, message).await;
         }
@@ -681,4 +908,45 @@ mod tests {
         );
         assert!(service.ctx.subscriptions.read().await.get(sub_2).is_none());
     }
+
+    #[tokio::test]
+    async fn malformed_request_test() {
+        let service = PubsubService::new();
+        let res = service
+            .publish(Request::new(rpc::PublishRequest { topic: "topic_1".into(), message: None }))
+            .await;
+        assert_eq!(res.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        let res = service.pull(Request::new(rpc::PullRequest { subscription: "sub_1".into() })).await;
+        assert_eq!(res.unwrap_err().code(), tonic::Code::NotFound);
+
+        assert!(matches!(Server::start("not an address"), Err(Error::Address(_))));
+    }
+
+    #[tokio::test]
+    async fn metrics_test() {
+        let service = PubsubService::new();
+        let (topic, sub) = ("metrics_topic", "metrics_sub");
+        metrics().add_topics(vec![topic.to_string()]);
+        let _res = service.ctx.create_subscription(topic, sub).await;
+        for _ in 0..103 {
+            let message = rpc::PubsubMessage { data: vec![0; 10], ..Default::default() };
+            let _res = service.ctx.publish(topic, message).await;
+        }
+        let _res = service.ctx.pull(sub).await;
+
+        let metrics = metrics();
+        assert_eq!(metrics.published_messages.with_label_values(&[topic]).get(), 103);
+        assert_eq!(metrics.published_bytes.with_label_values(&[topic]).get(), 1030);
+
+        // unknown topics share one label
+        let _res = service.ctx.publish("metrics_unknown_topic", rpc::PubsubMessage::default()).await;
+        assert!(metrics.published_messages.with_label_values(&[crate::metrics::OTHER_TOPIC]).get() >= 1);
+        assert!(metrics.published_messages.remove_label_values(&["metrics_unknown_topic"]).is_err());
+        assert_eq!(metrics.queue_depth.with_label_values(&[sub]).get(), 100);
+        assert_eq!(metrics.dropped_messages.with_label_values(&[sub]).get(), 2);
+
+        service.ctx.delete_subscription(sub).await;
+        assert!(metrics.queue_depth.remove_label_values(&[sub]).is_err());
+    }
 }
@@ -187,6 +187,10 @@ pub struct Image {
     // message fields
     pub data: ::std::vec::Vec<u8>,
     pub mime_type: ::std::string::String,
+    pub width: u32,
+    pub height: u32,
+    pub encoding: ImageEncoding,
+    pub stride: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -254,6 +258,66 @@ impl Image {
     pub fn take_mime_type(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.mime_type, ::std::string::String::new())
     }
+
+    // uint32 width = 3;
+
+
+    pub fn get_width(&self) -> u32 {
+        self.width
+    }
+    pub fn clear_width(&mut self) {
+        self.width = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_width(&mut self, v: u32) {
+        self.width = v;
+    }
+
+    // uint32 height = 4;
+
+
+    pub fn get_height(&self) -> u32 {
+        self.height
+    }
+    pub fn clear_height(&mut self) {
+        self.height = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_height(&mut self, v: u32) {
+        self.height = v;
+    }
+
+    // .viz.ImageEncoding encoding = 5;
+
+
+    pub fn get_encoding(&self) -> ImageEncoding {
+        self.encoding
+    }
+    pub fn clear_encoding(&mut self) {
+        self.encoding = ImageEncoding::ENCODED;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encoding(&mut self, v: ImageEncoding) {
+        self.encoding = v;
+    }
+
+    // uint32 stride = 6;
+
+
+    pub fn get_stride(&self) -> u32 {
+        self.stride
+    }
+    pub fn clear_stride(&mut self) {
+        self.stride = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stride(&mut self, v: u32) {
+        self.stride = v;
+    }
 }
 
 impl ::protobuf::Message for Image {
@@ -271,6 +335,30 @@ impl ::protobuf::Message for Image {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.mime_type)?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.width = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.height = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.encoding, 5, &mut self.unknown_fields)?
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.stride = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -289,6 +377,18 @@ impl ::protobuf::Message for Image {
         if !self.mime_type.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.mime_type);
         }
+        if self.width != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.width, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.encoding != ImageEncoding::ENCODED {
+            my_size += ::protobuf::rt::enum_size(5, self.encoding);
+        }
+        if self.stride != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.stride, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -301,6 +401,18 @@ impl ::protobuf::Message for Image {
         if !self.mime_type.is_empty() {
             os.write_string(2, &self.mime_type)?;
         }
+        if self.width != 0 {
+            os.write_uint32(3, self.width)?;
+        }
+        if self.height != 0 {
+            os.write_uint32(4, self.height)?;
+        }
+        if self.encoding != ImageEncoding::ENCODED {
+            os.write_enum(5, ::protobuf::ProtobufEnum::value(&self.encoding))?;
+        }
+        if self.stride != 0 {
+            os.write_uint32(6, self.stride)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -349,6 +461,26 @@ impl ::protobuf::Message for Image {
                 |m: &Image| { &m.mime_type },
                 |m: &mut Image| { &mut m.mime_type },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "width",
+                |m: &Image| { &m.width },
+                |m: &mut Image| { &mut m.width },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "height",
+                |m: &Image| { &m.height },
+                |m: &mut Image| { &mut m.height },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ImageEncoding>>(
+                "encoding",
+                |m: &Image| { &m.encoding },
+                |m: &mut Image| { &mut m.encoding },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "stride",
+                |m: &Image| { &m.stride },
+                |m: &mut Image| { &mut m.stride },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<Image>(
                 "Image",
                 fields,
@@ -367,6 +499,10 @@ impl ::protobuf::Clear for Image {
     fn clear(&mut self) {
         self.data.clear();
         self.mime_type.clear();
+        self.width = 0;
+        self.height = 0;
+        self.encoding = ImageEncoding::ENCODED;
+        self.stride = 0;
         self.unknown_fields.clear();
     }
 }
@@ -792,43 +928,2289 @@ impl ::protobuf::reflect::ProtobufValue for Point3d {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct Color {
+    // message fields
+    pub r: u32,
+    pub g: u32,
+    pub b: u32,
+    pub a: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Color {
+    fn default() -> &'a Color {
+        <Color as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Color {
+    pub fn new() -> Color {
+        ::std::default::Default::default()
+    }
+
+    // uint32 r = 1;
+
+
+    pub fn get_r(&self) -> u32 {
+        self.r
+    }
+    pub fn clear_r(&mut self) {
+        self.r = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_r(&mut self, v: u32) {
+        self.r = v;
+    }
+
+    // uint32 g = 2;
+
+
+    pub fn get_g(&self) -> u32 {
+        self.g
+    }
+    pub fn clear_g(&mut self) {
+        self.g = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_g(&mut self, v: u32) {
+        self.g = v;
+    }
+
+    // uint32 b = 3;
+
+
+    pub fn get_b(&self) -> u32 {
+        self.b
+    }
+    pub fn clear_b(&mut self) {
+        self.b = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_b(&mut self, v: u32) {
+        self.b = v;
+    }
+
+    // uint32 a = 4;
+
+
+    pub fn get_a(&self) -> u32 {
+        self.a
+    }
+    pub fn clear_a(&mut self) {
+        self.a = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_a(&mut self, v: u32) {
+        self.a = v;
+    }
+}
+
+impl ::protobuf::Message for Color {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.r = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.g = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.b = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.a = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.r != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.r, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.g != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.g, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.b != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.b, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.a != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.a, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.r != 0 {
+            os.write_uint32(1, self.r)?;
+        }
+        if self.g != 0 {
+            os.write_uint32(2, self.g)?;
+        }
+        if self.b != 0 {
+            os.write_uint32(3, self.b)?;
+        }
+        if self.a != 0 {
+            os.write_uint32(4, self.a)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Color {
+        Color::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "r",
+                |m: &Color| { &m.r },
+                |m: &mut Color| { &mut m.r },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "g",
+                |m: &Color| { &m.g },
+                |m: &mut Color| { &mut m.g },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "b",
+                |m: &Color| { &m.b },
+                |m: &mut Color| { &mut m.b },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "a",
+                |m: &Color| { &m.a },
+                |m: &mut Color| { &mut m.a },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Color>(
+                "Color",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Color {
+        static instance: ::protobuf::rt::LazyV2<Color> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Color::new)
+    }
+}
+
+impl ::protobuf::Clear for Color {
+    fn clear(&mut self) {
+        self.r = 0;
+        self.g = 0;
+        self.b = 0;
+        self.a = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Color {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Color {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BoundingBox2d {
+    // message fields
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub color: ::protobuf::SingularPtrField<Color>,
+    pub label: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BoundingBox2d {
+    fn default() -> &'a BoundingBox2d {
+        <BoundingBox2d as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BoundingBox2d {
+    pub fn new() -> BoundingBox2d {
+        ::std::default::Default::default()
+    }
+
+    // float x = 1;
+
+
+    pub fn get_x(&self) -> f32 {
+        self.x
+    }
+    pub fn clear_x(&mut self) {
+        self.x = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_x(&mut self, v: f32) {
+        self.x = v;
+    }
+
+    // float y = 2;
+
+
+    pub fn get_y(&self) -> f32 {
+        self.y
+    }
+    pub fn clear_y(&mut self) {
+        self.y = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_y(&mut self, v: f32) {
+        self.y = v;
+    }
+
+    // float width = 3;
+
+
+    pub fn get_width(&self) -> f32 {
+        self.width
+    }
+    pub fn clear_width(&mut self) {
+        self.width = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_width(&mut self, v: f32) {
+        self.width = v;
+    }
+
+    // float height = 4;
+
+
+    pub fn get_height(&self) -> f32 {
+        self.height
+    }
+    pub fn clear_height(&mut self) {
+        self.height = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_height(&mut self, v: f32) {
+        self.height = v;
+    }
+
+    // .viz.Color color = 5;
+
+
+    pub fn get_color(&self) -> &Color {
+        self.color.as_ref().unwrap_or_else(|| <Color as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_color(&mut self) {
+        self.color.clear();
+    }
+
+    pub fn has_color(&self) -> bool {
+        self.color.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color(&mut self, v: Color) {
+        self.color = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_color(&mut self) -> &mut Color {
+        if self.color.is_none() {
+            self.color.set_default();
+        }
+        self.color.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_color(&mut self) -> Color {
+        self.color.take().unwrap_or_else(|| Color::new())
+    }
+
+    // string label = 6;
+
+
+    pub fn get_label(&self) -> &str {
+        &self.label
+    }
+    pub fn clear_label(&mut self) {
+        self.label.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_label(&mut self, v: ::std::string::String) {
+        self.label = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_label(&mut self) -> &mut ::std::string::String {
+        &mut self.label
+    }
+
+    // Take field
+    pub fn take_label(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.label, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for BoundingBox2d {
+    fn is_initialized(&self) -> bool {
+        for v in &self.color {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.x = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.y = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.width = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.height = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.color)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.label)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.x != 0. {
+            my_size += 5;
+        }
+        if self.y != 0. {
+            my_size += 5;
+        }
+        if self.width != 0. {
+            my_size += 5;
+        }
+        if self.height != 0. {
+            my_size += 5;
+        }
+        if let Some(ref v) = self.color.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.label.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.label);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.x != 0. {
+            os.write_float(1, self.x)?;
+        }
+        if self.y != 0. {
+            os.write_float(2, self.y)?;
+        }
+        if self.width != 0. {
+            os.write_float(3, self.width)?;
+        }
+        if self.height != 0. {
+            os.write_float(4, self.height)?;
+        }
+        if let Some(ref v) = self.color.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.label.is_empty() {
+            os.write_string(6, &self.label)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BoundingBox2d {
+        BoundingBox2d::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
+                "x",
+                |m: &BoundingBox2d| { &m.x },
+                |m: &mut BoundingBox2d| { &mut m.x },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
+                "y",
+                |m: &BoundingBox2d| { &m.y },
+                |m: &mut BoundingBox2d| { &mut m.y },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
+                "width",
+                |m: &BoundingBox2d| { &m.width },
+                |m: &mut BoundingBox2d| { &mut m.width },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
+                "height",
+                |m: &BoundingBox2d| { &m.height },
+                |m: &mut BoundingBox2d| { &mut m.height },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Color>>(
+                "color",
+                |m: &BoundingBox2d| { &m.color },
+                |m: &mut BoundingBox2d| { &mut m.color },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "label",
+                |m: &BoundingBox2d| { &m.label },
+                |m: &mut BoundingBox2d| { &mut m.label },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BoundingBox2d>(
+                "BoundingBox2d",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BoundingBox2d {
+        static instance: ::protobuf::rt::LazyV2<BoundingBox2d> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BoundingBox2d::new)
+    }
+}
+
+impl ::protobuf::Clear for BoundingBox2d {
+    fn clear(&mut self) {
+        self.x = 0.;
+        self.y = 0.;
+        self.width = 0.;
+        self.height = 0.;
+        self.color.clear();
+        self.label.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BoundingBox2d {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BoundingBox2d {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Polygon2d {
+    // message fields
+    pub points: ::protobuf::RepeatedField<Point2d>,
+    pub color: ::protobuf::SingularPtrField<Color>,
+    pub label: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Polygon2d {
+    fn default() -> &'a Polygon2d {
+        <Polygon2d as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Polygon2d {
+    pub fn new() -> Polygon2d {
+        ::std::default::Default::default()
+    }
+
+    // repeated .viz.Point2d points = 1;
+
+
+    pub fn get_points(&self) -> &[Point2d] {
+        &self.points
+    }
+    pub fn clear_points(&mut self) {
+        self.points.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_points(&mut self, v: ::protobuf::RepeatedField<Point2d>) {
+        self.points = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_points(&mut self) -> &mut ::protobuf::RepeatedField<Point2d> {
+        &mut self.points
+    }
+
+    // Take field
+    pub fn take_points(&mut self) -> ::protobuf::RepeatedField<Point2d> {
+        ::std::mem::replace(&mut self.points, ::protobuf::RepeatedField::new())
+    }
+
+    // .viz.Color color = 2;
+
+
+    pub fn get_color(&self) -> &Color {
+        self.color.as_ref().unwrap_or_else(|| <Color as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_color(&mut self) {
+        self.color.clear();
+    }
+
+    pub fn has_color(&self) -> bool {
+        self.color.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color(&mut self, v: Color) {
+        self.color = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_color(&mut self) -> &mut Color {
+        if self.color.is_none() {
+            self.color.set_default();
+        }
+        self.color.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_color(&mut self) -> Color {
+        self.color.take().unwrap_or_else(|| Color::new())
+    }
+
+    // string label = 3;
+
+
+    pub fn get_label(&self) -> &str {
+        &self.label
+    }
+    pub fn clear_label(&mut self) {
+        self.label.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_label(&mut self, v: ::std::string::String) {
+        self.label = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_label(&mut self) -> &mut ::std::string::String {
+        &mut self.label
+    }
+
+    // Take field
+    pub fn take_label(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.label, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Polygon2d {
+    fn is_initialized(&self) -> bool {
+        for v in &self.points {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.color {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.points)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.color)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.label)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.points {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.color.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.label.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.label);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.points {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.color.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.label.is_empty() {
+            os.write_string(3, &self.label)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Polygon2d {
+        Polygon2d::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Point2d>>(
+                "points",
+                |m: &Polygon2d| { &m.points },
+                |m: &mut Polygon2d| { &mut m.points },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Color>>(
+                "color",
+                |m: &Polygon2d| { &m.color },
+                |m: &mut Polygon2d| { &mut m.color },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "label",
+                |m: &Polygon2d| { &m.label },
+                |m: &mut Polygon2d| { &mut m.label },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Polygon2d>(
+                "Polygon2d",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Polygon2d {
+        static instance: ::protobuf::rt::LazyV2<Polygon2d> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Polygon2d::new)
+    }
+}
+
+impl ::protobuf::Clear for Polygon2d {
+    fn clear(&mut self) {
+        self.points.clear();
+        self.color.clear();
+        self.label.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Polygon2d {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Polygon2d {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Polyline2d {
+    // message fields
+    pub points: ::protobuf::RepeatedField<Point2d>,
+    pub color: ::protobuf::SingularPtrField<Color>,
+    pub label: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Polyline2d {
+    fn default() -> &'a Polyline2d {
+        <Polyline2d as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Polyline2d {
+    pub fn new() -> Polyline2d {
+        ::std::default::Default::default()
+    }
+
+    // repeated .viz.Point2d points = 1;
+
+
+    pub fn get_points(&self) -> &[Point2d] {
+        &self.points
+    }
+    pub fn clear_points(&mut self) {
+        self.points.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_points(&mut self, v: ::protobuf::RepeatedField<Point2d>) {
+        self.points = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_points(&mut self) -> &mut ::protobuf::RepeatedField<Point2d> {
+        &mut self.points
+    }
+
+    // Take field
+    pub fn take_points(&mut self) -> ::protobuf::RepeatedField<Point2d> {
+        ::std::mem::replace(&mut self.points, ::protobuf::RepeatedField::new())
+    }
+
+    // .viz.Color color = 2;
+
+
+    pub fn get_color(&self) -> &Color {
+        self.color.as_ref().unwrap_or_else(|| <Color as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_color(&mut self) {
+        self.color.clear();
+    }
+
+    pub fn has_color(&self) -> bool {
+        self.color.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color(&mut self, v: Color) {
+        self.color = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_color(&mut self) -> &mut Color {
+        if self.color.is_none() {
+            self.color.set_default();
+        }
+        self.color.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_color(&mut self) -> Color {
+        self.color.take().unwrap_or_else(|| Color::new())
+    }
+
+    // string label = 3;
+
+
+    pub fn get_label(&self) -> &str {
+        &self.label
+    }
+    pub fn clear_label(&mut self) {
+        self.label.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_label(&mut self, v: ::std::string::String) {
+        self.label = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_label(&mut self) -> &mut ::std::string::String {
+        &mut self.label
+    }
+
+    // Take field
+    pub fn take_label(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.label, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Polyline2d {
+    fn is_initialized(&self) -> bool {
+        for v in &self.points {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.color {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.points)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.color)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.label)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.points {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.color.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.label.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.label);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.points {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.color.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.label.is_empty() {
+            os.write_string(3, &self.label)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Polyline2d {
+        Polyline2d::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Point2d>>(
+                "points",
+                |m: &Polyline2d| { &m.points },
+                |m: &mut Polyline2d| { &mut m.points },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Color>>(
+                "color",
+                |m: &Polyline2d| { &m.color },
+                |m: &mut Polyline2d| { &mut m.color },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "label",
+                |m: &Polyline2d| { &m.label },
+                |m: &mut Polyline2d| { &mut m.label },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Polyline2d>(
+                "Polyline2d",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Polyline2d {
+        static instance: ::protobuf::rt::LazyV2<Polyline2d> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Polyline2d::new)
+    }
+}
+
+impl ::protobuf::Clear for Polyline2d {
+    fn clear(&mut self) {
+        self.points.clear();
+        self.color.clear();
+        self.label.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Polyline2d {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Polyline2d {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Keypoints2d {
+    // message fields
+    pub points: ::protobuf::RepeatedField<Point2d>,
+    pub color: ::protobuf::SingularPtrField<Color>,
+    pub label: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Keypoints2d {
+    fn default() -> &'a Keypoints2d {
+        <Keypoints2d as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Keypoints2d {
+    pub fn new() -> Keypoints2d {
+        ::std::default::Default::default()
+    }
+
+    // repeated .viz.Point2d points = 1;
+
+
+    pub fn get_points(&self) -> &[Point2d] {
+        &self.points
+    }
+    pub fn clear_points(&mut self) {
+        self.points.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_points(&mut self, v: ::protobuf::RepeatedField<Point2d>) {
+        self.points = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_points(&mut self) -> &mut ::protobuf::RepeatedField<Point2d> {
+        &mut self.points
+    }
+
+    // Take field
+    pub fn take_points(&mut self) -> ::protobuf::RepeatedField<Point2d> {
+        ::std::mem::replace(&mut self.points, ::protobuf::RepeatedField::new())
+    }
+
+    // .viz.Color color = 2;
+
+
+    pub fn get_color(&self) -> &Color {
+        self.color.as_ref().unwrap_or_else(|| <Color as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_color(&mut self) {
+        self.color.clear();
+    }
+
+    pub fn has_color(&self) -> bool {
+        self.color.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color(&mut self, v: Color) {
+        self.color = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_color(&mut self) -> &mut Color {
+        if self.color.is_none() {
+            self.color.set_default();
+        }
+        self.color.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_color(&mut self) -> Color {
+        self.color.take().unwrap_or_else(|| Color::new())
+    }
+
+    // string label = 3;
+
+
+    pub fn get_label(&self) -> &str {
+        &self.label
+    }
+    pub fn clear_label(&mut self) {
+        self.label.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_label(&mut self, v: ::std::string::String) {
+        self.label = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_label(&mut self) -> &mut ::std::string::String {
+        &mut self.label
+    }
+
+    // Take field
+    pub fn take_label(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.label, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Keypoints2d {
+    fn is_initialized(&self) -> bool {
+        for v in &self.points {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.color {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.points)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.color)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.label)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.points {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.color.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.label.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.label);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.points {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.color.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.label.is_empty() {
+            os.write_string(3, &self.label)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Keypoints2d {
+        Keypoints2d::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Point2d>>(
+                "points",
+                |m: &Keypoints2d| { &m.points },
+                |m: &mut Keypoints2d| { &mut m.points },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Color>>(
+                "color",
+                |m: &Keypoints2d| { &m.color },
+                |m: &mut Keypoints2d| { &mut m.color },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "label",
+                |m: &Keypoints2d| { &m.label },
+                |m: &mut Keypoints2d| { &mut m.label },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Keypoints2d>(
+                "Keypoints2d",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Keypoints2d {
+        static instance: ::protobuf::rt::LazyV2<Keypoints2d> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Keypoints2d::new)
+    }
+}
+
+impl ::protobuf::Clear for Keypoints2d {
+    fn clear(&mut self) {
+        self.points.clear();
+        self.color.clear();
+        self.label.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Keypoints2d {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Keypoints2d {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Label2d {
+    // message fields
+    pub position: ::protobuf::SingularPtrField<Point2d>,
+    pub text: ::std::string::String,
+    pub color: ::protobuf::SingularPtrField<Color>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Label2d {
+    fn default() -> &'a Label2d {
+        <Label2d as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Label2d {
+    pub fn new() -> Label2d {
+        ::std::default::Default::default()
+    }
+
+    // .viz.Point2d position = 1;
+
+
+    pub fn get_position(&self) -> &Point2d {
+        self.position.as_ref().unwrap_or_else(|| <Point2d as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_position(&mut self) {
+        self.position.clear();
+    }
+
+    pub fn has_position(&self) -> bool {
+        self.position.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_position(&mut self, v: Point2d) {
+        self.position = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_position(&mut self) -> &mut Point2d {
+        if self.position.is_none() {
+            self.position.set_default();
+        }
+        self.position.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_position(&mut self) -> Point2d {
+        self.position.take().unwrap_or_else(|| Point2d::new())
+    }
+
+    // string text = 2;
+
+
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+    pub fn clear_text(&mut self) {
+        self.text.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: ::std::string::String) {
+        self.text = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text(&mut self) -> &mut ::std::string::String {
+        &mut self.text
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.text, ::std::string::String::new())
+    }
+
+    // .viz.Color color = 3;
+
+
+    pub fn get_color(&self) -> &Color {
+        self.color.as_ref().unwrap_or_else(|| <Color as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_color(&mut self) {
+        self.color.clear();
+    }
+
+    pub fn has_color(&self) -> bool {
+        self.color.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color(&mut self, v: Color) {
+        self.color = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_color(&mut self) -> &mut Color {
+        if self.color.is_none() {
+            self.color.set_default();
+        }
+        self.color.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_color(&mut self) -> Color {
+        self.color.take().unwrap_or_else(|| Color::new())
+    }
+}
+
+impl ::protobuf::Message for Label2d {
+    fn is_initialized(&self) -> bool {
+        for v in &self.position {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.color {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.position)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.text)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.color)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.text.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.text);
+        }
+        if let Some(ref v) = self.color.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.position.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.text.is_empty() {
+            os.write_string(2, &self.text)?;
+        }
+        if let Some(ref v) = self.color.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Label2d {
+        Label2d::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Point2d>>(
+                "position",
+                |m: &Label2d| { &m.position },
+                |m: &mut Label2d| { &mut m.position },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "text",
+                |m: &Label2d| { &m.text },
+                |m: &mut Label2d| { &mut m.text },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Color>>(
+                "color",
+                |m: &Label2d| { &m.color },
+                |m: &mut Label2d| { &mut m.color },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Label2d>(
+                "Label2d",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Label2d {
+        static instance: ::protobuf::rt::LazyV2<Label2d> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Label2d::new)
+    }
+}
+
+impl ::protobuf::Clear for Label2d {
+    fn clear(&mut self) {
+        self.position.clear();
+        self.text.clear();
+        self.color.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Label2d {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Label2d {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImageAnnotations {
+    // message fields
+    pub boxes: ::protobuf::RepeatedField<BoundingBox2d>,
+    pub polygons: ::protobuf::RepeatedField<Polygon2d>,
+    pub polylines: ::protobuf::RepeatedField<Polyline2d>,
+    pub keypoints: ::protobuf::RepeatedField<Keypoints2d>,
+    pub labels: ::protobuf::RepeatedField<Label2d>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImageAnnotations {
+    fn default() -> &'a ImageAnnotations {
+        <ImageAnnotations as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImageAnnotations {
+    pub fn new() -> ImageAnnotations {
+        ::std::default::Default::default()
+    }
+
+    // repeated .viz.BoundingBox2d boxes = 1;
+
+
+    pub fn get_boxes(&self) -> &[BoundingBox2d] {
+        &self.boxes
+    }
+    pub fn clear_boxes(&mut self) {
+        self.boxes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_boxes(&mut self, v: ::protobuf::RepeatedField<BoundingBox2d>) {
+        self.boxes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_boxes(&mut self) -> &mut ::protobuf::RepeatedField<BoundingBox2d> {
+        &mut self.boxes
+    }
+
+    // Take field
+    pub fn take_boxes(&mut self) -> ::protobuf::RepeatedField<BoundingBox2d> {
+        ::std::mem::replace(&mut self.boxes, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .viz.Polygon2d polygons = 2;
+
+
+    pub fn get_polygons(&self) -> &[Polygon2d] {
+        &self.polygons
+    }
+    pub fn clear_polygons(&mut self) {
+        self.polygons.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_polygons(&mut self, v: ::protobuf::RepeatedField<Polygon2d>) {
+        self.polygons = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_polygons(&mut self) -> &mut ::protobuf::RepeatedField<Polygon2d> {
+        &mut self.polygons
+    }
+
+    // Take field
+    pub fn take_polygons(&mut self) -> ::protobuf::RepeatedField<Polygon2d> {
+        ::std::mem::replace(&mut self.polygons, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .viz.Polyline2d polylines = 3;
+
+
+    pub fn get_polylines(&self) -> &[Polyline2d] {
+        &self.polylines
+    }
+    pub fn clear_polylines(&mut self) {
+        self.polylines.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_polylines(&mut self, v: ::protobuf::RepeatedField<Polyline2d>) {
+        self.polylines = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_polylines(&mut self) -> &mut ::protobuf::RepeatedField<Polyline2d> {
+        &mut self.polylines
+    }
+
+    // Take field
+    pub fn take_polylines(&mut self) -> ::protobuf::RepeatedField<Polyline2d> {
+        ::std::mem::replace(&mut self.polylines, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .viz.Keypoints2d keypoints = 4;
+
+
+    pub fn get_keypoints(&self) -> &[Keypoints2d] {
+        &self.keypoints
+    }
+    pub fn clear_keypoints(&mut self) {
+        self.keypoints.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keypoints(&mut self, v: ::protobuf::RepeatedField<Keypoints2d>) {
+        self.keypoints = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_keypoints(&mut self) -> &mut ::protobuf::RepeatedField<Keypoints2d> {
+        &mut self.keypoints
+    }
+
+    // Take field
+    pub fn take_keypoints(&mut self) -> ::protobuf::RepeatedField<Keypoints2d> {
+        ::std::mem::replace(&mut self.keypoints, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .viz.Label2d labels = 5;
+
+
+    pub fn get_labels(&self) -> &[Label2d] {
+        &self.labels
+    }
+    pub fn clear_labels(&mut self) {
+        self.labels.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_labels(&mut self, v: ::protobuf::RepeatedField<Label2d>) {
+        self.labels = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_labels(&mut self) -> &mut ::protobuf::RepeatedField<Label2d> {
+        &mut self.labels
+    }
+
+    // Take field
+    pub fn take_labels(&mut self) -> ::protobuf::RepeatedField<Label2d> {
+        ::std::mem::replace(&mut self.labels, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ImageAnnotations {
+    fn is_initialized(&self) -> bool {
+        for v in &self.boxes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.polygons {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.polylines {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.keypoints {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.labels {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.boxes)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.polygons)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.polylines)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.keypoints)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.labels)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.boxes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.polygons {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.polylines {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.keypoints {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.labels {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.boxes {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.polygons {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.polylines {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.keypoints {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.labels {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ImageAnnotations {
+        ImageAnnotations::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BoundingBox2d>>(
+                "boxes",
+                |m: &ImageAnnotations| { &m.boxes },
+                |m: &mut ImageAnnotations| { &mut m.boxes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Polygon2d>>(
+                "polygons",
+                |m: &ImageAnnotations| { &m.polygons },
+                |m: &mut ImageAnnotations| { &mut m.polygons },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Polyline2d>>(
+                "polylines",
+                |m: &ImageAnnotations| { &m.polylines },
+                |m: &mut ImageAnnotations| { &mut m.polylines },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Keypoints2d>>(
+                "keypoints",
+                |m: &ImageAnnotations| { &m.keypoints },
+                |m: &mut ImageAnnotations| { &mut m.keypoints },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Label2d>>(
+                "labels",
+                |m: &ImageAnnotations| { &m.labels },
+                |m: &mut ImageAnnotations| { &mut m.labels },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ImageAnnotations>(
+                "ImageAnnotations",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ImageAnnotations {
+        static instance: ::protobuf::rt::LazyV2<ImageAnnotations> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ImageAnnotations::new)
+    }
+}
+
+impl ::protobuf::Clear for ImageAnnotations {
+    fn clear(&mut self) {
+        self.boxes.clear();
+        self.polygons.clear();
+        self.polylines.clear();
+        self.keypoints.clear();
+        self.labels.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImageAnnotations {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImageAnnotations {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ImageEncoding {
+    ENCODED = 0,
+    RGB8 = 1,
+    BGR8 = 2,
+    MONO8 = 3,
+    MONO16 = 4,
+    DEPTH16 = 5,
+}
+
+impl ::protobuf::ProtobufEnum for ImageEncoding {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ImageEncoding> {
+        match value {
+            0 => ::std::option::Option::Some(ImageEncoding::ENCODED),
+            1 => ::std::option::Option::Some(ImageEncoding::RGB8),
+            2 => ::std::option::Option::Some(ImageEncoding::BGR8),
+            3 => ::std::option::Option::Some(ImageEncoding::MONO8),
+            4 => ::std::option::Option::Some(ImageEncoding::MONO16),
+            5 => ::std::option::Option::Some(ImageEncoding::DEPTH16),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ImageEncoding] = &[
+            ImageEncoding::ENCODED,
+            ImageEncoding::RGB8,
+            ImageEncoding::BGR8,
+            ImageEncoding::MONO8,
+            ImageEncoding::MONO16,
+            ImageEncoding::DEPTH16,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ImageEncoding>("ImageEncoding", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ImageEncoding {
+}
+
+impl ::std::default::Default for ImageEncoding {
+    fn default() -> Self {
+        ImageEncoding::ENCODED
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImageEncoding {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x10primitives.proto\x12\x03viz\"\x1a\n\x04Text\x12\x12\n\x04text\x18\
-    \x01\x20\x01(\tR\x04text\"8\n\x05Image\x12\x12\n\x04data\x18\x01\x20\x01\
-    (\x0cR\x04data\x12\x1b\n\tmime_type\x18\x02\x20\x01(\tR\x08mimeType\"%\n\
-    \x07Point2d\x12\x0c\n\x01x\x18\x01\x20\x01(\x02R\x01x\x12\x0c\n\x01y\x18\
-    \x02\x20\x01(\x02R\x01y\"3\n\x07Point3d\x12\x0c\n\x01x\x18\x01\x20\x01(\
-    \x02R\x01x\x12\x0c\n\x01y\x18\x02\x20\x01(\x02R\x01y\x12\x0c\n\x01z\x18\
-    \x03\x20\x01(\x02R\x01zJ\xdb\x04\n\x06\x12\x04\0\0\x18\x01\n\x08\n\x01\
-    \x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x02\0\x0c\n\n\n\x02\x04\0\
-    \x12\x04\x04\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x04\x08\x0c\n\x0b\n\
-    \x04\x04\0\x02\0\x12\x03\x05\x04\x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
-    \x05\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\
-    \x04\0\x02\0\x03\x12\x03\x05\x12\x13\n\n\n\x02\x04\x01\x12\x04\x08\0\r\
-    \x01\n\n\n\x03\x04\x01\x01\x12\x03\x08\x08\r\n\x19\n\x04\x04\x01\x02\0\
-    \x12\x03\n\x04\x13\x1a\x0c\x20image\x20data\n\n\x0c\n\x05\x04\x01\x02\0\
-    \x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\n\n\x0e\n\x0c\
-    \n\x05\x04\x01\x02\0\x03\x12\x03\n\x11\x12\n$\n\x04\x04\x01\x02\x01\x12\
-    \x03\x0c\x04\x19\x1a\x17\x20image/jpeg,\x20image/png\n\n\x0c\n\x05\x04\
-    \x01\x02\x01\x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\
-    \x03\x0c\x0b\x14\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0c\x17\x18\n\n\
-    \n\x02\x04\x02\x12\x04\x0f\0\x12\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0f\
-    \x08\x0f\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x10\x04\x10\n\x0c\n\x05\x04\
-    \x02\x02\0\x05\x12\x03\x10\x04\t\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\
-    \x10\n\x0b\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x10\x0e\x0f\n\x0b\n\x04\
-    \x04\x02\x02\x01\x12\x03\x11\x04\x10\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\
-    \x03\x11\x04\t\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x11\n\x0b\n\x0c\n\
-    \x05\x04\x02\x02\x01\x03\x12\x03\x11\x0e\x0f\n\n\n\x02\x04\x03\x12\x04\
-    \x14\0\x18\x01\n\n\n\x03\x04\x03\x01\x12\x03\x14\x08\x0f\n\x0b\n\x04\x04\
-    \x03\x02\0\x12\x03\x15\x04\x10\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x15\
-    \x04\t\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x15\n\x0b\n\x0c\n\x05\x04\
-    \x03\x02\0\x03\x12\x03\x15\x0e\x0f\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\
-    \x16\x04\x10\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x16\x04\t\n\x0c\n\
-    \x05\x04\x03\x02\x01\x01\x12\x03\x16\n\x0b\n\x0c\n\x05\x04\x03\x02\x01\
-    \x03\x12\x03\x16\x0e\x0f\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x17\x04\x10\
-    \n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x17\x04\t\n\x0c\n\x05\x04\x03\
-    \x02\x02\x01\x12\x03\x17\n\x0b\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\
-    \x17\x0e\x0fb\x06proto3\
+    \x01\x20\x01(\tR\x04text\"\xae\x01\n\x05Image\x12\x12\n\x04data\x18\x01\
+    \x20\x01(\x0cR\x04data\x12\x1b\n\tmime_type\x18\x02\x20\x01(\tR\x08mimeT\
+    ype\x12\x14\n\x05width\x18\x03\x20\x01(\rR\x05width\x12\x16\n\x06height\
+    \x18\x04\x20\x01(\rR\x06height\x12.\n\x08encoding\x18\x05\x20\x01(\x0e2\
+    \x12.viz.ImageEncodingR\x08encoding\x12\x16\n\x06stride\x18\x06\x20\x01(\
+    \rR\x06stride\"%\n\x07Point2d\x12\x0c\n\x01x\x18\x01\x20\x01(\x02R\x01x\
+    \x12\x0c\n\x01y\x18\x02\x20\x01(\x02R\x01y\"3\n\x07Point3d\x12\x0c\n\x01\
+    x\x18\x01\x20\x01(\x02R\x01x\x12\x0c\n\x01y\x18\x02\x20\x01(\x02R\x01y\
+    \x12\x0c\n\x01z\x18\x03\x20\x01(\x02R\x01z\"?\n\x05Color\x12\x0c\n\x01r\
+    \x18\x01\x20\x01(\rR\x01r\x12\x0c\n\x01g\x18\x02\x20\x01(\rR\x01g\x12\
+    \x0c\n\x01b\x18\x03\x20\x01(\rR\x01b\x12\x0c\n\x01a\x18\x04\x20\x01(\rR\
+    \x01a\"\x91\x01\n\rBoundingBox2d\x12\x0c\n\x01x\x18\x01\x20\x01(\x02R\
+    \x01x\x12\x0c\n\x01y\x18\x02\x20\x01(\x02R\x01y\x12\x14\n\x05width\x18\
+    \x03\x20\x01(\x02R\x05width\x12\x16\n\x06height\x18\x04\x20\x01(\x02R\
+    \x06height\x12\x20\n\x05color\x18\x05\x20\x01(\x0b2\n.viz.ColorR\x05colo\
+    r\x12\x14\n\x05label\x18\x06\x20\x01(\tR\x05label\"i\n\tPolygon2d\x12$\n\
+    \x06points\x18\x01\x20\x03(\x0b2\x0c.viz.Point2dR\x06points\x12\x20\n\
+    \x05color\x18\x02\x20\x01(\x0b2\n.viz.ColorR\x05color\x12\x14\n\x05label\
+    \x18\x03\x20\x01(\tR\x05label\"j\n\nPolyline2d\x12$\n\x06points\x18\x01\
+    \x20\x03(\x0b2\x0c.viz.Point2dR\x06points\x12\x20\n\x05color\x18\x02\x20\
+    \x01(\x0b2\n.viz.ColorR\x05color\x12\x14\n\x05label\x18\x03\x20\x01(\tR\
+    \x05label\"k\n\x0bKeypoints2d\x12$\n\x06points\x18\x01\x20\x03(\x0b2\x0c\
+    .viz.Point2dR\x06points\x12\x20\n\x05color\x18\x02\x20\x01(\x0b2\n.viz.C\
+    olorR\x05color\x12\x14\n\x05label\x18\x03\x20\x01(\tR\x05label\"i\n\x07L\
+    abel2d\x12(\n\x08position\x18\x01\x20\x01(\x0b2\x0c.viz.Point2dR\x08posi\
+    tion\x12\x12\n\x04text\x18\x02\x20\x01(\tR\x04text\x12\x20\n\x05color\
+    \x18\x03\x20\x01(\x0b2\n.viz.ColorR\x05color\"\xed\x01\n\x10ImageAnnotat\
+    ions\x12(\n\x05boxes\x18\x01\x20\x03(\x0b2\x12.viz.BoundingBox2dR\x05box\
+    es\x12*\n\x08polygons\x18\x02\x20\x03(\x0b2\x0e.viz.Polygon2dR\x08polygo\
+    ns\x12-\n\tpolylines\x18\x03\x20\x03(\x0b2\x0f.viz.Polyline2dR\tpolyline\
+    s\x12.\n\tkeypoints\x18\x04\x20\x03(\x0b2\x10.viz.Keypoints2dR\tkeypoint\
+    s\x12$\n\x06labels\x18\x05\x20\x03(\x0b2\x0c.viz.Label2dR\x06labels*T\n\
+    \rImageEncoding\x12\x0b\n\x07ENCODED\x10\0\x12\x08\n\x04RGB8\x10\x01\x12\
+    \x08\n\x04BGR8\x10\x02\x12\t\n\x05MONO8\x10\x03\x12\n\n\x06MONO16\x10\
+    \x04\x12\x0b\n\x07DEPTH16\x10\x05J\xbc\x18\n\x06\x12\x04\0\0b\x01\n\x08\
+    \n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x02\0\x0c\n\n\n\x02\
+    \x04\0\x12\x04\x04\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x04\x08\x0c\n\
+    \x0b\n\x04\x04\0\x02\0\x12\x03\x05\x04\x14\n\x0c\n\x05\x04\0\x02\0\x05\
+    \x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\x0b\x0f\n\x0c\
+    \n\x05\x04\0\x02\0\x03\x12\x03\x05\x12\x13\n\n\n\x02\x05\0\x12\x04\x08\0\
+    \x12\x01\n\n\n\x03\x05\0\x01\x12\x03\x08\x05\x12\n0\n\x04\x05\0\x02\0\
+    \x12\x03\n\x04\x10\x1a#\x20encoded\x20file,\x20see\x20Image.mime_type\n\
+    \n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\n\x04\x0b\n\x0c\n\x05\x05\0\x02\0\
+    \x02\x12\x03\n\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x0b\x04\r\n\x0c\
+    \n\x05\x05\0\x02\x01\x01\x12\x03\x0b\x04\x08\n\x0c\n\x05\x05\0\x02\x01\
+    \x02\x12\x03\x0b\x0b\x0c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x0c\x04\r\n\
+    \x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x0c\x04\x08\n\x0c\n\x05\x05\0\x02\
+    \x02\x02\x12\x03\x0c\x0b\x0c\n\x0b\n\x04\x05\0\x02\x03\x12\x03\r\x04\x0e\
+    \n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\r\x04\t\n\x0c\n\x05\x05\0\x02\x03\
+    \x02\x12\x03\r\x0c\r\n\x1c\n\x04\x05\0\x02\x04\x12\x03\x0f\x04\x0f\x1a\
+    \x0f\x20little\x20endian\n\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x0f\x04\
+    \n\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x0f\r\x0e\n0\n\x04\x05\0\x02\
+    \x05\x12\x03\x11\x04\x10\x1a#\x20little\x20endian,\x200\x20is\x20invalid\
+    \x20depth\n\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x11\x04\x0b\n\x0c\n\
+    \x05\x05\0\x02\x05\x02\x12\x03\x11\x0e\x0f\n\n\n\x02\x04\x01\x12\x04\x14\
+    \0\x20\x01\n\n\n\x03\x04\x01\x01\x12\x03\x14\x08\r\n\x19\n\x04\x04\x01\
+    \x02\0\x12\x03\x16\x04\x13\x1a\x0c\x20image\x20data\n\n\x0c\n\x05\x04\
+    \x01\x02\0\x05\x12\x03\x16\x04\t\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\
+    \x16\n\x0e\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x16\x11\x12\n$\n\x04\
+    \x04\x01\x02\x01\x12\x03\x18\x04\x19\x1a\x17\x20image/jpeg,\x20image/png\
+    \n\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x18\x04\n\n\x0c\n\x05\x04\x01\
+    \x02\x01\x01\x12\x03\x18\x0b\x14\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\
+    \x18\x17\x18\n$\n\x04\x04\x01\x02\x02\x12\x03\x1b\x04\x15\x1a\x17\x20for\
+    \x20raw\x20pixel\x20buffers\n\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\
+    \x1b\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x1b\x0b\x10\n\x0c\n\
+    \x05\x04\x01\x02\x02\x03\x12\x03\x1b\x13\x14\n\x0b\n\x04\x04\x01\x02\x03\
+    \x12\x03\x1c\x04\x16\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\x1c\x04\n\n\
+    \x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x1c\x0b\x11\n\x0c\n\x05\x04\x01\
+    \x02\x03\x03\x12\x03\x1c\x14\x15\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\x1d\
+    \x04\x1f\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\x1d\x04\x11\n\x0c\n\x05\
+    \x04\x01\x02\x04\x01\x12\x03\x1d\x12\x1a\n\x0c\n\x05\x04\x01\x02\x04\x03\
+    \x12\x03\x1d\x1d\x1e\n1\n\x04\x04\x01\x02\x05\x12\x03\x1f\x04\x16\x1a$\
+    \x20bytes\x20per\x20row,\x200\x20means\x20packed\x20rows\n\n\x0c\n\x05\
+    \x04\x01\x02\x05\x05\x12\x03\x1f\x04\n\n\x0c\n\x05\x04\x01\x02\x05\x01\
+    \x12\x03\x1f\x0b\x11\n\x0c\n\x05\x04\x01\x02\x05\x03\x12\x03\x1f\x14\x15\
+    \n\n\n\x02\x04\x02\x12\x04\"\0%\x01\n\n\n\x03\x04\x02\x01\x12\x03\"\x08\
+    \x0f\n\x0b\n\x04\x04\x02\x02\0\x12\x03#\x04\x10\n\x0c\n\x05\x04\x02\x02\
+    \0\x05\x12\x03#\x04\t\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03#\n\x0b\n\x0c\
+    \n\x05\x04\x02\x02\0\x03\x12\x03#\x0e\x0f\n\x0b\n\x04\x04\x02\x02\x01\
+    \x12\x03$\x04\x10\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03$\x04\t\n\x0c\n\
+    \x05\x04\x02\x02\x01\x01\x12\x03$\n\x0b\n\x0c\n\x05\x04\x02\x02\x01\x03\
+    \x12\x03$\x0e\x0f\n\n\n\x02\x04\x03\x12\x04'\0+\x01\n\n\n\x03\x04\x03\
+    \x01\x12\x03'\x08\x0f\n\x0b\n\x04\x04\x03\x02\0\x12\x03(\x04\x10\n\x0c\n\
+    \x05\x04\x03\x02\0\x05\x12\x03(\x04\t\n\x0c\n\x05\x04\x03\x02\0\x01\x12\
+    \x03(\n\x0b\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03(\x0e\x0f\n\x0b\n\x04\
+    \x04\x03\x02\x01\x12\x03)\x04\x10\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\
+    \x03)\x04\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03)\n\x0b\n\x0c\n\x05\
+    \x04\x03\x02\x01\x03\x12\x03)\x0e\x0f\n\x0b\n\x04\x04\x03\x02\x02\x12\
+    \x03*\x04\x10\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03*\x04\t\n\x0c\n\x05\
+    \x04\x03\x02\x02\x01\x12\x03*\n\x0b\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\
+    \x03*\x0e\x0f\n\x1b\n\x02\x04\x04\x12\x04/\04\x01\x1a\x0f\x20RGBA,\x200\
+    \x20-\x20255\n\n\n\n\x03\x04\x04\x01\x12\x03/\x08\r\n\x0b\n\x04\x04\x04\
+    \x02\0\x12\x030\x04\x11\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x030\x04\n\n\
+    \x0c\n\x05\x04\x04\x02\0\x01\x12\x030\x0b\x0c\n\x0c\n\x05\x04\x04\x02\0\
+    \x03\x12\x030\x0f\x10\n\x0b\n\x04\x04\x04\x02\x01\x12\x031\x04\x11\n\x0c\
+    \n\x05\x04\x04\x02\x01\x05\x12\x031\x04\n\n\x0c\n\x05\x04\x04\x02\x01\
+    \x01\x12\x031\x0b\x0c\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x031\x0f\x10\n\
+    \x0b\n\x04\x04\x04\x02\x02\x12\x032\x04\x11\n\x0c\n\x05\x04\x04\x02\x02\
+    \x05\x12\x032\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x032\x0b\x0c\n\
+    \x0c\n\x05\x04\x04\x02\x02\x03\x12\x032\x0f\x10\n\x0b\n\x04\x04\x04\x02\
+    \x03\x12\x033\x04\x11\n\x0c\n\x05\x04\x04\x02\x03\x05\x12\x033\x04\n\n\
+    \x0c\n\x05\x04\x04\x02\x03\x01\x12\x033\x0b\x0c\n\x0c\n\x05\x04\x04\x02\
+    \x03\x03\x12\x033\x0f\x10\nL\n\x02\x04\x05\x12\x048\0@\x012@\x20Annotati\
+    ons\x20are\x20in\x20image\x20pixel\x20coordinates,\x20origin\x20is\x20to\
+    p-left\n\n\n\n\x03\x04\x05\x01\x12\x038\x08\x15\n\x1e\n\x04\x04\x05\x02\
+    \0\x12\x03:\x04\x10\x1a\x11\x20top-left\x20corner\n\n\x0c\n\x05\x04\x05\
+    \x02\0\x05\x12\x03:\x04\t\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03:\n\x0b\n\
+    \x0c\n\x05\x04\x05\x02\0\x03\x12\x03:\x0e\x0f\n\x0b\n\x04\x04\x05\x02\
+    \x01\x12\x03;\x04\x10\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03;\x04\t\n\
+    \x0c\n\x05\x04\x05\x02\x01\x01\x12\x03;\n\x0b\n\x0c\n\x05\x04\x05\x02\
+    \x01\x03\x12\x03;\x0e\x0f\n\x0b\n\x04\x04\x05\x02\x02\x12\x03<\x04\x14\n\
+    \x0c\n\x05\x04\x05\x02\x02\x05\x12\x03<\x04\t\n\x0c\n\x05\x04\x05\x02\
+    \x02\x01\x12\x03<\n\x0f\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03<\x12\x13\
+    \n\x0b\n\x04\x04\x05\x02\x03\x12\x03=\x04\x15\n\x0c\n\x05\x04\x05\x02\
+    \x03\x05\x12\x03=\x04\t\n\x0c\n\x05\x04\x05\x02\x03\x01\x12\x03=\n\x10\n\
+    \x0c\n\x05\x04\x05\x02\x03\x03\x12\x03=\x13\x14\n\x0b\n\x04\x04\x05\x02\
+    \x04\x12\x03>\x04\x14\n\x0c\n\x05\x04\x05\x02\x04\x06\x12\x03>\x04\t\n\
+    \x0c\n\x05\x04\x05\x02\x04\x01\x12\x03>\n\x0f\n\x0c\n\x05\x04\x05\x02\
+    \x04\x03\x12\x03>\x12\x13\n\x0b\n\x04\x04\x05\x02\x05\x12\x03?\x04\x15\n\
+    \x0c\n\x05\x04\x05\x02\x05\x05\x12\x03?\x04\n\n\x0c\n\x05\x04\x05\x02\
+    \x05\x01\x12\x03?\x0b\x10\n\x0c\n\x05\x04\x05\x02\x05\x03\x12\x03?\x13\
+    \x14\n\n\n\x02\x04\x06\x12\x04B\0G\x01\n\n\n\x03\x04\x06\x01\x12\x03B\
+    \x08\x11\n\x15\n\x04\x04\x06\x02\0\x12\x03D\x04\x20\x1a\x08\x20closed\n\
+    \n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03D\x04\x0c\n\x0c\n\x05\x04\x06\x02\
+    \0\x06\x12\x03D\r\x14\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03D\x15\x1b\n\
+    \x0c\n\x05\x04\x06\x02\0\x03\x12\x03D\x1e\x1f\n\x0b\n\x04\x04\x06\x02\
+    \x01\x12\x03E\x04\x14\n\x0c\n\x05\x04\x06\x02\x01\x06\x12\x03E\x04\t\n\
+    \x0c\n\x05\x04\x06\x02\x01\x01\x12\x03E\n\x0f\n\x0c\n\x05\x04\x06\x02\
+    \x01\x03\x12\x03E\x12\x13\n\x0b\n\x04\x04\x06\x02\x02\x12\x03F\x04\x15\n\
+    \x0c\n\x05\x04\x06\x02\x02\x05\x12\x03F\x04\n\n\x0c\n\x05\x04\x06\x02\
+    \x02\x01\x12\x03F\x0b\x10\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03F\x13\
+    \x14\n\n\n\x02\x04\x07\x12\x04I\0N\x01\n\n\n\x03\x04\x07\x01\x12\x03I\
+    \x08\x12\n\x13\n\x04\x04\x07\x02\0\x12\x03K\x04\x20\x1a\x06\x20open\n\n\
+    \x0c\n\x05\x04\x07\x02\0\x04\x12\x03K\x04\x0c\n\x0c\n\x05\x04\x07\x02\0\
+    \x06\x12\x03K\r\x14\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03K\x15\x1b\n\x0c\
+    \n\x05\x04\x07\x02\0\x03\x12\x03K\x1e\x1f\n\x0b\n\x04\x04\x07\x02\x01\
+    \x12\x03L\x04\x14\n\x0c\n\x05\x04\x07\x02\x01\x06\x12\x03L\x04\t\n\x0c\n\
+    \x05\x04\x07\x02\x01\x01\x12\x03L\n\x0f\n\x0c\n\x05\x04\x07\x02\x01\x03\
+    \x12\x03L\x12\x13\n\x0b\n\x04\x04\x07\x02\x02\x12\x03M\x04\x15\n\x0c\n\
+    \x05\x04\x07\x02\x02\x05\x12\x03M\x04\n\n\x0c\n\x05\x04\x07\x02\x02\x01\
+    \x12\x03M\x0b\x10\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03M\x13\x14\n\n\n\
+    \x02\x04\x08\x12\x04P\0T\x01\n\n\n\x03\x04\x08\x01\x12\x03P\x08\x13\n\
+    \x0b\n\x04\x04\x08\x02\0\x12\x03Q\x04\x20\n\x0c\n\x05\x04\x08\x02\0\x04\
+    \x12\x03Q\x04\x0c\n\x0c\n\x05\x04\x08\x02\0\x06\x12\x03Q\r\x14\n\x0c\n\
+    \x05\x04\x08\x02\0\x01\x12\x03Q\x15\x1b\n\x0c\n\x05\x04\x08\x02\0\x03\
+    \x12\x03Q\x1e\x1f\n\x0b\n\x04\x04\x08\x02\x01\x12\x03R\x04\x14\n\x0c\n\
+    \x05\x04\x08\x02\x01\x06\x12\x03R\x04\t\n\x0c\n\x05\x04\x08\x02\x01\x01\
+    \x12\x03R\n\x0f\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x03R\x12\x13\n\x0b\n\
+    \x04\x04\x08\x02\x02\x12\x03S\x04\x15\n\x0c\n\x05\x04\x08\x02\x02\x05\
+    \x12\x03S\x04\n\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x03S\x0b\x10\n\x0c\n\
+    \x05\x04\x08\x02\x02\x03\x12\x03S\x13\x14\n\n\n\x02\x04\t\x12\x04V\0Z\
+    \x01\n\n\n\x03\x04\t\x01\x12\x03V\x08\x0f\n\x0b\n\x04\x04\t\x02\0\x12\
+    \x03W\x04\x19\n\x0c\n\x05\x04\t\x02\0\x06\x12\x03W\x04\x0b\n\x0c\n\x05\
+    \x04\t\x02\0\x01\x12\x03W\x0c\x14\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03W\
+    \x17\x18\n\x0b\n\x04\x04\t\x02\x01\x12\x03X\x04\x14\n\x0c\n\x05\x04\t\
+    \x02\x01\x05\x12\x03X\x04\n\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03X\x0b\
+    \x0f\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03X\x12\x13\n\x0b\n\x04\x04\t\
+    \x02\x02\x12\x03Y\x04\x14\n\x0c\n\x05\x04\t\x02\x02\x06\x12\x03Y\x04\t\n\
+    \x0c\n\x05\x04\t\x02\x02\x01\x12\x03Y\n\x0f\n\x0c\n\x05\x04\t\x02\x02\
+    \x03\x12\x03Y\x12\x13\n\n\n\x02\x04\n\x12\x04\\\0b\x01\n\n\n\x03\x04\n\
+    \x01\x12\x03\\\x08\x18\n\x0b\n\x04\x04\n\x02\0\x12\x03]\x04%\n\x0c\n\x05\
+    \x04\n\x02\0\x04\x12\x03]\x04\x0c\n\x0c\n\x05\x04\n\x02\0\x06\x12\x03]\r\
+    \x1a\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03]\x1b\x20\n\x0c\n\x05\x04\n\x02\
+    \0\x03\x12\x03]#$\n\x0b\n\x04\x04\n\x02\x01\x12\x03^\x04$\n\x0c\n\x05\
+    \x04\n\x02\x01\x04\x12\x03^\x04\x0c\n\x0c\n\x05\x04\n\x02\x01\x06\x12\
+    \x03^\r\x16\n\x0c\n\x05\x04\n\x02\x01\x01\x12\x03^\x17\x1f\n\x0c\n\x05\
+    \x04\n\x02\x01\x03\x12\x03^\"#\n\x0b\n\x04\x04\n\x02\x02\x12\x03_\x04&\n\
+    \x0c\n\x05\x04\n\x02\x02\x04\x12\x03_\x04\x0c\n\x0c\n\x05\x04\n\x02\x02\
+    \x06\x12\x03_\r\x17\n\x0c\n\x05\x04\n\x02\x02\x01\x12\x03_\x18!\n\x0c\n\
+    \x05\x04\n\x02\x02\x03\x12\x03_$%\n\x0b\n\x04\x04\n\x02\x03\x12\x03`\x04\
+    '\n\x0c\n\x05\x04\n\x02\x03\x04\x12\x03`\x04\x0c\n\x0c\n\x05\x04\n\x02\
+    \x03\x06\x12\x03`\r\x18\n\x0c\n\x05\x04\n\x02\x03\x01\x12\x03`\x19\"\n\
+    \x0c\n\x05\x04\n\x02\x03\x03\x12\x03`%&\n\x0b\n\x04\x04\n\x02\x04\x12\
+    \x03a\x04\x20\n\x0c\n\x05\x04\n\x02\x04\x04\x12\x03a\x04\x0c\n\x0c\n\x05\
+    \x04\n\x02\x04\x06\x12\x03a\r\x14\n\x0c\n\x05\x04\n\x02\x04\x01\x12\x03a\
+    \x15\x1b\n\x0c\n\x05\x04\n\x02\x04\x03\x12\x03a\x1e\x1fb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
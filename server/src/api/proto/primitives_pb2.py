@@ -2,6 +2,7 @@
 # Generated by the protocol buffer compiler.  DO NOT EDIT!
 # source: primitives.proto
 """Generated protocol buffer code."""
+from google.protobuf.internal import enum_type_wrapper
 from google.protobuf import descriptor as _descriptor
 from google.protobuf import message as _message
 from google.protobuf import reflection as _reflection
@@ -19,9 +20,61 @@ DESCRIPTOR = _descriptor.FileDescriptor(
   syntax='proto3',
   serialized_options=None,
   create_key=_descriptor._internal_create_key,
-  serialized_pb=b'\n\x10primitives.proto\x12\x03viz\"\x14\n\x04Text\x12\x0c\n\x04text\x18\x01 \x01(\t\"(\n\x05Image\x12\x0c\n\x04\x64\x61ta\x18\x01 \x01(\x0c\x12\x11\n\tmime_type\x18\x02 \x01(\t\"\x1f\n\x07Point2d\x12\t\n\x01x\x18\x01 \x01(\x02\x12\t\n\x01y\x18\x02 \x01(\x02\"*\n\x07Point3d\x12\t\n\x01x\x18\x01 \x01(\x02\x12\t\n\x01y\x18\x02 \x01(\x02\x12\t\n\x01z\x18\x03 \x01(\x02\x62\x06proto3'
+  serialized_pb=b'\n\x10primitives.proto\x12\x03viz\"\x14\n\x04Text\x12\x0c\n\x04text\x18\x01 \x01(\t\"}\n\x05Image\x12\x0c\n\x04\x64\x61ta\x18\x01 \x01(\x0c\x12\x11\n\tmime_type\x18\x02 \x01(\t\x12\r\n\x05width\x18\x03 \x01(\r\x12\x0e\n\x06height\x18\x04 \x01(\r\x12$\n\x08\x65ncoding\x18\x05 \x01(\x0e\x32\x12.viz.ImageEncoding\x12\x0e\n\x06stride\x18\x06 \x01(\r\"\x1f\n\x07Point2d\x12\t\n\x01x\x18\x01 \x01(\x02\x12\t\n\x01y\x18\x02 \x01(\x02\"*\n\x07Point3d\x12\t\n\x01x\x18\x01 \x01(\x02\x12\t\n\x01y\x18\x02 \x01(\x02\x12\t\n\x01z\x18\x03 \x01(\x02\"3\n\x05\x43olor\x12\t\n\x01r\x18\x01 \x01(\r\x12\t\n\x01g\x18\x02 \x01(\r\x12\t\n\x01\x62\x18\x03 \x01(\r\x12\t\n\x01\x61\x18\x04 \x01(\r\"n\n\rBoundingBox2d\x12\t\n\x01x\x18\x01 \x01(\x02\x12\t\n\x01y\x18\x02 \x01(\x02\x12\r\n\x05width\x18\x03 \x01(\x02\x12\x0e\n\x06height\x18\x04 \x01(\x02\x12\x19\n\x05\x63olor\x18\x05 \x01(\x0b\x32\n.viz.Color\x12\r\n\x05label\x18\x06 \x01(\t\"S\n\tPolygon2d\x12\x1c\n\x06points\x18\x01 \x03(\x0b\x32\x0c.viz.Point2d\x12\x19\n\x05\x63olor\x18\x02 \x01(\x0b\x32\n.viz.Color\x12\r\n\x05label\x18\x03 \x01(\t\"T\n\nPolyline2d\x12\x1c\n\x06points\x18\x01 \x03(\x0b\x32\x0c.viz.Point2d\x12\x19\n\x05\x63olor\x18\x02 \x01(\x0b\x32\n.viz.Color\x12\r\n\x05label\x18\x03 \x01(\t\"U\n\x0bKeypoints2d\x12\x1c\n\x06points\x18\x01 \x03(\x0b\x32\x0c.viz.Point2d\x12\x19\n\x05\x63olor\x18\x02 \x01(\x0b\x32\n.viz.Color\x12\r\n\x05label\x18\x03 \x01(\t\"R\n\x07Label2d\x12\x1e\n\x08position\x18\x01 \x01(\x0b\x32\x0c.viz.Point2d\x12\x0c\n\x04text\x18\x02 \x01(\t\x12\x19\n\x05\x63olor\x18\x03 \x01(\x0b\x32\n.viz.Color\"\xbe\x01\n\x10ImageAnnotations\x12!\n\x05\x62oxes\x18\x01 \x03(\x0b\x32\x12.viz.BoundingBox2d\x12 \n\x08polygons\x18\x02 \x03(\x0b\x32\x0e.viz.Polygon2d\x12\"\n\tpolylines\x18\x03 \x03(\x0b\x32\x0f.viz.Polyline2d\x12#\n\tkeypoints\x18\x04 \x03(\x0b\x32\x10.viz.Keypoints2d\x12\x1c\n\x06labels\x18\x05 \x03(\x0b\x32\x0c.viz.Label2d*T\n\rImageEncoding\x12\x0b\n\x07\x45NCODED\x10\x00\x12\x08\n\x04RGB8\x10\x01\x12\x08\n\x04\x42GR8\x10\x02\x12\t\n\x05MONO8\x10\x03\x12\n\n\x06MONO16\x10\x04\x12\x0b\n\x07\x44\x45PTH16\x10\x05\x62\x06proto3'
 )
 
+_IMAGEENCODING = _descriptor.EnumDescriptor(
+  name='ImageEncoding',
+  full_name='viz.ImageEncoding',
+  filename=None,
+  file=DESCRIPTOR,
+  create_key=_descriptor._internal_create_key,
+  values=[
+    _descriptor.EnumValueDescriptor(
+      name='ENCODED', index=0, number=0,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+    _descriptor.EnumValueDescriptor(
+      name='RGB8', index=1, number=1,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+    _descriptor.EnumValueDescriptor(
+      name='BGR8', index=2, number=2,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+    _descriptor.EnumValueDescriptor(
+      name='MONO8', index=3, number=3,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+    _descriptor.EnumValueDescriptor(
+      name='MONO16', index=4, number=4,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+    _descriptor.EnumValueDescriptor(
+      name='DEPTH16', index=5, number=5,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+  ],
+  containing_type=None,
+  serialized_options=None,
+  serialized_start=951,
+  serialized_end=1035,
+)
+_sym_db.RegisterEnumDescriptor(_IMAGEENCODING)
+
+ImageEncoding = enum_type_wrapper.EnumTypeWrapper(_IMAGEENCODING)
+ENCODED = 0
+RGB8 = 1
+BGR8 = 2
+MONO8 = 3
+MONO16 = 4
+DEPTH16 = 5
 
 
 
@@ -79,6 +132,34 @@ _IMAGE = _descriptor.Descriptor(
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='width', full_name='viz.Image.width', index=2,
+      number=3, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='height', full_name='viz.Image.height', index=3,
+      number=4, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='encoding', full_name='viz.Image.encoding', index=4,
+      number=5, type=14, cpp_type=8, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='stride', full_name='viz.Image.stride', index=5,
+      number=6, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
   ],
   extensions=[
   ],
@@ -92,7 +173,7 @@ _IMAGE = _descriptor.Descriptor(
   oneofs=[
   ],
   serialized_start=47,
-  serialized_end=87,
+  serialized_end=172,
 )
 
 
@@ -130,8 +211,8 @@ _POINT2D = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=89,
-  serialized_end=120,
+  serialized_start=174,
+  serialized_end=205,
 )
 
 
@@ -176,14 +257,401 @@ _POINT3D = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=122,
-  serialized_end=164,
+  serialized_start=207,
+  serialized_end=249,
+)
+
+
+_COLOR = _descriptor.Descriptor(
+  name='Color',
+  full_name='viz.Color',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='r', full_name='viz.Color.r', index=0,
+      number=1, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='g', full_name='viz.Color.g', index=1,
+      number=2, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='b', full_name='viz.Color.b', index=2,
+      number=3, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='a', full_name='viz.Color.a', index=3,
+      number=4, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=251,
+  serialized_end=302,
+)
+
+
+_BOUNDINGBOX2D = _descriptor.Descriptor(
+  name='BoundingBox2d',
+  full_name='viz.BoundingBox2d',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='x', full_name='viz.BoundingBox2d.x', index=0,
+      number=1, type=2, cpp_type=6, label=1,
+      has_default_value=False, default_value=float(0),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='y', full_name='viz.BoundingBox2d.y', index=1,
+      number=2, type=2, cpp_type=6, label=1,
+      has_default_value=False, default_value=float(0),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='width', full_name='viz.BoundingBox2d.width', index=2,
+      number=3, type=2, cpp_type=6, label=1,
+      has_default_value=False, default_value=float(0),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='height', full_name='viz.BoundingBox2d.height', index=3,
+      number=4, type=2, cpp_type=6, label=1,
+      has_default_value=False, default_value=float(0),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='color', full_name='viz.BoundingBox2d.color', index=4,
+      number=5, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='label', full_name='viz.BoundingBox2d.label', index=5,
+      number=6, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=304,
+  serialized_end=414,
+)
+
+
+_POLYGON2D = _descriptor.Descriptor(
+  name='Polygon2d',
+  full_name='viz.Polygon2d',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='points', full_name='viz.Polygon2d.points', index=0,
+      number=1, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='color', full_name='viz.Polygon2d.color', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='label', full_name='viz.Polygon2d.label', index=2,
+      number=3, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=416,
+  serialized_end=499,
+)
+
+
+_POLYLINE2D = _descriptor.Descriptor(
+  name='Polyline2d',
+  full_name='viz.Polyline2d',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='points', full_name='viz.Polyline2d.points', index=0,
+      number=1, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='color', full_name='viz.Polyline2d.color', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='label', full_name='viz.Polyline2d.label', index=2,
+      number=3, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=501,
+  serialized_end=585,
 )
 
+
+_KEYPOINTS2D = _descriptor.Descriptor(
+  name='Keypoints2d',
+  full_name='viz.Keypoints2d',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='points', full_name='viz.Keypoints2d.points', index=0,
+      number=1, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='color', full_name='viz.Keypoints2d.color', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='label', full_name='viz.Keypoints2d.label', index=2,
+      number=3, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=587,
+  serialized_end=672,
+)
+
+
+_LABEL2D = _descriptor.Descriptor(
+  name='Label2d',
+  full_name='viz.Label2d',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='position', full_name='viz.Label2d.position', index=0,
+      number=1, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='text', full_name='viz.Label2d.text', index=1,
+      number=2, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='color', full_name='viz.Label2d.color', index=2,
+      number=3, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=674,
+  serialized_end=756,
+)
+
+
+_IMAGEANNOTATIONS = _descriptor.Descriptor(
+  name='ImageAnnotations',
+  full_name='viz.ImageAnnotations',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='boxes', full_name='viz.ImageAnnotations.boxes', index=0,
+      number=1, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='polygons', full_name='viz.ImageAnnotations.polygons', index=1,
+      number=2, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='polylines', full_name='viz.ImageAnnotations.polylines', index=2,
+      number=3, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='keypoints', full_name='viz.ImageAnnotations.keypoints', index=3,
+      number=4, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='labels', full_name='viz.ImageAnnotations.labels', index=4,
+      number=5, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=759,
+  serialized_end=949,
+)
+
+_IMAGE.fields_by_name['encoding'].enum_type = _IMAGEENCODING
+_BOUNDINGBOX2D.fields_by_name['color'].message_type = _COLOR
+_POLYGON2D.fields_by_name['points'].message_type = _POINT2D
+_POLYGON2D.fields_by_name['color'].message_type = _COLOR
+_POLYLINE2D.fields_by_name['points'].message_type = _POINT2D
+_POLYLINE2D.fields_by_name['color'].message_type = _COLOR
+_KEYPOINTS2D.fields_by_name['points'].message_type = _POINT2D
+_KEYPOINTS2D.fields_by_name['color'].message_type = _COLOR
+_LABEL2D.fields_by_name['position'].message_type = _POINT2D
+_LABEL2D.fields_by_name['color'].message_type = _COLOR
+_IMAGEANNOTATIONS.fields_by_name['boxes'].message_type = _BOUNDINGBOX2D
+_IMAGEANNOTATIONS.fields_by_name['polygons'].message_type = _POLYGON2D
+_IMAGEANNOTATIONS.fields_by_name['polylines'].message_type = _POLYLINE2D
+_IMAGEANNOTATIONS.fields_by_name['keypoints'].message_type = _KEYPOINTS2D
+_IMAGEANNOTATIONS.fields_by_name['labels'].message_type = _LABEL2D
 DESCRIPTOR.message_types_by_name['Text'] = _TEXT
 DESCRIPTOR.message_types_by_name['Image'] = _IMAGE
 DESCRIPTOR.message_types_by_name['Point2d'] = _POINT2D
 DESCRIPTOR.message_types_by_name['Point3d'] = _POINT3D
+DESCRIPTOR.message_types_by_name['Color'] = _COLOR
+DESCRIPTOR.message_types_by_name['BoundingBox2d'] = _BOUNDINGBOX2D
+DESCRIPTOR.message_types_by_name['Polygon2d'] = _POLYGON2D
+DESCRIPTOR.message_types_by_name['Polyline2d'] = _POLYLINE2D
+DESCRIPTOR.message_types_by_name['Keypoints2d'] = _KEYPOINTS2D
+DESCRIPTOR.message_types_by_name['Label2d'] = _LABEL2D
+DESCRIPTOR.message_types_by_name['ImageAnnotations'] = _IMAGEANNOTATIONS
+DESCRIPTOR.enum_types_by_name['ImageEncoding'] = _IMAGEENCODING
 _sym_db.RegisterFileDescriptor(DESCRIPTOR)
 
 Text = _reflection.GeneratedProtocolMessageType('Text', (_message.Message,), {
@@ -214,5 +682,54 @@ Point3d = _reflection.GeneratedProtocolMessageType('Point3d', (_message.Message,
   })
 _sym_db.RegisterMessage(Point3d)
 
+Color = _reflection.GeneratedProtocolMessageType('Color', (_message.Message,), {
+  'DESCRIPTOR' : _COLOR,
+  '__module__' : 'primitives_pb2'
+  # @@protoc_insertion_point(class_scope:viz.Color)
+  })
+_sym_db.RegisterMessage(Color)
+
+BoundingBox2d = _reflection.GeneratedProtocolMessageType('BoundingBox2d', (_message.Message,), {
+  'DESCRIPTOR' : _BOUNDINGBOX2D,
+  '__module__' : 'primitives_pb2'
+  # @@protoc_insertion_point(class_scope:viz.BoundingBox2d)
+  })
+_sym_db.RegisterMessage(BoundingBox2d)
+
+Polygon2d = _reflection.GeneratedProtocolMessageType('Polygon2d', (_message.Message,), {
+  'DESCRIPTOR' : _POLYGON2D,
+  '__module__' : 'primitives_pb2'
+  # @@protoc_insertion_point(class_scope:viz.Polygon2d)
+  })
+_sym_db.RegisterMessage(Polygon2d)
+
+Polyline2d = _reflection.GeneratedProtocolMessageType('Polyline2d', (_message.Message,), {
+  'DESCRIPTOR' : _POLYLINE2D,
+  '__module__' : 'primitives_pb2'
+  # @@protoc_insertion_point(class_scope:viz.Polyline2d)
+  })
+_sym_db.RegisterMessage(Polyline2d)
+
+Keypoints2d = _reflection.GeneratedProtocolMessageType('Keypoints2d', (_message.Message,), {
+  'DESCRIPTOR' : _KEYPOINTS2D,
+  '__module__' : 'primitives_pb2'
+  # @@protoc_insertion_point(class_scope:viz.Keypoints2d)
+  })
+_sym_db.RegisterMessage(Keypoints2d)
+
+Label2d = _reflection.GeneratedProtocolMessageType('Label2d', (_message.Message,), {
+  'DESCRIPTOR' : _LABEL2D,
+  '__module__' : 'primitives_pb2'
+  # @@protoc_insertion_point(class_scope:viz.Label2d)
+  })
+_sym_db.RegisterMessage(Label2d)
+
+ImageAnnotations = _reflection.GeneratedProtocolMessageType('ImageAnnotations', (_message.Message,), {
+  'DESCRIPTOR' : _IMAGEANNOTATIONS,
+  '__module__' : 'primitives_pb2'
+  # @@protoc_insertion_point(class_scope:viz.ImageAnnotations)
+  })
+_sym_db.RegisterMessage(ImageAnnotations)
+
 
 # @@protoc_insertion_point(module_scope)
@@ -392,24 +392,22 @@ impl ::protobuf::reflect::ProtobufValue for RequestCommandRecord {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct RequestStatus {
+pub struct RequestCommandRecordState {
     // message fields
     pub header: ::protobuf::SingularPtrField<RequestHeader>,
-    pub start_time: u64,
-    pub end_time: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a RequestStatus {
-    fn default() -> &'a RequestStatus {
-        <RequestStatus as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RequestCommandRecordState {
+    fn default() -> &'a RequestCommandRecordState {
+        <RequestCommandRecordState as ::protobuf::Message>::default_instance()
     }
 }
 
-impl RequestStatus {
-    pub fn new() -> RequestStatus {
+impl RequestCommandRecordState {
+    pub fn new() -> RequestCommandRecordState {
         ::std::default::Default::default()
     }
 
@@ -445,39 +443,9 @@ impl RequestStatus {
     pub fn take_header(&mut self) -> RequestHeader {
         self.header.take().unwrap_or_else(|| RequestHeader::new())
     }
-
-    // uint64 start_time = 2;
-
-
-    pub fn get_start_time(&self) -> u64 {
-        self.start_time
-    }
-    pub fn clear_start_time(&mut self) {
-        self.start_time = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_start_time(&mut self, v: u64) {
-        self.start_time = v;
-    }
-
-    // uint64 end_time = 3;
-
-
-    pub fn get_end_time(&self) -> u64 {
-        self.end_time
-    }
-    pub fn clear_end_time(&mut self) {
-        self.end_time = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_end_time(&mut self, v: u64) {
-        self.end_time = v;
-    }
 }
 
-impl ::protobuf::Message for RequestStatus {
+impl ::protobuf::Message for RequestCommandRecordState {
     fn is_initialized(&self) -> bool {
         for v in &self.header {
             if !v.is_initialized() {
@@ -494,20 +462,6 @@ impl ::protobuf::Message for RequestStatus {
                 1 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
                 },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.start_time = tmp;
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.end_time = tmp;
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -524,12 +478,6 @@ impl ::protobuf::Message for RequestStatus {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
-        if self.start_time != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.start_time, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.end_time != 0 {
-            my_size += ::protobuf::rt::value_size(3, self.end_time, ::protobuf::wire_format::WireTypeVarint);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -541,12 +489,6 @@ impl ::protobuf::Message for RequestStatus {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
-        if self.start_time != 0 {
-            os.write_uint64(2, self.start_time)?;
-        }
-        if self.end_time != 0 {
-            os.write_uint64(3, self.end_time)?;
-        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -577,8 +519,8 @@ impl ::protobuf::Message for RequestStatus {
         Self::descriptor_static()
     }
 
-    fn new() -> RequestStatus {
-        RequestStatus::new()
+    fn new() -> RequestCommandRecordState {
+        RequestCommandRecordState::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -587,72 +529,62 @@ impl ::protobuf::Message for RequestStatus {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
                 "header",
-                |m: &RequestStatus| { &m.header },
-                |m: &mut RequestStatus| { &mut m.header },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                "start_time",
-                |m: &RequestStatus| { &m.start_time },
-                |m: &mut RequestStatus| { &mut m.start_time },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                "end_time",
-                |m: &RequestStatus| { &m.end_time },
-                |m: &mut RequestStatus| { &mut m.end_time },
+                |m: &RequestCommandRecordState| { &m.header },
+                |m: &mut RequestCommandRecordState| { &mut m.header },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestStatus>(
-                "RequestStatus",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestCommandRecordState>(
+                "RequestCommandRecordState",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static RequestStatus {
-        static instance: ::protobuf::rt::LazyV2<RequestStatus> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(RequestStatus::new)
+    fn default_instance() -> &'static RequestCommandRecordState {
+        static instance: ::protobuf::rt::LazyV2<RequestCommandRecordState> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCommandRecordState::new)
     }
 }
 
-impl ::protobuf::Clear for RequestStatus {
+impl ::protobuf::Clear for RequestCommandRecordState {
     fn clear(&mut self) {
         self.header.clear();
-        self.start_time = 0;
-        self.end_time = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for RequestStatus {
+impl ::std::fmt::Debug for RequestCommandRecordState {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for RequestStatus {
+impl ::protobuf::reflect::ProtobufValue for RequestCommandRecordState {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct RequestStatusLive {
+pub struct RequestStatus {
     // message fields
     pub header: ::protobuf::SingularPtrField<RequestHeader>,
-    pub enable: bool,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub mode: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a RequestStatusLive {
-    fn default() -> &'a RequestStatusLive {
-        <RequestStatusLive as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RequestStatus {
+    fn default() -> &'a RequestStatus {
+        <RequestStatus as ::protobuf::Message>::default_instance()
     }
 }
 
-impl RequestStatusLive {
-    pub fn new() -> RequestStatusLive {
+impl RequestStatus {
+    pub fn new() -> RequestStatus {
         ::std::default::Default::default()
     }
 
@@ -689,23 +621,64 @@ impl RequestStatusLive {
         self.header.take().unwrap_or_else(|| RequestHeader::new())
     }
 
-    // bool enable = 2;
+    // uint64 start_time = 2;
 
 
-    pub fn get_enable(&self) -> bool {
-        self.enable
+    pub fn get_start_time(&self) -> u64 {
+        self.start_time
     }
-    pub fn clear_enable(&mut self) {
-        self.enable = false;
+    pub fn clear_start_time(&mut self) {
+        self.start_time = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_enable(&mut self, v: bool) {
-        self.enable = v;
+    pub fn set_start_time(&mut self, v: u64) {
+        self.start_time = v;
+    }
+
+    // uint64 end_time = 3;
+
+
+    pub fn get_end_time(&self) -> u64 {
+        self.end_time
+    }
+    pub fn clear_end_time(&mut self) {
+        self.end_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_time(&mut self, v: u64) {
+        self.end_time = v;
+    }
+
+    // string mode = 4;
+
+
+    pub fn get_mode(&self) -> &str {
+        &self.mode
+    }
+    pub fn clear_mode(&mut self) {
+        self.mode.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mode(&mut self, v: ::std::string::String) {
+        self.mode = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_mode(&mut self) -> &mut ::std::string::String {
+        &mut self.mode
+    }
+
+    // Take field
+    pub fn take_mode(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.mode, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for RequestStatusLive {
+impl ::protobuf::Message for RequestStatus {
     fn is_initialized(&self) -> bool {
         for v in &self.header {
             if !v.is_initialized() {
@@ -726,8 +699,18 @@ impl ::protobuf::Message for RequestStatusLive {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_bool()?;
-                    self.enable = tmp;
+                    let tmp = is.read_uint64()?;
+                    self.start_time = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.end_time = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.mode)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -745,8 +728,14 @@ impl ::protobuf::Message for RequestStatusLive {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
-        if self.enable != false {
-            my_size += 2;
+        if self.start_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.end_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.mode.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.mode);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -759,8 +748,14 @@ impl ::protobuf::Message for RequestStatusLive {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
-        if self.enable != false {
-            os.write_bool(2, self.enable)?;
+        if self.start_time != 0 {
+            os.write_uint64(2, self.start_time)?;
+        }
+        if self.end_time != 0 {
+            os.write_uint64(3, self.end_time)?;
+        }
+        if !self.mode.is_empty() {
+            os.write_string(4, &self.mode)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -792,8 +787,8 @@ impl ::protobuf::Message for RequestStatusLive {
         Self::descriptor_static()
     }
 
-    fn new() -> RequestStatusLive {
-        RequestStatusLive::new()
+    fn new() -> RequestStatus {
+        RequestStatus::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -802,43 +797,55 @@ impl ::protobuf::Message for RequestStatusLive {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
                 "header",
-                |m: &RequestStatusLive| { &m.header },
-                |m: &mut RequestStatusLive| { &mut m.header },
+                |m: &RequestStatus| { &m.header },
+                |m: &mut RequestStatus| { &mut m.header },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                "enable",
-                |m: &RequestStatusLive| { &m.enable },
-                |m: &mut RequestStatusLive| { &mut m.enable },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "start_time",
+                |m: &RequestStatus| { &m.start_time },
+                |m: &mut RequestStatus| { &mut m.start_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "end_time",
+                |m: &RequestStatus| { &m.end_time },
+                |m: &mut RequestStatus| { &mut m.end_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "mode",
+                |m: &RequestStatus| { &m.mode },
+                |m: &mut RequestStatus| { &mut m.mode },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestStatusLive>(
-                "RequestStatusLive",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestStatus>(
+                "RequestStatus",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static RequestStatusLive {
-        static instance: ::protobuf::rt::LazyV2<RequestStatusLive> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(RequestStatusLive::new)
+    fn default_instance() -> &'static RequestStatus {
+        static instance: ::protobuf::rt::LazyV2<RequestStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestStatus::new)
     }
 }
 
-impl ::protobuf::Clear for RequestStatusLive {
+impl ::protobuf::Clear for RequestStatus {
     fn clear(&mut self) {
         self.header.clear();
-        self.enable = false;
+        self.start_time = 0;
+        self.end_time = 0;
+        self.mode.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for RequestStatusLive {
+impl ::std::fmt::Debug for RequestStatus {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for RequestStatusLive {
+impl ::protobuf::reflect::ProtobufValue for RequestStatus {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
@@ -853,6 +860,8 @@ pub struct RequestImage {
     pub resource: ::std::string::String,
     pub scale_x: f32,
     pub scale_y: f32,
+    pub annotation: ::std::string::String,
+    pub overlay: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -987,6 +996,47 @@ impl RequestImage {
     pub fn set_scale_y(&mut self, v: f32) {
         self.scale_y = v;
     }
+
+    // string annotation = 7;
+
+
+    pub fn get_annotation(&self) -> &str {
+        &self.annotation
+    }
+    pub fn clear_annotation(&mut self) {
+        self.annotation.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_annotation(&mut self, v: ::std::string::String) {
+        self.annotation = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_annotation(&mut self) -> &mut ::std::string::String {
+        &mut self.annotation
+    }
+
+    // Take field
+    pub fn take_annotation(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.annotation, ::std::string::String::new())
+    }
+
+    // bool overlay = 8;
+
+
+    pub fn get_overlay(&self) -> bool {
+        self.overlay
+    }
+    pub fn clear_overlay(&mut self) {
+        self.overlay = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_overlay(&mut self, v: bool) {
+        self.overlay = v;
+    }
 }
 
 impl ::protobuf::Message for RequestImage {
@@ -1037,6 +1087,16 @@ impl ::protobuf::Message for RequestImage {
                     let tmp = is.read_float()?;
                     self.scale_y = tmp;
                 },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.annotation)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.overlay = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1068,6 +1128,12 @@ impl ::protobuf::Message for RequestImage {
         if self.scale_y != 0. {
             my_size += 5;
         }
+        if !self.annotation.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.annotation);
+        }
+        if self.overlay != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1094,6 +1160,12 @@ impl ::protobuf::Message for RequestImage {
         if self.scale_y != 0. {
             os.write_float(6, self.scale_y)?;
         }
+        if !self.annotation.is_empty() {
+            os.write_string(7, &self.annotation)?;
+        }
+        if self.overlay != false {
+            os.write_bool(8, self.overlay)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1162,6 +1234,16 @@ impl ::protobuf::Message for RequestImage {
                 |m: &RequestImage| { &m.scale_y },
                 |m: &mut RequestImage| { &mut m.scale_y },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "annotation",
+                |m: &RequestImage| { &m.annotation },
+                |m: &mut RequestImage| { &mut m.annotation },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "overlay",
+                |m: &RequestImage| { &m.overlay },
+                |m: &mut RequestImage| { &mut m.overlay },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestImage>(
                 "RequestImage",
                 fields,
@@ -1184,6 +1266,8 @@ impl ::protobuf::Clear for RequestImage {
         self.resource.clear();
         self.scale_x = 0.;
         self.scale_y = 0.;
+        self.annotation.clear();
+        self.overlay = false;
         self.unknown_fields.clear();
     }
 }
@@ -1201,27 +1285,28 @@ impl ::protobuf::reflect::ProtobufValue for RequestImage {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct RequestStreamImage {
+pub struct RequestThumbnails {
     // message fields
     pub header: ::protobuf::SingularPtrField<RequestHeader>,
     pub start_time: u64,
+    pub end_time: u64,
     pub resource: ::std::string::String,
-    pub scale_x: f32,
-    pub scale_y: f32,
-    pub client_id: ::std::string::String,
+    pub count: u32,
+    pub size: u32,
+    pub contact_sheet: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a RequestStreamImage {
-    fn default() -> &'a RequestStreamImage {
-        <RequestStreamImage as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RequestThumbnails {
+    fn default() -> &'a RequestThumbnails {
+        <RequestThumbnails as ::protobuf::Message>::default_instance()
     }
 }
 
-impl RequestStreamImage {
-    pub fn new() -> RequestStreamImage {
+impl RequestThumbnails {
+    pub fn new() -> RequestThumbnails {
         ::std::default::Default::default()
     }
 
@@ -1273,90 +1358,2885 @@ impl RequestStreamImage {
         self.start_time = v;
     }
 
-    // string resource = 3;
+    // uint64 end_time = 3;
 
 
-    pub fn get_resource(&self) -> &str {
-        &self.resource
+    pub fn get_end_time(&self) -> u64 {
+        self.end_time
     }
-    pub fn clear_resource(&mut self) {
-        self.resource.clear();
+    pub fn clear_end_time(&mut self) {
+        self.end_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_time(&mut self, v: u64) {
+        self.end_time = v;
+    }
+
+    // string resource = 4;
+
+
+    pub fn get_resource(&self) -> &str {
+        &self.resource
+    }
+    pub fn clear_resource(&mut self) {
+        self.resource.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resource(&mut self, v: ::std::string::String) {
+        self.resource = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resource(&mut self) -> &mut ::std::string::String {
+        &mut self.resource
+    }
+
+    // Take field
+    pub fn take_resource(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.resource, ::std::string::String::new())
+    }
+
+    // uint32 count = 5;
+
+
+    pub fn get_count(&self) -> u32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u32) {
+        self.count = v;
+    }
+
+    // uint32 size = 6;
+
+
+    pub fn get_size(&self) -> u32 {
+        self.size
+    }
+    pub fn clear_size(&mut self) {
+        self.size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: u32) {
+        self.size = v;
+    }
+
+    // bool contact_sheet = 7;
+
+
+    pub fn get_contact_sheet(&self) -> bool {
+        self.contact_sheet
+    }
+    pub fn clear_contact_sheet(&mut self) {
+        self.contact_sheet = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_contact_sheet(&mut self, v: bool) {
+        self.contact_sheet = v;
+    }
+}
+
+impl ::protobuf::Message for RequestThumbnails {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_time = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.end_time = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.resource)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.count = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.size = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.contact_sheet = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.start_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.end_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.resource.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.resource);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.contact_sheet != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.start_time != 0 {
+            os.write_uint64(2, self.start_time)?;
+        }
+        if self.end_time != 0 {
+            os.write_uint64(3, self.end_time)?;
+        }
+        if !self.resource.is_empty() {
+            os.write_string(4, &self.resource)?;
+        }
+        if self.count != 0 {
+            os.write_uint32(5, self.count)?;
+        }
+        if self.size != 0 {
+            os.write_uint32(6, self.size)?;
+        }
+        if self.contact_sheet != false {
+            os.write_bool(7, self.contact_sheet)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestThumbnails {
+        RequestThumbnails::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                "header",
+                |m: &RequestThumbnails| { &m.header },
+                |m: &mut RequestThumbnails| { &mut m.header },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "start_time",
+                |m: &RequestThumbnails| { &m.start_time },
+                |m: &mut RequestThumbnails| { &mut m.start_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "end_time",
+                |m: &RequestThumbnails| { &m.end_time },
+                |m: &mut RequestThumbnails| { &mut m.end_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "resource",
+                |m: &RequestThumbnails| { &m.resource },
+                |m: &mut RequestThumbnails| { &mut m.resource },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "count",
+                |m: &RequestThumbnails| { &m.count },
+                |m: &mut RequestThumbnails| { &mut m.count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "size",
+                |m: &RequestThumbnails| { &m.size },
+                |m: &mut RequestThumbnails| { &mut m.size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "contact_sheet",
+                |m: &RequestThumbnails| { &m.contact_sheet },
+                |m: &mut RequestThumbnails| { &mut m.contact_sheet },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestThumbnails>(
+                "RequestThumbnails",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RequestThumbnails {
+        static instance: ::protobuf::rt::LazyV2<RequestThumbnails> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestThumbnails::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestThumbnails {
+    fn clear(&mut self) {
+        self.header.clear();
+        self.start_time = 0;
+        self.end_time = 0;
+        self.resource.clear();
+        self.count = 0;
+        self.size = 0;
+        self.contact_sheet = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestThumbnails {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestThumbnails {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestStreamImage {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    pub start_time: u64,
+    pub resource: ::std::string::String,
+    pub scale_x: f32,
+    pub scale_y: f32,
+    pub client_id: ::std::string::String,
+    pub annotation: ::std::string::String,
+    pub overlay: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestStreamImage {
+    fn default() -> &'a RequestStreamImage {
+        <RequestStreamImage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestStreamImage {
+    pub fn new() -> RequestStreamImage {
+        ::std::default::Default::default()
+    }
+
+    // .viz.RequestHeader header = 1;
+
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| <RequestHeader as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+
+    // uint64 start_time = 2;
+
+
+    pub fn get_start_time(&self) -> u64 {
+        self.start_time
+    }
+    pub fn clear_start_time(&mut self) {
+        self.start_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_time(&mut self, v: u64) {
+        self.start_time = v;
+    }
+
+    // string resource = 3;
+
+
+    pub fn get_resource(&self) -> &str {
+        &self.resource
+    }
+    pub fn clear_resource(&mut self) {
+        self.resource.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resource(&mut self, v: ::std::string::String) {
+        self.resource = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resource(&mut self) -> &mut ::std::string::String {
+        &mut self.resource
+    }
+
+    // Take field
+    pub fn take_resource(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.resource, ::std::string::String::new())
+    }
+
+    // float scale_x = 4;
+
+
+    pub fn get_scale_x(&self) -> f32 {
+        self.scale_x
+    }
+    pub fn clear_scale_x(&mut self) {
+        self.scale_x = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scale_x(&mut self, v: f32) {
+        self.scale_x = v;
+    }
+
+    // float scale_y = 5;
+
+
+    pub fn get_scale_y(&self) -> f32 {
+        self.scale_y
+    }
+    pub fn clear_scale_y(&mut self) {
+        self.scale_y = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scale_y(&mut self, v: f32) {
+        self.scale_y = v;
+    }
+
+    // string client_id = 6;
+
+
+    pub fn get_client_id(&self) -> &str {
+        &self.client_id
+    }
+    pub fn clear_client_id(&mut self) {
+        self.client_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_client_id(&mut self, v: ::std::string::String) {
+        self.client_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_client_id(&mut self) -> &mut ::std::string::String {
+        &mut self.client_id
+    }
+
+    // Take field
+    pub fn take_client_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.client_id, ::std::string::String::new())
+    }
+
+    // string annotation = 7;
+
+
+    pub fn get_annotation(&self) -> &str {
+        &self.annotation
+    }
+    pub fn clear_annotation(&mut self) {
+        self.annotation.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_annotation(&mut self, v: ::std::string::String) {
+        self.annotation = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_annotation(&mut self) -> &mut ::std::string::String {
+        &mut self.annotation
+    }
+
+    // Take field
+    pub fn take_annotation(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.annotation, ::std::string::String::new())
+    }
+
+    // bool overlay = 8;
+
+
+    pub fn get_overlay(&self) -> bool {
+        self.overlay
+    }
+    pub fn clear_overlay(&mut self) {
+        self.overlay = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_overlay(&mut self, v: bool) {
+        self.overlay = v;
+    }
+}
+
+impl ::protobuf::Message for RequestStreamImage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_time = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.resource)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.scale_x = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.scale_y = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.client_id)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.annotation)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.overlay = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.start_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.resource.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.resource);
+        }
+        if self.scale_x != 0. {
+            my_size += 5;
+        }
+        if self.scale_y != 0. {
+            my_size += 5;
+        }
+        if !self.client_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.client_id);
+        }
+        if !self.annotation.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.annotation);
+        }
+        if self.overlay != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.start_time != 0 {
+            os.write_uint64(2, self.start_time)?;
+        }
+        if !self.resource.is_empty() {
+            os.write_string(3, &self.resource)?;
+        }
+        if self.scale_x != 0. {
+            os.write_float(4, self.scale_x)?;
+        }
+        if self.scale_y != 0. {
+            os.write_float(5, self.scale_y)?;
+        }
+        if !self.client_id.is_empty() {
+            os.write_string(6, &self.client_id)?;
+        }
+        if !self.annotation.is_empty() {
+            os.write_string(7, &self.annotation)?;
+        }
+        if self.overlay != false {
+            os.write_bool(8, self.overlay)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestStreamImage {
+        RequestStreamImage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                "header",
+                |m: &RequestStreamImage| { &m.header },
+                |m: &mut RequestStreamImage| { &mut m.header },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "start_time",
+                |m: &RequestStreamImage| { &m.start_time },
+                |m: &mut RequestStreamImage| { &mut m.start_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "resource",
+                |m: &RequestStreamImage| { &m.resource },
+                |m: &mut RequestStreamImage| { &mut m.resource },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
+                "scale_x",
+                |m: &RequestStreamImage| { &m.scale_x },
+                |m: &mut RequestStreamImage| { &mut m.scale_x },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
+                "scale_y",
+                |m: &RequestStreamImage| { &m.scale_y },
+                |m: &mut RequestStreamImage| { &mut m.scale_y },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "client_id",
+                |m: &RequestStreamImage| { &m.client_id },
+                |m: &mut RequestStreamImage| { &mut m.client_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "annotation",
+                |m: &RequestStreamImage| { &m.annotation },
+                |m: &mut RequestStreamImage| { &mut m.annotation },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "overlay",
+                |m: &RequestStreamImage| { &m.overlay },
+                |m: &mut RequestStreamImage| { &mut m.overlay },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestStreamImage>(
+                "RequestStreamImage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RequestStreamImage {
+        static instance: ::protobuf::rt::LazyV2<RequestStreamImage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestStreamImage::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestStreamImage {
+    fn clear(&mut self) {
+        self.header.clear();
+        self.start_time = 0;
+        self.resource.clear();
+        self.scale_x = 0.;
+        self.scale_y = 0.;
+        self.client_id.clear();
+        self.annotation.clear();
+        self.overlay = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestStreamImage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestStreamImage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestImageSubscribe {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    pub resource: ::std::string::String,
+    pub scale_x: f32,
+    pub scale_y: f32,
+    pub annotation: ::std::string::String,
+    pub overlay: bool,
+    pub max_rate: f64,
+    pub latest_only: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestImageSubscribe {
+    fn default() -> &'a RequestImageSubscribe {
+        <RequestImageSubscribe as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestImageSubscribe {
+    pub fn new() -> RequestImageSubscribe {
+        ::std::default::Default::default()
+    }
+
+    // .viz.RequestHeader header = 1;
+
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| <RequestHeader as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+
+    // string resource = 2;
+
+
+    pub fn get_resource(&self) -> &str {
+        &self.resource
+    }
+    pub fn clear_resource(&mut self) {
+        self.resource.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resource(&mut self, v: ::std::string::String) {
+        self.resource = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resource(&mut self) -> &mut ::std::string::String {
+        &mut self.resource
+    }
+
+    // Take field
+    pub fn take_resource(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.resource, ::std::string::String::new())
+    }
+
+    // float scale_x = 3;
+
+
+    pub fn get_scale_x(&self) -> f32 {
+        self.scale_x
+    }
+    pub fn clear_scale_x(&mut self) {
+        self.scale_x = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scale_x(&mut self, v: f32) {
+        self.scale_x = v;
+    }
+
+    // float scale_y = 4;
+
+
+    pub fn get_scale_y(&self) -> f32 {
+        self.scale_y
+    }
+    pub fn clear_scale_y(&mut self) {
+        self.scale_y = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scale_y(&mut self, v: f32) {
+        self.scale_y = v;
+    }
+
+    // string annotation = 5;
+
+
+    pub fn get_annotation(&self) -> &str {
+        &self.annotation
+    }
+    pub fn clear_annotation(&mut self) {
+        self.annotation.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_annotation(&mut self, v: ::std::string::String) {
+        self.annotation = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_annotation(&mut self) -> &mut ::std::string::String {
+        &mut self.annotation
+    }
+
+    // Take field
+    pub fn take_annotation(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.annotation, ::std::string::String::new())
+    }
+
+    // bool overlay = 6;
+
+
+    pub fn get_overlay(&self) -> bool {
+        self.overlay
+    }
+    pub fn clear_overlay(&mut self) {
+        self.overlay = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_overlay(&mut self, v: bool) {
+        self.overlay = v;
+    }
+
+    // double max_rate = 7;
+
+
+    pub fn get_max_rate(&self) -> f64 {
+        self.max_rate
+    }
+    pub fn clear_max_rate(&mut self) {
+        self.max_rate = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_rate(&mut self, v: f64) {
+        self.max_rate = v;
+    }
+
+    // bool latest_only = 8;
+
+
+    pub fn get_latest_only(&self) -> bool {
+        self.latest_only
+    }
+    pub fn clear_latest_only(&mut self) {
+        self.latest_only = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_latest_only(&mut self, v: bool) {
+        self.latest_only = v;
+    }
+}
+
+impl ::protobuf::Message for RequestImageSubscribe {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.resource)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.scale_x = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.scale_y = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.annotation)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.overlay = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.max_rate = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.latest_only = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.resource.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.resource);
+        }
+        if self.scale_x != 0. {
+            my_size += 5;
+        }
+        if self.scale_y != 0. {
+            my_size += 5;
+        }
+        if !self.annotation.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.annotation);
+        }
+        if self.overlay != false {
+            my_size += 2;
+        }
+        if self.max_rate != 0. {
+            my_size += 9;
+        }
+        if self.latest_only != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.resource.is_empty() {
+            os.write_string(2, &self.resource)?;
+        }
+        if self.scale_x != 0. {
+            os.write_float(3, self.scale_x)?;
+        }
+        if self.scale_y != 0. {
+            os.write_float(4, self.scale_y)?;
+        }
+        if !self.annotation.is_empty() {
+            os.write_string(5, &self.annotation)?;
+        }
+        if self.overlay != false {
+            os.write_bool(6, self.overlay)?;
+        }
+        if self.max_rate != 0. {
+            os.write_double(7, self.max_rate)?;
+        }
+        if self.latest_only != false {
+            os.write_bool(8, self.latest_only)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestImageSubscribe {
+        RequestImageSubscribe::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                "header",
+                |m: &RequestImageSubscribe| { &m.header },
+                |m: &mut RequestImageSubscribe| { &mut m.header },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "resource",
+                |m: &RequestImageSubscribe| { &m.resource },
+                |m: &mut RequestImageSubscribe| { &mut m.resource },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
+                "scale_x",
+                |m: &RequestImageSubscribe| { &m.scale_x },
+                |m: &mut RequestImageSubscribe| { &mut m.scale_x },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
+                "scale_y",
+                |m: &RequestImageSubscribe| { &m.scale_y },
+                |m: &mut RequestImageSubscribe| { &mut m.scale_y },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "annotation",
+                |m: &RequestImageSubscribe| { &m.annotation },
+                |m: &mut RequestImageSubscribe| { &mut m.annotation },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "overlay",
+                |m: &RequestImageSubscribe| { &m.overlay },
+                |m: &mut RequestImageSubscribe| { &mut m.overlay },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "max_rate",
+                |m: &RequestImageSubscribe| { &m.max_rate },
+                |m: &mut RequestImageSubscribe| { &mut m.max_rate },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "latest_only",
+                |m: &RequestImageSubscribe| { &m.latest_only },
+                |m: &mut RequestImageSubscribe| { &mut m.latest_only },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestImageSubscribe>(
+                "RequestImageSubscribe",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RequestImageSubscribe {
+        static instance: ::protobuf::rt::LazyV2<RequestImageSubscribe> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestImageSubscribe::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestImageSubscribe {
+    fn clear(&mut self) {
+        self.header.clear();
+        self.resource.clear();
+        self.scale_x = 0.;
+        self.scale_y = 0.;
+        self.annotation.clear();
+        self.overlay = false;
+        self.max_rate = 0.;
+        self.latest_only = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestImageSubscribe {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestImageSubscribe {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestImageUnsubscribe {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    pub resource: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestImageUnsubscribe {
+    fn default() -> &'a RequestImageUnsubscribe {
+        <RequestImageUnsubscribe as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestImageUnsubscribe {
+    pub fn new() -> RequestImageUnsubscribe {
+        ::std::default::Default::default()
+    }
+
+    // .viz.RequestHeader header = 1;
+
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| <RequestHeader as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+
+    // string resource = 2;
+
+
+    pub fn get_resource(&self) -> &str {
+        &self.resource
+    }
+    pub fn clear_resource(&mut self) {
+        self.resource.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resource(&mut self, v: ::std::string::String) {
+        self.resource = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resource(&mut self) -> &mut ::std::string::String {
+        &mut self.resource
+    }
+
+    // Take field
+    pub fn take_resource(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.resource, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RequestImageUnsubscribe {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.resource)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.resource.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.resource);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.resource.is_empty() {
+            os.write_string(2, &self.resource)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestImageUnsubscribe {
+        RequestImageUnsubscribe::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                "header",
+                |m: &RequestImageUnsubscribe| { &m.header },
+                |m: &mut RequestImageUnsubscribe| { &mut m.header },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "resource",
+                |m: &RequestImageUnsubscribe| { &m.resource },
+                |m: &mut RequestImageUnsubscribe| { &mut m.resource },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestImageUnsubscribe>(
+                "RequestImageUnsubscribe",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RequestImageUnsubscribe {
+        static instance: ::protobuf::rt::LazyV2<RequestImageUnsubscribe> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestImageUnsubscribe::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestImageUnsubscribe {
+    fn clear(&mut self) {
+        self.header.clear();
+        self.resource.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestImageUnsubscribe {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestImageUnsubscribe {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestStopStreamImage {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    pub client_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestStopStreamImage {
+    fn default() -> &'a RequestStopStreamImage {
+        <RequestStopStreamImage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestStopStreamImage {
+    pub fn new() -> RequestStopStreamImage {
+        ::std::default::Default::default()
+    }
+
+    // .viz.RequestHeader header = 1;
+
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| <RequestHeader as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+
+    // string client_id = 2;
+
+
+    pub fn get_client_id(&self) -> &str {
+        &self.client_id
+    }
+    pub fn clear_client_id(&mut self) {
+        self.client_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_client_id(&mut self, v: ::std::string::String) {
+        self.client_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_client_id(&mut self) -> &mut ::std::string::String {
+        &mut self.client_id
+    }
+
+    // Take field
+    pub fn take_client_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.client_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RequestStopStreamImage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.client_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.client_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.client_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.client_id.is_empty() {
+            os.write_string(2, &self.client_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestStopStreamImage {
+        RequestStopStreamImage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                "header",
+                |m: &RequestStopStreamImage| { &m.header },
+                |m: &mut RequestStopStreamImage| { &mut m.header },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "client_id",
+                |m: &RequestStopStreamImage| { &m.client_id },
+                |m: &mut RequestStopStreamImage| { &mut m.client_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestStopStreamImage>(
+                "RequestStopStreamImage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RequestStopStreamImage {
+        static instance: ::protobuf::rt::LazyV2<RequestStopStreamImage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestStopStreamImage::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestStopStreamImage {
+    fn clear(&mut self) {
+        self.header.clear();
+        self.client_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestStopStreamImage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestStopStreamImage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestSeriesQuery {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub topic: ::std::string::String,
+    pub field: ::std::string::String,
+    pub count: u32,
+    pub method: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestSeriesQuery {
+    fn default() -> &'a RequestSeriesQuery {
+        <RequestSeriesQuery as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestSeriesQuery {
+    pub fn new() -> RequestSeriesQuery {
+        ::std::default::Default::default()
+    }
+
+    // .viz.RequestHeader header = 1;
+
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| <RequestHeader as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+
+    // uint64 start_time = 2;
+
+
+    pub fn get_start_time(&self) -> u64 {
+        self.start_time
+    }
+    pub fn clear_start_time(&mut self) {
+        self.start_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_time(&mut self, v: u64) {
+        self.start_time = v;
+    }
+
+    // uint64 end_time = 3;
+
+
+    pub fn get_end_time(&self) -> u64 {
+        self.end_time
+    }
+    pub fn clear_end_time(&mut self) {
+        self.end_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_time(&mut self, v: u64) {
+        self.end_time = v;
+    }
+
+    // string topic = 4;
+
+
+    pub fn get_topic(&self) -> &str {
+        &self.topic
+    }
+    pub fn clear_topic(&mut self) {
+        self.topic.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_topic(&mut self, v: ::std::string::String) {
+        self.topic = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_topic(&mut self) -> &mut ::std::string::String {
+        &mut self.topic
+    }
+
+    // Take field
+    pub fn take_topic(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.topic, ::std::string::String::new())
+    }
+
+    // string field = 5;
+
+
+    pub fn get_field(&self) -> &str {
+        &self.field
+    }
+    pub fn clear_field(&mut self) {
+        self.field.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field(&mut self, v: ::std::string::String) {
+        self.field = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field(&mut self) -> &mut ::std::string::String {
+        &mut self.field
+    }
+
+    // Take field
+    pub fn take_field(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field, ::std::string::String::new())
+    }
+
+    // uint32 count = 6;
+
+
+    pub fn get_count(&self) -> u32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u32) {
+        self.count = v;
+    }
+
+    // string method = 7;
+
+
+    pub fn get_method(&self) -> &str {
+        &self.method
+    }
+    pub fn clear_method(&mut self) {
+        self.method.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_method(&mut self, v: ::std::string::String) {
+        self.method = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_method(&mut self) -> &mut ::std::string::String {
+        &mut self.method
+    }
+
+    // Take field
+    pub fn take_method(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.method, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RequestSeriesQuery {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_time = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.end_time = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.topic)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.count = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.method)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.start_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.end_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.topic.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.topic);
+        }
+        if !self.field.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.field);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.method.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.method);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.start_time != 0 {
+            os.write_uint64(2, self.start_time)?;
+        }
+        if self.end_time != 0 {
+            os.write_uint64(3, self.end_time)?;
+        }
+        if !self.topic.is_empty() {
+            os.write_string(4, &self.topic)?;
+        }
+        if !self.field.is_empty() {
+            os.write_string(5, &self.field)?;
+        }
+        if self.count != 0 {
+            os.write_uint32(6, self.count)?;
+        }
+        if !self.method.is_empty() {
+            os.write_string(7, &self.method)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestSeriesQuery {
+        RequestSeriesQuery::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                "header",
+                |m: &RequestSeriesQuery| { &m.header },
+                |m: &mut RequestSeriesQuery| { &mut m.header },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "start_time",
+                |m: &RequestSeriesQuery| { &m.start_time },
+                |m: &mut RequestSeriesQuery| { &mut m.start_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "end_time",
+                |m: &RequestSeriesQuery| { &m.end_time },
+                |m: &mut RequestSeriesQuery| { &mut m.end_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "topic",
+                |m: &RequestSeriesQuery| { &m.topic },
+                |m: &mut RequestSeriesQuery| { &mut m.topic },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "field",
+                |m: &RequestSeriesQuery| { &m.field },
+                |m: &mut RequestSeriesQuery| { &mut m.field },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "count",
+                |m: &RequestSeriesQuery| { &m.count },
+                |m: &mut RequestSeriesQuery| { &mut m.count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "method",
+                |m: &RequestSeriesQuery| { &m.method },
+                |m: &mut RequestSeriesQuery| { &mut m.method },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestSeriesQuery>(
+                "RequestSeriesQuery",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RequestSeriesQuery {
+        static instance: ::protobuf::rt::LazyV2<RequestSeriesQuery> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestSeriesQuery::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestSeriesQuery {
+    fn clear(&mut self) {
+        self.header.clear();
+        self.start_time = 0;
+        self.end_time = 0;
+        self.topic.clear();
+        self.field.clear();
+        self.count = 0;
+        self.method.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestSeriesQuery {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestSeriesQuery {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestStreamCredit {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    pub stream_id: u32,
+    pub credits: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestStreamCredit {
+    fn default() -> &'a RequestStreamCredit {
+        <RequestStreamCredit as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestStreamCredit {
+    pub fn new() -> RequestStreamCredit {
+        ::std::default::Default::default()
+    }
+
+    // .viz.RequestHeader header = 1;
+
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| <RequestHeader as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+
+    // uint32 stream_id = 4;
+
+
+    pub fn get_stream_id(&self) -> u32 {
+        self.stream_id
+    }
+    pub fn clear_stream_id(&mut self) {
+        self.stream_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stream_id(&mut self, v: u32) {
+        self.stream_id = v;
+    }
+
+    // uint32 credits = 3;
+
+
+    pub fn get_credits(&self) -> u32 {
+        self.credits
+    }
+    pub fn clear_credits(&mut self) {
+        self.credits = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_credits(&mut self, v: u32) {
+        self.credits = v;
+    }
+}
+
+impl ::protobuf::Message for RequestStreamCredit {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.stream_id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.credits = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.stream_id != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.stream_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.credits != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.credits, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.stream_id != 0 {
+            os.write_uint32(4, self.stream_id)?;
+        }
+        if self.credits != 0 {
+            os.write_uint32(3, self.credits)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestStreamCredit {
+        RequestStreamCredit::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                "header",
+                |m: &RequestStreamCredit| { &m.header },
+                |m: &mut RequestStreamCredit| { &mut m.header },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "stream_id",
+                |m: &RequestStreamCredit| { &m.stream_id },
+                |m: &mut RequestStreamCredit| { &mut m.stream_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "credits",
+                |m: &RequestStreamCredit| { &m.credits },
+                |m: &mut RequestStreamCredit| { &mut m.credits },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestStreamCredit>(
+                "RequestStreamCredit",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RequestStreamCredit {
+        static instance: ::protobuf::rt::LazyV2<RequestStreamCredit> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestStreamCredit::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestStreamCredit {
+    fn clear(&mut self) {
+        self.header.clear();
+        self.stream_id = 0;
+        self.credits = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestStreamCredit {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestStreamCredit {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestTopicList {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestTopicList {
+    fn default() -> &'a RequestTopicList {
+        <RequestTopicList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestTopicList {
+    pub fn new() -> RequestTopicList {
+        ::std::default::Default::default()
+    }
+
+    // .viz.RequestHeader header = 1;
+
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| <RequestHeader as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+}
+
+impl ::protobuf::Message for RequestTopicList {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestTopicList {
+        RequestTopicList::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                "header",
+                |m: &RequestTopicList| { &m.header },
+                |m: &mut RequestTopicList| { &mut m.header },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestTopicList>(
+                "RequestTopicList",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RequestTopicList {
+        static instance: ::protobuf::rt::LazyV2<RequestTopicList> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestTopicList::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestTopicList {
+    fn clear(&mut self) {
+        self.header.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestTopicList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestTopicList {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestTopicDecode {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    pub topic: ::std::string::String,
+    pub timestamp: u64,
+    pub format: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestTopicDecode {
+    fn default() -> &'a RequestTopicDecode {
+        <RequestTopicDecode as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestTopicDecode {
+    pub fn new() -> RequestTopicDecode {
+        ::std::default::Default::default()
+    }
+
+    // .viz.RequestHeader header = 1;
+
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| <RequestHeader as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+
+    // string topic = 2;
+
+
+    pub fn get_topic(&self) -> &str {
+        &self.topic
+    }
+    pub fn clear_topic(&mut self) {
+        self.topic.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_topic(&mut self, v: ::std::string::String) {
+        self.topic = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_topic(&mut self) -> &mut ::std::string::String {
+        &mut self.topic
+    }
+
+    // Take field
+    pub fn take_topic(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.topic, ::std::string::String::new())
+    }
+
+    // uint64 timestamp = 3;
+
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: u64) {
+        self.timestamp = v;
+    }
+
+    // string format = 4;
+
+
+    pub fn get_format(&self) -> &str {
+        &self.format
+    }
+    pub fn clear_format(&mut self) {
+        self.format.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_format(&mut self, v: ::std::string::String) {
+        self.format = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_format(&mut self) -> &mut ::std::string::String {
+        &mut self.format
+    }
+
+    // Take field
+    pub fn take_format(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.format, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RequestTopicDecode {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.topic)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.timestamp = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.format)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.topic.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.topic);
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.format.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.format);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.topic.is_empty() {
+            os.write_string(2, &self.topic)?;
+        }
+        if self.timestamp != 0 {
+            os.write_uint64(3, self.timestamp)?;
+        }
+        if !self.format.is_empty() {
+            os.write_string(4, &self.format)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestTopicDecode {
+        RequestTopicDecode::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                "header",
+                |m: &RequestTopicDecode| { &m.header },
+                |m: &mut RequestTopicDecode| { &mut m.header },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "topic",
+                |m: &RequestTopicDecode| { &m.topic },
+                |m: &mut RequestTopicDecode| { &mut m.topic },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "timestamp",
+                |m: &RequestTopicDecode| { &m.timestamp },
+                |m: &mut RequestTopicDecode| { &mut m.timestamp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "format",
+                |m: &RequestTopicDecode| { &m.format },
+                |m: &mut RequestTopicDecode| { &mut m.format },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestTopicDecode>(
+                "RequestTopicDecode",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RequestTopicDecode {
+        static instance: ::protobuf::rt::LazyV2<RequestTopicDecode> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestTopicDecode::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestTopicDecode {
+    fn clear(&mut self) {
+        self.header.clear();
+        self.topic.clear();
+        self.timestamp = 0;
+        self.format.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestTopicDecode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestTopicDecode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestTopicSubscribe {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    pub topic: ::std::string::String,
+    pub format: ::std::string::String,
+    pub max_rate: f64,
+    pub latest_only: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestTopicSubscribe {
+    fn default() -> &'a RequestTopicSubscribe {
+        <RequestTopicSubscribe as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestTopicSubscribe {
+    pub fn new() -> RequestTopicSubscribe {
+        ::std::default::Default::default()
+    }
+
+    // .viz.RequestHeader header = 1;
+
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| <RequestHeader as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_resource(&mut self, v: ::std::string::String) {
-        self.resource = v;
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_resource(&mut self) -> &mut ::std::string::String {
-        &mut self.resource
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_resource(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.resource, ::std::string::String::new())
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
     }
 
-    // float scale_x = 4;
+    // string topic = 2;
 
 
-    pub fn get_scale_x(&self) -> f32 {
-        self.scale_x
+    pub fn get_topic(&self) -> &str {
+        &self.topic
     }
-    pub fn clear_scale_x(&mut self) {
-        self.scale_x = 0.;
+    pub fn clear_topic(&mut self) {
+        self.topic.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_scale_x(&mut self, v: f32) {
-        self.scale_x = v;
+    pub fn set_topic(&mut self, v: ::std::string::String) {
+        self.topic = v;
     }
 
-    // float scale_y = 5;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_topic(&mut self) -> &mut ::std::string::String {
+        &mut self.topic
+    }
+
+    // Take field
+    pub fn take_topic(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.topic, ::std::string::String::new())
+    }
 
+    // string format = 3;
 
-    pub fn get_scale_y(&self) -> f32 {
-        self.scale_y
+
+    pub fn get_format(&self) -> &str {
+        &self.format
     }
-    pub fn clear_scale_y(&mut self) {
-        self.scale_y = 0.;
+    pub fn clear_format(&mut self) {
+        self.format.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_scale_y(&mut self, v: f32) {
-        self.scale_y = v;
+    pub fn set_format(&mut self, v: ::std::string::String) {
+        self.format = v;
     }
 
-    // string client_id = 6;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_format(&mut self) -> &mut ::std::string::String {
+        &mut self.format
+    }
 
+    // Take field
+    pub fn take_format(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.format, ::std::string::String::new())
+    }
 
-    pub fn get_client_id(&self) -> &str {
-        &self.client_id
+    // double max_rate = 4;
+
+
+    pub fn get_max_rate(&self) -> f64 {
+        self.max_rate
     }
-    pub fn clear_client_id(&mut self) {
-        self.client_id.clear();
+    pub fn clear_max_rate(&mut self) {
+        self.max_rate = 0.;
     }
 
     // Param is passed by value, moved
-    pub fn set_client_id(&mut self, v: ::std::string::String) {
-        self.client_id = v;
+    pub fn set_max_rate(&mut self, v: f64) {
+        self.max_rate = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_client_id(&mut self) -> &mut ::std::string::String {
-        &mut self.client_id
+    // bool latest_only = 5;
+
+
+    pub fn get_latest_only(&self) -> bool {
+        self.latest_only
+    }
+    pub fn clear_latest_only(&mut self) {
+        self.latest_only = false;
     }
 
-    // Take field
-    pub fn take_client_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.client_id, ::std::string::String::new())
+    // Param is passed by value, moved
+    pub fn set_latest_only(&mut self, v: bool) {
+        self.latest_only = v;
     }
 }
 
-impl ::protobuf::Message for RequestStreamImage {
+impl ::protobuf::Message for RequestTopicSubscribe {
     fn is_initialized(&self) -> bool {
         for v in &self.header {
             if !v.is_initialized() {
@@ -1374,31 +4254,24 @@ impl ::protobuf::Message for RequestStreamImage {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.start_time = tmp;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.topic)?;
                 },
                 3 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.resource)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.format)?;
                 },
                 4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_float()?;
-                    self.scale_x = tmp;
+                    let tmp = is.read_double()?;
+                    self.max_rate = tmp;
                 },
                 5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_float()?;
-                    self.scale_y = tmp;
-                },
-                6 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.client_id)?;
+                    let tmp = is.read_bool()?;
+                    self.latest_only = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1416,20 +4289,17 @@ impl ::protobuf::Message for RequestStreamImage {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
-        if self.start_time != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.start_time, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if !self.resource.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.resource);
+        if !self.topic.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.topic);
         }
-        if self.scale_x != 0. {
-            my_size += 5;
+        if !self.format.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.format);
         }
-        if self.scale_y != 0. {
-            my_size += 5;
+        if self.max_rate != 0. {
+            my_size += 9;
         }
-        if !self.client_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(6, &self.client_id);
+        if self.latest_only != false {
+            my_size += 2;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1442,20 +4312,17 @@ impl ::protobuf::Message for RequestStreamImage {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
-        if self.start_time != 0 {
-            os.write_uint64(2, self.start_time)?;
-        }
-        if !self.resource.is_empty() {
-            os.write_string(3, &self.resource)?;
+        if !self.topic.is_empty() {
+            os.write_string(2, &self.topic)?;
         }
-        if self.scale_x != 0. {
-            os.write_float(4, self.scale_x)?;
+        if !self.format.is_empty() {
+            os.write_string(3, &self.format)?;
         }
-        if self.scale_y != 0. {
-            os.write_float(5, self.scale_y)?;
+        if self.max_rate != 0. {
+            os.write_double(4, self.max_rate)?;
         }
-        if !self.client_id.is_empty() {
-            os.write_string(6, &self.client_id)?;
+        if self.latest_only != false {
+            os.write_bool(5, self.latest_only)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1487,8 +4354,8 @@ impl ::protobuf::Message for RequestStreamImage {
         Self::descriptor_static()
     }
 
-    fn new() -> RequestStreamImage {
-        RequestStreamImage::new()
+    fn new() -> RequestTopicSubscribe {
+        RequestTopicSubscribe::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1497,90 +4364,84 @@ impl ::protobuf::Message for RequestStreamImage {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
                 "header",
-                |m: &RequestStreamImage| { &m.header },
-                |m: &mut RequestStreamImage| { &mut m.header },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                "start_time",
-                |m: &RequestStreamImage| { &m.start_time },
-                |m: &mut RequestStreamImage| { &mut m.start_time },
+                |m: &RequestTopicSubscribe| { &m.header },
+                |m: &mut RequestTopicSubscribe| { &mut m.header },
             ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "resource",
-                |m: &RequestStreamImage| { &m.resource },
-                |m: &mut RequestStreamImage| { &mut m.resource },
+                "topic",
+                |m: &RequestTopicSubscribe| { &m.topic },
+                |m: &mut RequestTopicSubscribe| { &mut m.topic },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
-                "scale_x",
-                |m: &RequestStreamImage| { &m.scale_x },
-                |m: &mut RequestStreamImage| { &mut m.scale_x },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "format",
+                |m: &RequestTopicSubscribe| { &m.format },
+                |m: &mut RequestTopicSubscribe| { &mut m.format },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
-                "scale_y",
-                |m: &RequestStreamImage| { &m.scale_y },
-                |m: &mut RequestStreamImage| { &mut m.scale_y },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "max_rate",
+                |m: &RequestTopicSubscribe| { &m.max_rate },
+                |m: &mut RequestTopicSubscribe| { &mut m.max_rate },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "client_id",
-                |m: &RequestStreamImage| { &m.client_id },
-                |m: &mut RequestStreamImage| { &mut m.client_id },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "latest_only",
+                |m: &RequestTopicSubscribe| { &m.latest_only },
+                |m: &mut RequestTopicSubscribe| { &mut m.latest_only },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestStreamImage>(
-                "RequestStreamImage",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestTopicSubscribe>(
+                "RequestTopicSubscribe",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static RequestStreamImage {
-        static instance: ::protobuf::rt::LazyV2<RequestStreamImage> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(RequestStreamImage::new)
+    fn default_instance() -> &'static RequestTopicSubscribe {
+        static instance: ::protobuf::rt::LazyV2<RequestTopicSubscribe> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestTopicSubscribe::new)
     }
 }
 
-impl ::protobuf::Clear for RequestStreamImage {
+impl ::protobuf::Clear for RequestTopicSubscribe {
     fn clear(&mut self) {
         self.header.clear();
-        self.start_time = 0;
-        self.resource.clear();
-        self.scale_x = 0.;
-        self.scale_y = 0.;
-        self.client_id.clear();
+        self.topic.clear();
+        self.format.clear();
+        self.max_rate = 0.;
+        self.latest_only = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for RequestStreamImage {
+impl ::std::fmt::Debug for RequestTopicSubscribe {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for RequestStreamImage {
+impl ::protobuf::reflect::ProtobufValue for RequestTopicSubscribe {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct RequestStopStreamImage {
+pub struct RequestTopicUnsubscribe {
     // message fields
     pub header: ::protobuf::SingularPtrField<RequestHeader>,
-    pub client_id: ::std::string::String,
+    pub topic: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a RequestStopStreamImage {
-    fn default() -> &'a RequestStopStreamImage {
-        <RequestStopStreamImage as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RequestTopicUnsubscribe {
+    fn default() -> &'a RequestTopicUnsubscribe {
+        <RequestTopicUnsubscribe as ::protobuf::Message>::default_instance()
     }
 }
 
-impl RequestStopStreamImage {
-    pub fn new() -> RequestStopStreamImage {
+impl RequestTopicUnsubscribe {
+    pub fn new() -> RequestTopicUnsubscribe {
         ::std::default::Default::default()
     }
 
@@ -1617,34 +4478,34 @@ impl RequestStopStreamImage {
         self.header.take().unwrap_or_else(|| RequestHeader::new())
     }
 
-    // string client_id = 2;
+    // string topic = 2;
 
 
-    pub fn get_client_id(&self) -> &str {
-        &self.client_id
+    pub fn get_topic(&self) -> &str {
+        &self.topic
     }
-    pub fn clear_client_id(&mut self) {
-        self.client_id.clear();
+    pub fn clear_topic(&mut self) {
+        self.topic.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_client_id(&mut self, v: ::std::string::String) {
-        self.client_id = v;
+    pub fn set_topic(&mut self, v: ::std::string::String) {
+        self.topic = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_client_id(&mut self) -> &mut ::std::string::String {
-        &mut self.client_id
+    pub fn mut_topic(&mut self) -> &mut ::std::string::String {
+        &mut self.topic
     }
 
     // Take field
-    pub fn take_client_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.client_id, ::std::string::String::new())
+    pub fn take_topic(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.topic, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for RequestStopStreamImage {
+impl ::protobuf::Message for RequestTopicUnsubscribe {
     fn is_initialized(&self) -> bool {
         for v in &self.header {
             if !v.is_initialized() {
@@ -1662,7 +4523,7 @@ impl ::protobuf::Message for RequestStopStreamImage {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.client_id)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.topic)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1680,8 +4541,8 @@ impl ::protobuf::Message for RequestStopStreamImage {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
-        if !self.client_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.client_id);
+        if !self.topic.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.topic);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1694,8 +4555,8 @@ impl ::protobuf::Message for RequestStopStreamImage {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
-        if !self.client_id.is_empty() {
-            os.write_string(2, &self.client_id)?;
+        if !self.topic.is_empty() {
+            os.write_string(2, &self.topic)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1727,8 +4588,8 @@ impl ::protobuf::Message for RequestStopStreamImage {
         Self::descriptor_static()
     }
 
-    fn new() -> RequestStopStreamImage {
-        RequestStopStreamImage::new()
+    fn new() -> RequestTopicUnsubscribe {
+        RequestTopicUnsubscribe::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1737,43 +4598,43 @@ impl ::protobuf::Message for RequestStopStreamImage {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
                 "header",
-                |m: &RequestStopStreamImage| { &m.header },
-                |m: &mut RequestStopStreamImage| { &mut m.header },
+                |m: &RequestTopicUnsubscribe| { &m.header },
+                |m: &mut RequestTopicUnsubscribe| { &mut m.header },
             ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "client_id",
-                |m: &RequestStopStreamImage| { &m.client_id },
-                |m: &mut RequestStopStreamImage| { &mut m.client_id },
+                "topic",
+                |m: &RequestTopicUnsubscribe| { &m.topic },
+                |m: &mut RequestTopicUnsubscribe| { &mut m.topic },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestStopStreamImage>(
-                "RequestStopStreamImage",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestTopicUnsubscribe>(
+                "RequestTopicUnsubscribe",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static RequestStopStreamImage {
-        static instance: ::protobuf::rt::LazyV2<RequestStopStreamImage> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(RequestStopStreamImage::new)
+    fn default_instance() -> &'static RequestTopicUnsubscribe {
+        static instance: ::protobuf::rt::LazyV2<RequestTopicUnsubscribe> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestTopicUnsubscribe::new)
     }
 }
 
-impl ::protobuf::Clear for RequestStopStreamImage {
+impl ::protobuf::Clear for RequestTopicUnsubscribe {
     fn clear(&mut self) {
         self.header.clear();
-        self.client_id.clear();
+        self.topic.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for RequestStopStreamImage {
+impl ::std::fmt::Debug for RequestTopicUnsubscribe {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for RequestStopStreamImage {
+impl ::protobuf::reflect::ProtobufValue for RequestTopicUnsubscribe {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
@@ -1794,33 +4655,78 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \n\rRequestHeader\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\"n\n\x14\
     RequestCommandRecord\x12*\n\x06header\x18\x01\x20\x01(\x0b2\x12.viz.Requ\
     estHeaderR\x06header\x12\x16\n\x06enable\x18\x02\x20\x01(\x08R\x06enable\
-    :\x12\xc2\xbb\x1a\x0eCommand/Record\"\x9c\x01\n\rRequestStatus\x12*\n\
-    \x06header\x18\x01\x20\x01(\x0b2\x12.viz.RequestHeaderR\x06header\x12\
+    :\x12\xc2\xbb\x1a\x0eCommand/Record\"`\n\x19RequestCommandRecordState\
+    \x12*\n\x06header\x18\x01\x20\x01(\x0b2\x12.viz.RequestHeaderR\x06header\
+    :\x17\xc2\xbb\x1a\x13Command/RecordState\"\xb0\x01\n\rRequestStatus\x12*\
+    \n\x06header\x18\x01\x20\x01(\x0b2\x12.viz.RequestHeaderR\x06header\x12\
     \x1d\n\nstart_time\x18\x02\x20\x01(\x04R\tstartTime\x12\x19\n\x08end_tim\
-    e\x18\x03\x20\x01(\x04R\x07endTime:%\xc2\xbb\x1a\rStatus/Status\xca\xbb\
-    \x1a\x06Stream\xd2\xbb\x1a\x06Status\"h\n\x11RequestStatusLive\x12*\n\
-    \x06header\x18\x01\x20\x01(\x0b2\x12.viz.RequestHeaderR\x06header\x12\
-    \x16\n\x06enable\x18\x02\x20\x01(\x08R\x06enable:\x0f\xc2\xbb\x1a\x0bSta\
-    tus/Live\"\xea\x01\n\x0cRequestImage\x12*\n\x06header\x18\x01\x20\x01(\
+    e\x18\x03\x20\x01(\x04R\x07endTime\x12\x12\n\x04mode\x18\x04\x20\x01(\tR\
+    \x04mode:%\xd2\xbb\x1a\x06Status\xc2\xbb\x1a\rStatus/Status\xca\xbb\x1a\
+    \x06Stream\"\xa4\x02\n\x0cRequestImage\x12*\n\x06header\x18\x01\x20\x01(\
     \x0b2\x12.viz.RequestHeaderR\x06header\x12\x1d\n\nstart_time\x18\x02\x20\
     \x01(\x04R\tstartTime\x12\x19\n\x08end_time\x18\x03\x20\x01(\x04R\x07end\
     Time\x12\x1a\n\x08resource\x18\x04\x20\x01(\tR\x08resource\x12\x17\n\x07\
     scale_x\x18\x05\x20\x01(\x02R\x06scaleX\x12\x17\n\x07scale_y\x18\x06\x20\
-    \x01(\x02R\x06scaleY:&\xd2\xbb\x1a\tImageData\xc2\xbb\x1a\x0bImage/Image\
-    \xca\xbb\x1a\x06Stream\"\xf8\x01\n\x12RequestStreamImage\x12*\n\x06heade\
-    r\x18\x01\x20\x01(\x0b2\x12.viz.RequestHeaderR\x06header\x12\x1d\n\nstar\
-    t_time\x18\x02\x20\x01(\x04R\tstartTime\x12\x1a\n\x08resource\x18\x03\
-    \x20\x01(\tR\x08resource\x12\x17\n\x07scale_x\x18\x04\x20\x01(\x02R\x06s\
-    caleX\x12\x17\n\x07scale_y\x18\x05\x20\x01(\x02R\x06scaleY\x12\x1b\n\tcl\
-    ient_id\x18\x06\x20\x01(\tR\x08clientId:,\xca\xbb\x1a\x06Stream\xc2\xbb\
-    \x1a\x11Image/StreamImage\xd2\xbb\x1a\tImageData\"|\n\x16RequestStopStre\
-    amImage\x12*\n\x06header\x18\x01\x20\x01(\x0b2\x12.viz.RequestHeaderR\
-    \x06header\x12\x1b\n\tclient_id\x18\x02\x20\x01(\tR\x08clientId:\x19\xc2\
-    \xbb\x1a\x15Image/StopStreamImage:5\n\x04path\x18\xb8\xa7\x03\x20\x01(\t\
-    \x12\x1f.google.protobuf.MessageOptionsR\x04path:F\n\rresponse_type\x18\
-    \xb9\xa7\x03\x20\x01(\t\x12\x1f.google.protobuf.MessageOptionsR\x0crespo\
-    nseType:J\n\x0fresponse_schema\x18\xba\xa7\x03\x20\x01(\t\x12\x1f.google\
-    .protobuf.MessageOptionsR\x0eresponseSchemaJ\x89\x0f\n\x06\x12\x04\0\0B\
+    \x01(\x02R\x06scaleY\x12\x1e\n\nannotation\x18\x07\x20\x01(\tR\nannotati\
+    on\x12\x18\n\x07overlay\x18\x08\x20\x01(\x08R\x07overlay:&\xd2\xbb\x1a\t\
+    ImageData\xc2\xbb\x1a\x0bImage/Image\xca\xbb\x1a\x06Stream\"\x91\x02\n\
+    \x11RequestThumbnails\x12*\n\x06header\x18\x01\x20\x01(\x0b2\x12.viz.Req\
+    uestHeaderR\x06header\x12\x1d\n\nstart_time\x18\x02\x20\x01(\x04R\tstart\
+    Time\x12\x19\n\x08end_time\x18\x03\x20\x01(\x04R\x07endTime\x12\x1a\n\
+    \x08resource\x18\x04\x20\x01(\tR\x08resource\x12\x14\n\x05count\x18\x05\
+    \x20\x01(\rR\x05count\x12\x12\n\x04size\x18\x06\x20\x01(\rR\x04size\x12#\
+    \n\rcontact_sheet\x18\x07\x20\x01(\x08R\x0ccontactSheet:+\xd2\xbb\x1a\tI\
+    mageData\xc2\xbb\x1a\x10Image/Thumbnails\xca\xbb\x1a\x06Stream\"\xb2\x02\
+    \n\x12RequestStreamImage\x12*\n\x06header\x18\x01\x20\x01(\x0b2\x12.viz.\
+    RequestHeaderR\x06header\x12\x1d\n\nstart_time\x18\x02\x20\x01(\x04R\tst\
+    artTime\x12\x1a\n\x08resource\x18\x03\x20\x01(\tR\x08resource\x12\x17\n\
+    \x07scale_x\x18\x04\x20\x01(\x02R\x06scaleX\x12\x17\n\x07scale_y\x18\x05\
+    \x20\x01(\x02R\x06scaleY\x12\x1b\n\tclient_id\x18\x06\x20\x01(\tR\x08cli\
+    entId\x12\x1e\n\nannotation\x18\x07\x20\x01(\tR\nannotation\x12\x18\n\
+    \x07overlay\x18\x08\x20\x01(\x08R\x07overlay:,\xd2\xbb\x1a\tImageData\
+    \xc2\xbb\x1a\x11Image/StreamImage\xca\xbb\x1a\x06Stream\"\xb3\x02\n\x15R\
+    equestImageSubscribe\x12*\n\x06header\x18\x01\x20\x01(\x0b2\x12.viz.Requ\
+    estHeaderR\x06header\x12\x1a\n\x08resource\x18\x02\x20\x01(\tR\x08resour\
+    ce\x12\x17\n\x07scale_x\x18\x03\x20\x01(\x02R\x06scaleX\x12\x17\n\x07sca\
+    le_y\x18\x04\x20\x01(\x02R\x06scaleY\x12\x1e\n\nannotation\x18\x05\x20\
+    \x01(\tR\nannotation\x12\x18\n\x07overlay\x18\x06\x20\x01(\x08R\x07overl\
+    ay\x12\x19\n\x08max_rate\x18\x07\x20\x01(\x01R\x07maxRate\x12\x1f\n\x0bl\
+    atest_only\x18\x08\x20\x01(\x08R\nlatestOnly:*\xd2\xbb\x1a\tImageData\
+    \xc2\xbb\x1a\x0fImage/Subscribe\xca\xbb\x1a\x06Stream\"x\n\x17RequestIma\
+    geUnsubscribe\x12*\n\x06header\x18\x01\x20\x01(\x0b2\x12.viz.RequestHead\
+    erR\x06header\x12\x1a\n\x08resource\x18\x02\x20\x01(\tR\x08resource:\x15\
+    \xc2\xbb\x1a\x11Image/Unsubscribe\"|\n\x16RequestStopStreamImage\x12*\n\
+    \x06header\x18\x01\x20\x01(\x0b2\x12.viz.RequestHeaderR\x06header\x12\
+    \x1b\n\tclient_id\x18\x02\x20\x01(\tR\x08clientId:\x19\xc2\xbb\x1a\x15Im\
+    age/StopStreamImage\"\x80\x02\n\x12RequestSeriesQuery\x12*\n\x06header\
+    \x18\x01\x20\x01(\x0b2\x12.viz.RequestHeaderR\x06header\x12\x1d\n\nstart\
+    _time\x18\x02\x20\x01(\x04R\tstartTime\x12\x19\n\x08end_time\x18\x03\x20\
+    \x01(\x04R\x07endTime\x12\x14\n\x05topic\x18\x04\x20\x01(\tR\x05topic\
+    \x12\x14\n\x05field\x18\x05\x20\x01(\tR\x05field\x12\x14\n\x05count\x18\
+    \x06\x20\x01(\rR\x05count\x12\x16\n\x06method\x18\x07\x20\x01(\tR\x06met\
+    hod:*\xd2\xbb\x1a\x0cSeriesBucket\xc2\xbb\x1a\x0cSeries/Query\xca\xbb\
+    \x1a\x06Stream\"\x91\x01\n\x13RequestStreamCredit\x12*\n\x06header\x18\
+    \x01\x20\x01(\x0b2\x12.viz.RequestHeaderR\x06header\x12\x1b\n\tstream_id\
+    \x18\x04\x20\x01(\rR\x08streamId\x12\x18\n\x07credits\x18\x03\x20\x01(\r\
+    R\x07credits:\x11\xc2\xbb\x1a\rStream/CreditJ\x04\x08\x02\x10\x03\"N\n\
+    \x10RequestTopicList\x12*\n\x06header\x18\x01\x20\x01(\x0b2\x12.viz.Requ\
+    estHeaderR\x06header:\x0e\xc2\xbb\x1a\nTopic/List\"\x9e\x01\n\x12Request\
+    TopicDecode\x12*\n\x06header\x18\x01\x20\x01(\x0b2\x12.viz.RequestHeader\
+    R\x06header\x12\x14\n\x05topic\x18\x02\x20\x01(\tR\x05topic\x12\x1c\n\tt\
+    imestamp\x18\x03\x20\x01(\x04R\ttimestamp\x12\x16\n\x06format\x18\x04\
+    \x20\x01(\tR\x06format:\x10\xc2\xbb\x1a\x0cTopic/Decode\"\xc2\x01\n\x15R\
+    equestTopicSubscribe\x12*\n\x06header\x18\x01\x20\x01(\x0b2\x12.viz.Requ\
+    estHeaderR\x06header\x12\x14\n\x05topic\x18\x02\x20\x01(\tR\x05topic\x12\
+    \x16\n\x06format\x18\x03\x20\x01(\tR\x06format\x12\x19\n\x08max_rate\x18\
+    \x04\x20\x01(\x01R\x07maxRate\x12\x1f\n\x0blatest_only\x18\x05\x20\x01(\
+    \x08R\nlatestOnly:\x13\xc2\xbb\x1a\x0fTopic/Subscribe\"r\n\x17RequestTop\
+    icUnsubscribe\x12*\n\x06header\x18\x01\x20\x01(\x0b2\x12.viz.RequestHead\
+    erR\x06header\x12\x14\n\x05topic\x18\x02\x20\x01(\tR\x05topic:\x15\xc2\
+    \xbb\x1a\x11Topic/Unsubscribe:5\n\x04path\x18\xb8\xa7\x03\x20\x01(\t\x12\
+    \x1f.google.protobuf.MessageOptionsR\x04path:F\n\rresponse_type\x18\xb9\
+    \xa7\x03\x20\x01(\t\x12\x1f.google.protobuf.MessageOptionsR\x0cresponseT\
+    ype:J\n\x0fresponse_schema\x18\xba\xa7\x03\x20\x01(\t\x12\x1f.google.pro\
+    tobuf.MessageOptionsR\x0eresponseSchemaJ\xfa0\n\x07\x12\x05\0\0\xb3\x01\
     \x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x02\0\x0c\n\
     \t\n\x02\x03\0\x12\x03\x04\0*\n\t\n\x01\x07\x12\x04\x06\0\n\x01\n\t\n\
     \x02\x07\0\x12\x03\x07\x04\x18\n\n\n\x03\x07\0\x02\x12\x03\x06\x07%\n\n\
@@ -1842,77 +4748,253 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x03\x13\x12\x18\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x13\x1b\x1c\n\x0b\
     \n\x04\x04\x01\x02\x01\x12\x03\x14\x04\x14\n\x0c\n\x05\x04\x01\x02\x01\
     \x05\x12\x03\x14\x04\x08\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x14\t\
-    \x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x14\x12\x13\n\n\n\x02\x04\
-    \x02\x12\x04\x17\0\x1e\x01\n\n\n\x03\x04\x02\x01\x12\x03\x17\x08\x15\n\n\
-    \n\x03\x04\x02\x07\x12\x03\x18\x04$\n\r\n\x06\x04\x02\x07\xb8\xa7\x03\
-    \x12\x03\x18\x04$\n\n\n\x03\x04\x02\x07\x12\x03\x19\x04&\n\r\n\x06\x04\
-    \x02\x07\xb9\xa7\x03\x12\x03\x19\x04&\n\n\n\x03\x04\x02\x07\x12\x03\x1a\
-    \x04(\n\r\n\x06\x04\x02\x07\xba\xa7\x03\x12\x03\x1a\x04(\n\x0b\n\x04\x04\
-    \x02\x02\0\x12\x03\x1b\x04\x1d\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\x1b\
-    \x04\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x1b\x12\x18\n\x0c\n\x05\
-    \x04\x02\x02\0\x03\x12\x03\x1b\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x01\x12\
-    \x03\x1c\x04\x1a\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x1c\x04\n\n\x0c\
-    \n\x05\x04\x02\x02\x01\x01\x12\x03\x1c\x0b\x15\n\x0c\n\x05\x04\x02\x02\
-    \x01\x03\x12\x03\x1c\x18\x19\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x1d\x04\
-    \x18\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x1d\x04\n\n\x0c\n\x05\x04\
-    \x02\x02\x02\x01\x12\x03\x1d\x0b\x13\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\
-    \x03\x1d\x16\x17\n\n\n\x02\x04\x03\x12\x04\x20\0$\x01\n\n\n\x03\x04\x03\
-    \x01\x12\x03\x20\x08\x19\n\n\n\x03\x04\x03\x07\x12\x03!\x04\"\n\r\n\x06\
-    \x04\x03\x07\xb8\xa7\x03\x12\x03!\x04\"\n\x0b\n\x04\x04\x03\x02\0\x12\
-    \x03\"\x04\x1d\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\"\x04\x11\n\x0c\n\
-    \x05\x04\x03\x02\0\x01\x12\x03\"\x12\x18\n\x0c\n\x05\x04\x03\x02\0\x03\
-    \x12\x03\"\x1b\x1c\n\x0b\n\x04\x04\x03\x02\x01\x12\x03#\x04\x14\n\x0c\n\
-    \x05\x04\x03\x02\x01\x05\x12\x03#\x04\x08\n\x0c\n\x05\x04\x03\x02\x01\
-    \x01\x12\x03#\t\x0f\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03#\x12\x13\n\n\
-    \n\x02\x04\x04\x12\x04&\00\x01\n\n\n\x03\x04\x04\x01\x12\x03&\x08\x14\n\
-    \n\n\x03\x04\x04\x07\x12\x03'\x04\"\n\r\n\x06\x04\x04\x07\xb8\xa7\x03\
-    \x12\x03'\x04\"\n\n\n\x03\x04\x04\x07\x12\x03(\x04&\n\r\n\x06\x04\x04\
-    \x07\xb9\xa7\x03\x12\x03(\x04&\n\n\n\x03\x04\x04\x07\x12\x03)\x04+\n\r\n\
-    \x06\x04\x04\x07\xba\xa7\x03\x12\x03)\x04+\n\x0b\n\x04\x04\x04\x02\0\x12\
-    \x03*\x04\x1d\n\x0c\n\x05\x04\x04\x02\0\x06\x12\x03*\x04\x11\n\x0c\n\x05\
-    \x04\x04\x02\0\x01\x12\x03*\x12\x18\n\x0c\n\x05\x04\x04\x02\0\x03\x12\
-    \x03*\x1b\x1c\n\x0b\n\x04\x04\x04\x02\x01\x12\x03+\x04\x1a\n\x0c\n\x05\
-    \x04\x04\x02\x01\x05\x12\x03+\x04\n\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\
-    \x03+\x0b\x15\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03+\x18\x19\n\x0b\n\
-    \x04\x04\x04\x02\x02\x12\x03,\x04\x18\n\x0c\n\x05\x04\x04\x02\x02\x05\
-    \x12\x03,\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03,\x0b\x13\n\x0c\n\
-    \x05\x04\x04\x02\x02\x03\x12\x03,\x16\x17\n\x0b\n\x04\x04\x04\x02\x03\
-    \x12\x03-\x04\x18\n\x0c\n\x05\x04\x04\x02\x03\x05\x12\x03-\x04\n\n\x0c\n\
-    \x05\x04\x04\x02\x03\x01\x12\x03-\x0b\x13\n\x0c\n\x05\x04\x04\x02\x03\
-    \x03\x12\x03-\x16\x17\n\x0b\n\x04\x04\x04\x02\x04\x12\x03.\x04\x16\n\x0c\
-    \n\x05\x04\x04\x02\x04\x05\x12\x03.\x04\t\n\x0c\n\x05\x04\x04\x02\x04\
-    \x01\x12\x03.\n\x11\n\x0c\n\x05\x04\x04\x02\x04\x03\x12\x03.\x14\x15\n\
-    \x0b\n\x04\x04\x04\x02\x05\x12\x03/\x04\x16\n\x0c\n\x05\x04\x04\x02\x05\
-    \x05\x12\x03/\x04\t\n\x0c\n\x05\x04\x04\x02\x05\x01\x12\x03/\n\x11\n\x0c\
-    \n\x05\x04\x04\x02\x05\x03\x12\x03/\x14\x15\n\n\n\x02\x04\x05\x12\x042\0\
-    <\x01\n\n\n\x03\x04\x05\x01\x12\x032\x08\x1a\n\n\n\x03\x04\x05\x07\x12\
-    \x033\x04(\n\r\n\x06\x04\x05\x07\xb8\xa7\x03\x12\x033\x04(\n\n\n\x03\x04\
-    \x05\x07\x12\x034\x04&\n\r\n\x06\x04\x05\x07\xb9\xa7\x03\x12\x034\x04&\n\
-    \n\n\x03\x04\x05\x07\x12\x035\x04+\n\r\n\x06\x04\x05\x07\xba\xa7\x03\x12\
-    \x035\x04+\n\x0b\n\x04\x04\x05\x02\0\x12\x036\x04\x1d\n\x0c\n\x05\x04\
-    \x05\x02\0\x06\x12\x036\x04\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x036\
-    \x12\x18\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x036\x1b\x1c\n\x0b\n\x04\x04\
-    \x05\x02\x01\x12\x037\x04\x1a\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x037\
-    \x04\n\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x037\x0b\x15\n\x0c\n\x05\x04\
-    \x05\x02\x01\x03\x12\x037\x18\x19\n\x0b\n\x04\x04\x05\x02\x02\x12\x038\
-    \x04\x18\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x038\x04\n\n\x0c\n\x05\x04\
-    \x05\x02\x02\x01\x12\x038\x0b\x13\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\
-    \x038\x16\x17\n\x0b\n\x04\x04\x05\x02\x03\x12\x039\x04\x16\n\x0c\n\x05\
-    \x04\x05\x02\x03\x05\x12\x039\x04\t\n\x0c\n\x05\x04\x05\x02\x03\x01\x12\
-    \x039\n\x11\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\x039\x14\x15\n\x0b\n\x04\
-    \x04\x05\x02\x04\x12\x03:\x04\x16\n\x0c\n\x05\x04\x05\x02\x04\x05\x12\
-    \x03:\x04\t\n\x0c\n\x05\x04\x05\x02\x04\x01\x12\x03:\n\x11\n\x0c\n\x05\
-    \x04\x05\x02\x04\x03\x12\x03:\x14\x15\n\x0b\n\x04\x04\x05\x02\x05\x12\
-    \x03;\x04\x19\n\x0c\n\x05\x04\x05\x02\x05\x05\x12\x03;\x04\n\n\x0c\n\x05\
-    \x04\x05\x02\x05\x01\x12\x03;\x0b\x14\n\x0c\n\x05\x04\x05\x02\x05\x03\
-    \x12\x03;\x17\x18\n\n\n\x02\x04\x06\x12\x04>\0B\x01\n\n\n\x03\x04\x06\
-    \x01\x12\x03>\x08\x1e\n\n\n\x03\x04\x06\x07\x12\x03?\x04,\n\r\n\x06\x04\
-    \x06\x07\xb8\xa7\x03\x12\x03?\x04,\n\x0b\n\x04\x04\x06\x02\0\x12\x03@\
-    \x04\x1d\n\x0c\n\x05\x04\x06\x02\0\x06\x12\x03@\x04\x11\n\x0c\n\x05\x04\
-    \x06\x02\0\x01\x12\x03@\x12\x18\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03@\
-    \x1b\x1c\n\x0b\n\x04\x04\x06\x02\x01\x12\x03A\x04\x19\n\x0c\n\x05\x04\
-    \x06\x02\x01\x05\x12\x03A\x04\n\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03A\
-    \x0b\x14\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03A\x17\x18b\x06proto3\
+    \x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x14\x12\x13\nX\n\x02\x04\
+    \x02\x12\x04\x18\0\x1b\x01\x1aL\x20Sent\x20to\x20every\x20client\x20on\
+    \x20connect\x20and\x20on\x20every\x20change,\x20without\x20a\x20request\
+    \x20too\n\n\n\n\x03\x04\x02\x01\x12\x03\x18\x08!\n\n\n\x03\x04\x02\x07\
+    \x12\x03\x19\x04*\n\r\n\x06\x04\x02\x07\xb8\xa7\x03\x12\x03\x19\x04*\n\
+    \x0b\n\x04\x04\x02\x02\0\x12\x03\x1a\x04\x1d\n\x0c\n\x05\x04\x02\x02\0\
+    \x06\x12\x03\x1a\x04\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x1a\x12\
+    \x18\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x1a\x1b\x1c\n\n\n\x02\x04\x03\
+    \x12\x04\x1d\0&\x01\n\n\n\x03\x04\x03\x01\x12\x03\x1d\x08\x15\n\n\n\x03\
+    \x04\x03\x07\x12\x03\x1e\x04$\n\r\n\x06\x04\x03\x07\xb8\xa7\x03\x12\x03\
+    \x1e\x04$\n\n\n\x03\x04\x03\x07\x12\x03\x1f\x04&\n\r\n\x06\x04\x03\x07\
+    \xb9\xa7\x03\x12\x03\x1f\x04&\n\n\n\x03\x04\x03\x07\x12\x03\x20\x04(\n\r\
+    \n\x06\x04\x03\x07\xba\xa7\x03\x12\x03\x20\x04(\n\x0b\n\x04\x04\x03\x02\
+    \0\x12\x03!\x04\x1d\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03!\x04\x11\n\x0c\
+    \n\x05\x04\x03\x02\0\x01\x12\x03!\x12\x18\n\x0c\n\x05\x04\x03\x02\0\x03\
+    \x12\x03!\x1b\x1c\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\"\x04\x1a\n\x0c\n\
+    \x05\x04\x03\x02\x01\x05\x12\x03\"\x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\
+    \x12\x03\"\x0b\x15\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\"\x18\x19\n\
+    \x0b\n\x04\x04\x03\x02\x02\x12\x03#\x04\x18\n\x0c\n\x05\x04\x03\x02\x02\
+    \x05\x12\x03#\x04\n\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03#\x0b\x13\n\
+    \x0c\n\x05\x04\x03\x02\x02\x03\x12\x03#\x16\x17\n,\n\x04\x04\x03\x02\x03\
+    \x12\x03%\x04\x14\x1a\x1f\x20live,\x20replay;\x20live\x20if\x20not\x20se\
+    t\n\n\x0c\n\x05\x04\x03\x02\x03\x05\x12\x03%\x04\n\n\x0c\n\x05\x04\x03\
+    \x02\x03\x01\x12\x03%\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03%\
+    \x12\x13\n\n\n\x02\x04\x04\x12\x04(\06\x01\n\n\n\x03\x04\x04\x01\x12\x03\
+    (\x08\x14\n\n\n\x03\x04\x04\x07\x12\x03)\x04\"\n\r\n\x06\x04\x04\x07\xb8\
+    \xa7\x03\x12\x03)\x04\"\n\n\n\x03\x04\x04\x07\x12\x03*\x04&\n\r\n\x06\
+    \x04\x04\x07\xb9\xa7\x03\x12\x03*\x04&\n\n\n\x03\x04\x04\x07\x12\x03+\
+    \x04+\n\r\n\x06\x04\x04\x07\xba\xa7\x03\x12\x03+\x04+\n\x0b\n\x04\x04\
+    \x04\x02\0\x12\x03,\x04\x1d\n\x0c\n\x05\x04\x04\x02\0\x06\x12\x03,\x04\
+    \x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03,\x12\x18\n\x0c\n\x05\x04\x04\
+    \x02\0\x03\x12\x03,\x1b\x1c\n\x0b\n\x04\x04\x04\x02\x01\x12\x03-\x04\x1a\
+    \n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03-\x04\n\n\x0c\n\x05\x04\x04\x02\
+    \x01\x01\x12\x03-\x0b\x15\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03-\x18\
+    \x19\n\x0b\n\x04\x04\x04\x02\x02\x12\x03.\x04\x18\n\x0c\n\x05\x04\x04\
+    \x02\x02\x05\x12\x03.\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03.\x0b\
+    \x13\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03.\x16\x17\n\x0b\n\x04\x04\
+    \x04\x02\x03\x12\x03/\x04\x18\n\x0c\n\x05\x04\x04\x02\x03\x05\x12\x03/\
+    \x04\n\n\x0c\n\x05\x04\x04\x02\x03\x01\x12\x03/\x0b\x13\n\x0c\n\x05\x04\
+    \x04\x02\x03\x03\x12\x03/\x16\x17\n\x0b\n\x04\x04\x04\x02\x04\x12\x030\
+    \x04\x16\n\x0c\n\x05\x04\x04\x02\x04\x05\x12\x030\x04\t\n\x0c\n\x05\x04\
+    \x04\x02\x04\x01\x12\x030\n\x11\n\x0c\n\x05\x04\x04\x02\x04\x03\x12\x030\
+    \x14\x15\n\x0b\n\x04\x04\x04\x02\x05\x12\x031\x04\x16\n\x0c\n\x05\x04\
+    \x04\x02\x05\x05\x12\x031\x04\t\n\x0c\n\x05\x04\x04\x02\x05\x01\x12\x031\
+    \n\x11\n\x0c\n\x05\x04\x04\x02\x05\x03\x12\x031\x14\x15\n<\n\x04\x04\x04\
+    \x02\x06\x12\x033\x04\x1a\x1a/\x20annotation\x20topic\x20(ImageAnnotatio\
+    ns),\x20optional\n\n\x0c\n\x05\x04\x04\x02\x06\x05\x12\x033\x04\n\n\x0c\
+    \n\x05\x04\x04\x02\x06\x01\x12\x033\x0b\x15\n\x0c\n\x05\x04\x04\x02\x06\
+    \x03\x12\x033\x18\x19\n.\n\x04\x04\x04\x02\x07\x12\x035\x04\x15\x1a!\x20\
+    burn\x20annotations\x20into\x20the\x20image\n\n\x0c\n\x05\x04\x04\x02\
+    \x07\x05\x12\x035\x04\x08\n\x0c\n\x05\x04\x04\x02\x07\x01\x12\x035\t\x10\
+    \n\x0c\n\x05\x04\x04\x02\x07\x03\x12\x035\x13\x14\n\n\n\x02\x04\x05\x12\
+    \x048\0F\x01\n\n\n\x03\x04\x05\x01\x12\x038\x08\x19\n\n\n\x03\x04\x05\
+    \x07\x12\x039\x04'\n\r\n\x06\x04\x05\x07\xb8\xa7\x03\x12\x039\x04'\n\n\n\
+    \x03\x04\x05\x07\x12\x03:\x04&\n\r\n\x06\x04\x05\x07\xb9\xa7\x03\x12\x03\
+    :\x04&\n\n\n\x03\x04\x05\x07\x12\x03;\x04+\n\r\n\x06\x04\x05\x07\xba\xa7\
+    \x03\x12\x03;\x04+\n\x0b\n\x04\x04\x05\x02\0\x12\x03<\x04\x1d\n\x0c\n\
+    \x05\x04\x05\x02\0\x06\x12\x03<\x04\x11\n\x0c\n\x05\x04\x05\x02\0\x01\
+    \x12\x03<\x12\x18\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03<\x1b\x1c\n\x0b\n\
+    \x04\x04\x05\x02\x01\x12\x03=\x04\x1a\n\x0c\n\x05\x04\x05\x02\x01\x05\
+    \x12\x03=\x04\n\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03=\x0b\x15\n\x0c\n\
+    \x05\x04\x05\x02\x01\x03\x12\x03=\x18\x19\n\x0b\n\x04\x04\x05\x02\x02\
+    \x12\x03>\x04\x18\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03>\x04\n\n\x0c\n\
+    \x05\x04\x05\x02\x02\x01\x12\x03>\x0b\x13\n\x0c\n\x05\x04\x05\x02\x02\
+    \x03\x12\x03>\x16\x17\n\x0b\n\x04\x04\x05\x02\x03\x12\x03?\x04\x18\n\x0c\
+    \n\x05\x04\x05\x02\x03\x05\x12\x03?\x04\n\n\x0c\n\x05\x04\x05\x02\x03\
+    \x01\x12\x03?\x0b\x13\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\x03?\x16\x17\n\
+    =\n\x04\x04\x05\x02\x04\x12\x03A\x04\x15\x1a0\x20number\x20of\x20frames\
+    \x20sampled\x20evenly\x20over\x20the\x20range\n\n\x0c\n\x05\x04\x05\x02\
+    \x04\x05\x12\x03A\x04\n\n\x0c\n\x05\x04\x05\x02\x04\x01\x12\x03A\x0b\x10\
+    \n\x0c\n\x05\x04\x05\x02\x04\x03\x12\x03A\x13\x14\n<\n\x04\x04\x05\x02\
+    \x05\x12\x03C\x04\x14\x1a/\x20max\x20width\x20and\x20height\x20of\x20a\
+    \x20thumbnail\x20in\x20pixels\n\n\x0c\n\x05\x04\x05\x02\x05\x05\x12\x03C\
+    \x04\n\n\x0c\n\x05\x04\x05\x02\x05\x01\x12\x03C\x0b\x0f\n\x0c\n\x05\x04\
+    \x05\x02\x05\x03\x12\x03C\x12\x13\n-\n\x04\x04\x05\x02\x06\x12\x03E\x04\
+    \x1b\x1a\x20\x20tile\x20thumbnails\x20into\x20one\x20image\n\n\x0c\n\x05\
+    \x04\x05\x02\x06\x05\x12\x03E\x04\x08\n\x0c\n\x05\x04\x05\x02\x06\x01\
+    \x12\x03E\t\x16\n\x0c\n\x05\x04\x05\x02\x06\x03\x12\x03E\x19\x1a\n\n\n\
+    \x02\x04\x06\x12\x04H\0V\x01\n\n\n\x03\x04\x06\x01\x12\x03H\x08\x1a\n\n\
+    \n\x03\x04\x06\x07\x12\x03I\x04(\n\r\n\x06\x04\x06\x07\xb8\xa7\x03\x12\
+    \x03I\x04(\n\n\n\x03\x04\x06\x07\x12\x03J\x04&\n\r\n\x06\x04\x06\x07\xb9\
+    \xa7\x03\x12\x03J\x04&\n\n\n\x03\x04\x06\x07\x12\x03K\x04+\n\r\n\x06\x04\
+    \x06\x07\xba\xa7\x03\x12\x03K\x04+\n\x0b\n\x04\x04\x06\x02\0\x12\x03L\
+    \x04\x1d\n\x0c\n\x05\x04\x06\x02\0\x06\x12\x03L\x04\x11\n\x0c\n\x05\x04\
+    \x06\x02\0\x01\x12\x03L\x12\x18\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03L\
+    \x1b\x1c\n\x0b\n\x04\x04\x06\x02\x01\x12\x03M\x04\x1a\n\x0c\n\x05\x04\
+    \x06\x02\x01\x05\x12\x03M\x04\n\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03M\
+    \x0b\x15\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03M\x18\x19\n\x0b\n\x04\
+    \x04\x06\x02\x02\x12\x03N\x04\x18\n\x0c\n\x05\x04\x06\x02\x02\x05\x12\
+    \x03N\x04\n\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03N\x0b\x13\n\x0c\n\x05\
+    \x04\x06\x02\x02\x03\x12\x03N\x16\x17\n\x0b\n\x04\x04\x06\x02\x03\x12\
+    \x03O\x04\x16\n\x0c\n\x05\x04\x06\x02\x03\x05\x12\x03O\x04\t\n\x0c\n\x05\
+    \x04\x06\x02\x03\x01\x12\x03O\n\x11\n\x0c\n\x05\x04\x06\x02\x03\x03\x12\
+    \x03O\x14\x15\n\x0b\n\x04\x04\x06\x02\x04\x12\x03P\x04\x16\n\x0c\n\x05\
+    \x04\x06\x02\x04\x05\x12\x03P\x04\t\n\x0c\n\x05\x04\x06\x02\x04\x01\x12\
+    \x03P\n\x11\n\x0c\n\x05\x04\x06\x02\x04\x03\x12\x03P\x14\x15\n\x0b\n\x04\
+    \x04\x06\x02\x05\x12\x03Q\x04\x19\n\x0c\n\x05\x04\x06\x02\x05\x05\x12\
+    \x03Q\x04\n\n\x0c\n\x05\x04\x06\x02\x05\x01\x12\x03Q\x0b\x14\n\x0c\n\x05\
+    \x04\x06\x02\x05\x03\x12\x03Q\x17\x18\n<\n\x04\x04\x06\x02\x06\x12\x03S\
+    \x04\x1a\x1a/\x20annotation\x20topic\x20(ImageAnnotations),\x20optional\
+    \n\n\x0c\n\x05\x04\x06\x02\x06\x05\x12\x03S\x04\n\n\x0c\n\x05\x04\x06\
+    \x02\x06\x01\x12\x03S\x0b\x15\n\x0c\n\x05\x04\x06\x02\x06\x03\x12\x03S\
+    \x18\x19\n.\n\x04\x04\x06\x02\x07\x12\x03U\x04\x15\x1a!\x20burn\x20annot\
+    ations\x20into\x20the\x20image\n\n\x0c\n\x05\x04\x06\x02\x07\x05\x12\x03\
+    U\x04\x08\n\x0c\n\x05\x04\x06\x02\x07\x01\x12\x03U\t\x10\n\x0c\n\x05\x04\
+    \x06\x02\x07\x03\x12\x03U\x13\x14\nU\n\x02\x04\x07\x12\x04Y\0i\x01\x1aI\
+    \x20Push\x20live\x20frames\x20as\x20Image/Live,\x20scaled\x20and\x20rate\
+    \x20limited\x20for\x20this\x20client\n\n\n\n\x03\x04\x07\x01\x12\x03Y\
+    \x08\x1d\n\n\n\x03\x04\x07\x07\x12\x03Z\x04&\n\r\n\x06\x04\x07\x07\xb8\
+    \xa7\x03\x12\x03Z\x04&\n\n\n\x03\x04\x07\x07\x12\x03[\x04&\n\r\n\x06\x04\
+    \x07\x07\xb9\xa7\x03\x12\x03[\x04&\n\n\n\x03\x04\x07\x07\x12\x03\\\x04+\
+    \n\r\n\x06\x04\x07\x07\xba\xa7\x03\x12\x03\\\x04+\n\x0b\n\x04\x04\x07\
+    \x02\0\x12\x03]\x04\x1d\n\x0c\n\x05\x04\x07\x02\0\x06\x12\x03]\x04\x11\n\
+    \x0c\n\x05\x04\x07\x02\0\x01\x12\x03]\x12\x18\n\x0c\n\x05\x04\x07\x02\0\
+    \x03\x12\x03]\x1b\x1c\n\x0b\n\x04\x04\x07\x02\x01\x12\x03^\x04\x18\n\x0c\
+    \n\x05\x04\x07\x02\x01\x05\x12\x03^\x04\n\n\x0c\n\x05\x04\x07\x02\x01\
+    \x01\x12\x03^\x0b\x13\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03^\x16\x17\n\
+    \x0b\n\x04\x04\x07\x02\x02\x12\x03_\x04\x16\n\x0c\n\x05\x04\x07\x02\x02\
+    \x05\x12\x03_\x04\t\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x03_\n\x11\n\x0c\
+    \n\x05\x04\x07\x02\x02\x03\x12\x03_\x14\x15\n\x0b\n\x04\x04\x07\x02\x03\
+    \x12\x03`\x04\x16\n\x0c\n\x05\x04\x07\x02\x03\x05\x12\x03`\x04\t\n\x0c\n\
+    \x05\x04\x07\x02\x03\x01\x12\x03`\n\x11\n\x0c\n\x05\x04\x07\x02\x03\x03\
+    \x12\x03`\x14\x15\n<\n\x04\x04\x07\x02\x04\x12\x03b\x04\x1a\x1a/\x20anno\
+    tation\x20topic\x20(ImageAnnotations),\x20optional\n\n\x0c\n\x05\x04\x07\
+    \x02\x04\x05\x12\x03b\x04\n\n\x0c\n\x05\x04\x07\x02\x04\x01\x12\x03b\x0b\
+    \x15\n\x0c\n\x05\x04\x07\x02\x04\x03\x12\x03b\x18\x19\n.\n\x04\x04\x07\
+    \x02\x05\x12\x03d\x04\x15\x1a!\x20burn\x20annotations\x20into\x20the\x20\
+    image\n\n\x0c\n\x05\x04\x07\x02\x05\x05\x12\x03d\x04\x08\n\x0c\n\x05\x04\
+    \x07\x02\x05\x01\x12\x03d\t\x10\n\x0c\n\x05\x04\x07\x02\x05\x03\x12\x03d\
+    \x13\x14\n.\n\x04\x04\x07\x02\x06\x12\x03f\x04\x18\x1a!\x20frames\x20per\
+    \x20second,\x200:\x20unlimited\n\n\x0c\n\x05\x04\x07\x02\x06\x05\x12\x03\
+    f\x04\n\n\x0c\n\x05\x04\x07\x02\x06\x01\x12\x03f\x0b\x13\n\x0c\n\x05\x04\
+    \x07\x02\x06\x03\x12\x03f\x16\x17\nO\n\x04\x04\x07\x02\x07\x12\x03h\x04\
+    \x19\x1aB\x20skip\x20to\x20the\x20newest\x20frame\x20when\x20the\x20clie\
+    nt\x20is\x20behind,\x20default\x20true\n\n\x0c\n\x05\x04\x07\x02\x07\x05\
+    \x12\x03h\x04\x08\n\x0c\n\x05\x04\x07\x02\x07\x01\x12\x03h\t\x14\n\x0c\n\
+    \x05\x04\x07\x02\x07\x03\x12\x03h\x17\x18\n\n\n\x02\x04\x08\x12\x04k\0o\
+    \x01\n\n\n\x03\x04\x08\x01\x12\x03k\x08\x1f\n\n\n\x03\x04\x08\x07\x12\
+    \x03l\x04(\n\r\n\x06\x04\x08\x07\xb8\xa7\x03\x12\x03l\x04(\n\x0b\n\x04\
+    \x04\x08\x02\0\x12\x03m\x04\x1d\n\x0c\n\x05\x04\x08\x02\0\x06\x12\x03m\
+    \x04\x11\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03m\x12\x18\n\x0c\n\x05\x04\
+    \x08\x02\0\x03\x12\x03m\x1b\x1c\n\x0b\n\x04\x04\x08\x02\x01\x12\x03n\x04\
+    \x18\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03n\x04\n\n\x0c\n\x05\x04\x08\
+    \x02\x01\x01\x12\x03n\x0b\x13\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x03n\
+    \x16\x17\n\n\n\x02\x04\t\x12\x04q\0u\x01\n\n\n\x03\x04\t\x01\x12\x03q\
+    \x08\x1e\n\n\n\x03\x04\t\x07\x12\x03r\x04,\n\r\n\x06\x04\t\x07\xb8\xa7\
+    \x03\x12\x03r\x04,\n\x0b\n\x04\x04\t\x02\0\x12\x03s\x04\x1d\n\x0c\n\x05\
+    \x04\t\x02\0\x06\x12\x03s\x04\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03s\
+    \x12\x18\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03s\x1b\x1c\n\x0b\n\x04\x04\t\
+    \x02\x01\x12\x03t\x04\x19\n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03t\x04\n\n\
+    \x0c\n\x05\x04\t\x02\x01\x01\x12\x03t\x0b\x14\n\x0c\n\x05\x04\t\x02\x01\
+    \x03\x12\x03t\x17\x18\n\x0b\n\x02\x04\n\x12\x05w\0\x85\x01\x01\n\n\n\x03\
+    \x04\n\x01\x12\x03w\x08\x1a\n\n\n\x03\x04\n\x07\x12\x03x\x04#\n\r\n\x06\
+    \x04\n\x07\xb8\xa7\x03\x12\x03x\x04#\n\n\n\x03\x04\n\x07\x12\x03y\x04&\n\
+    \r\n\x06\x04\n\x07\xb9\xa7\x03\x12\x03y\x04&\n\n\n\x03\x04\n\x07\x12\x03\
+    z\x04.\n\r\n\x06\x04\n\x07\xba\xa7\x03\x12\x03z\x04.\n\x0b\n\x04\x04\n\
+    \x02\0\x12\x03{\x04\x1d\n\x0c\n\x05\x04\n\x02\0\x06\x12\x03{\x04\x11\n\
+    \x0c\n\x05\x04\n\x02\0\x01\x12\x03{\x12\x18\n\x0c\n\x05\x04\n\x02\0\x03\
+    \x12\x03{\x1b\x1c\n\x0b\n\x04\x04\n\x02\x01\x12\x03|\x04\x1a\n\x0c\n\x05\
+    \x04\n\x02\x01\x05\x12\x03|\x04\n\n\x0c\n\x05\x04\n\x02\x01\x01\x12\x03|\
+    \x0b\x15\n\x0c\n\x05\x04\n\x02\x01\x03\x12\x03|\x18\x19\n\x0b\n\x04\x04\
+    \n\x02\x02\x12\x03}\x04\x18\n\x0c\n\x05\x04\n\x02\x02\x05\x12\x03}\x04\n\
+    \n\x0c\n\x05\x04\n\x02\x02\x01\x12\x03}\x0b\x13\n\x0c\n\x05\x04\n\x02\
+    \x02\x03\x12\x03}\x16\x17\n\x0b\n\x04\x04\n\x02\x03\x12\x03~\x04\x15\n\
+    \x0c\n\x05\x04\n\x02\x03\x05\x12\x03~\x04\n\n\x0c\n\x05\x04\n\x02\x03\
+    \x01\x12\x03~\x0b\x10\n\x0c\n\x05\x04\n\x02\x03\x03\x12\x03~\x13\x14\nB\
+    \n\x04\x04\n\x02\x04\x12\x04\x80\x01\x04\x15\x1a4\x20numeric\x20field\
+    \x20of\x20the\x20decoded\x20message:\x20\"position.y\"\n\n\r\n\x05\x04\n\
+    \x02\x04\x05\x12\x04\x80\x01\x04\n\n\r\n\x05\x04\n\x02\x04\x01\x12\x04\
+    \x80\x01\x0b\x10\n\r\n\x05\x04\n\x02\x04\x03\x12\x04\x80\x01\x13\x14\n'\
+    \n\x04\x04\n\x02\x05\x12\x04\x82\x01\x04\x15\x1a\x19\x20target\x20number\
+    \x20of\x20points\n\n\r\n\x05\x04\n\x02\x05\x05\x12\x04\x82\x01\x04\n\n\r\
+    \n\x05\x04\n\x02\x05\x01\x12\x04\x82\x01\x0b\x10\n\r\n\x05\x04\n\x02\x05\
+    \x03\x12\x04\x82\x01\x13\x14\n3\n\x04\x04\n\x02\x06\x12\x04\x84\x01\x04\
+    \x16\x1a%\x20minmax\x20(min/max/mean\x20buckets),\x20lttb\n\n\r\n\x05\
+    \x04\n\x02\x06\x05\x12\x04\x84\x01\x04\n\n\r\n\x05\x04\n\x02\x06\x01\x12\
+    \x04\x84\x01\x0b\x11\n\r\n\x05\x04\n\x02\x06\x03\x12\x04\x84\x01\x14\x15\
+    \n8\n\x02\x04\x0b\x12\x06\x88\x01\0\x91\x01\x01\x1a*\x20Flow\x20control\
+    \x20of\x20chunked\x20Stream\x20responses\n\n\x0b\n\x03\x04\x0b\x01\x12\
+    \x04\x88\x01\x08\x1b\n\x0b\n\x03\x04\x0b\x07\x12\x04\x89\x01\x04$\n\x0e\
+    \n\x06\x04\x0b\x07\xb8\xa7\x03\x12\x04\x89\x01\x04$\n\x0c\n\x04\x04\x0b\
+    \x02\0\x12\x04\x8a\x01\x04\x1d\n\r\n\x05\x04\x0b\x02\0\x06\x12\x04\x8a\
+    \x01\x04\x11\n\r\n\x05\x04\x0b\x02\0\x01\x12\x04\x8a\x01\x12\x18\n\r\n\
+    \x05\x04\x0b\x02\0\x03\x12\x04\x8a\x01\x1b\x1c\nK\n\x03\x04\x0b\t\x12\
+    \x04\x8c\x01\x04\x0f\x1a>\x20was\x20the\x20path\x20of\x20the\x20Stream,\
+    \x20which\x20concurrent\x20responses\x20share\n\n\x0c\n\x04\x04\x0b\t\0\
+    \x12\x04\x8c\x01\r\x0e\n\r\n\x05\x04\x0b\t\0\x01\x12\x04\x8c\x01\r\x0e\n\
+    \r\n\x05\x04\x0b\t\0\x02\x12\x04\x8c\x01\r\x0e\n.\n\x04\x04\x0b\x02\x01\
+    \x12\x04\x8e\x01\x04\x19\x1a\x20\x20stream_id\x20of\x20the\x20Stream\x20\
+    chunks\n\n\r\n\x05\x04\x0b\x02\x01\x05\x12\x04\x8e\x01\x04\n\n\r\n\x05\
+    \x04\x0b\x02\x01\x01\x12\x04\x8e\x01\x0b\x14\n\r\n\x05\x04\x0b\x02\x01\
+    \x03\x12\x04\x8e\x01\x17\x18\n?\n\x04\x04\x0b\x02\x02\x12\x04\x90\x01\
+    \x04\x17\x1a1\x20number\x20of\x20chunks\x20the\x20client\x20is\x20ready\
+    \x20to\x20receive\n\n\r\n\x05\x04\x0b\x02\x02\x05\x12\x04\x90\x01\x04\n\
+    \n\r\n\x05\x04\x0b\x02\x02\x01\x12\x04\x90\x01\x0b\x12\n\r\n\x05\x04\x0b\
+    \x02\x02\x03\x12\x04\x90\x01\x15\x16\n\x0c\n\x02\x04\x0c\x12\x06\x93\x01\
+    \0\x96\x01\x01\n\x0b\n\x03\x04\x0c\x01\x12\x04\x93\x01\x08\x18\n\x0b\n\
+    \x03\x04\x0c\x07\x12\x04\x94\x01\x04!\n\x0e\n\x06\x04\x0c\x07\xb8\xa7\
+    \x03\x12\x04\x94\x01\x04!\n\x0c\n\x04\x04\x0c\x02\0\x12\x04\x95\x01\x04\
+    \x1d\n\r\n\x05\x04\x0c\x02\0\x06\x12\x04\x95\x01\x04\x11\n\r\n\x05\x04\
+    \x0c\x02\0\x01\x12\x04\x95\x01\x12\x18\n\r\n\x05\x04\x0c\x02\0\x03\x12\
+    \x04\x95\x01\x1b\x1c\n\x0c\n\x02\x04\r\x12\x06\x98\x01\0\xa0\x01\x01\n\
+    \x0b\n\x03\x04\r\x01\x12\x04\x98\x01\x08\x1a\n\x0b\n\x03\x04\r\x07\x12\
+    \x04\x99\x01\x04#\n\x0e\n\x06\x04\r\x07\xb8\xa7\x03\x12\x04\x99\x01\x04#\
+    \n\x0c\n\x04\x04\r\x02\0\x12\x04\x9a\x01\x04\x1d\n\r\n\x05\x04\r\x02\0\
+    \x06\x12\x04\x9a\x01\x04\x11\n\r\n\x05\x04\r\x02\0\x01\x12\x04\x9a\x01\
+    \x12\x18\n\r\n\x05\x04\r\x02\0\x03\x12\x04\x9a\x01\x1b\x1c\n\x0c\n\x04\
+    \x04\r\x02\x01\x12\x04\x9b\x01\x04\x15\n\r\n\x05\x04\r\x02\x01\x05\x12\
+    \x04\x9b\x01\x04\n\n\r\n\x05\x04\r\x02\x01\x01\x12\x04\x9b\x01\x0b\x10\n\
+    \r\n\x05\x04\r\x02\x01\x03\x12\x04\x9b\x01\x13\x14\n)\n\x04\x04\r\x02\
+    \x02\x12\x04\x9d\x01\x04\x19\x1a\x1b\x20latest\x20message\x20if\x20not\
+    \x20set\n\n\r\n\x05\x04\r\x02\x02\x05\x12\x04\x9d\x01\x04\n\n\r\n\x05\
+    \x04\r\x02\x02\x01\x12\x04\x9d\x01\x0b\x14\n\r\n\x05\x04\r\x02\x02\x03\
+    \x12\x04\x9d\x01\x17\x18\n\x1b\n\x04\x04\r\x02\x03\x12\x04\x9f\x01\x04\
+    \x16\x1a\r\x20json,\x20proto\n\n\r\n\x05\x04\r\x02\x03\x05\x12\x04\x9f\
+    \x01\x04\n\n\r\n\x05\x04\r\x02\x03\x01\x12\x04\x9f\x01\x0b\x11\n\r\n\x05\
+    \x04\r\x02\x03\x03\x12\x04\x9f\x01\x14\x15\nD\n\x02\x04\x0e\x12\x06\xa3\
+    \x01\0\xad\x01\x01\x1a6\x20Push\x20every\x20new\x20message\x20of\x20the\
+    \x20topic\x20as\x20Topic/Message\n\n\x0b\n\x03\x04\x0e\x01\x12\x04\xa3\
+    \x01\x08\x1d\n\x0b\n\x03\x04\x0e\x07\x12\x04\xa4\x01\x04&\n\x0e\n\x06\
+    \x04\x0e\x07\xb8\xa7\x03\x12\x04\xa4\x01\x04&\n\x0c\n\x04\x04\x0e\x02\0\
+    \x12\x04\xa5\x01\x04\x1d\n\r\n\x05\x04\x0e\x02\0\x06\x12\x04\xa5\x01\x04\
+    \x11\n\r\n\x05\x04\x0e\x02\0\x01\x12\x04\xa5\x01\x12\x18\n\r\n\x05\x04\
+    \x0e\x02\0\x03\x12\x04\xa5\x01\x1b\x1c\n\x0c\n\x04\x04\x0e\x02\x01\x12\
+    \x04\xa6\x01\x04\x15\n\r\n\x05\x04\x0e\x02\x01\x05\x12\x04\xa6\x01\x04\n\
+    \n\r\n\x05\x04\x0e\x02\x01\x01\x12\x04\xa6\x01\x0b\x10\n\r\n\x05\x04\x0e\
+    \x02\x01\x03\x12\x04\xa6\x01\x13\x14\n\x1b\n\x04\x04\x0e\x02\x02\x12\x04\
+    \xa8\x01\x04\x16\x1a\r\x20json,\x20proto\n\n\r\n\x05\x04\x0e\x02\x02\x05\
+    \x12\x04\xa8\x01\x04\n\n\r\n\x05\x04\x0e\x02\x02\x01\x12\x04\xa8\x01\x0b\
+    \x11\n\r\n\x05\x04\x0e\x02\x02\x03\x12\x04\xa8\x01\x14\x15\n1\n\x04\x04\
+    \x0e\x02\x03\x12\x04\xaa\x01\x04\x18\x1a#\x20messages\x20per\x20second,\
+    \x200:\x20unlimited\n\n\r\n\x05\x04\x0e\x02\x03\x05\x12\x04\xaa\x01\x04\
+    \n\n\r\n\x05\x04\x0e\x02\x03\x01\x12\x04\xaa\x01\x0b\x13\n\r\n\x05\x04\
+    \x0e\x02\x03\x03\x12\x04\xaa\x01\x16\x17\nD\n\x04\x04\x0e\x02\x04\x12\
+    \x04\xac\x01\x04\x19\x1a6\x20skip\x20to\x20the\x20newest\x20message\x20w\
+    hen\x20the\x20client\x20is\x20behind\n\n\r\n\x05\x04\x0e\x02\x04\x05\x12\
+    \x04\xac\x01\x04\x08\n\r\n\x05\x04\x0e\x02\x04\x01\x12\x04\xac\x01\t\x14\
+    \n\r\n\x05\x04\x0e\x02\x04\x03\x12\x04\xac\x01\x17\x18\n\x0c\n\x02\x04\
+    \x0f\x12\x06\xaf\x01\0\xb3\x01\x01\n\x0b\n\x03\x04\x0f\x01\x12\x04\xaf\
+    \x01\x08\x1f\n\x0b\n\x03\x04\x0f\x07\x12\x04\xb0\x01\x04(\n\x0e\n\x06\
+    \x04\x0f\x07\xb8\xa7\x03\x12\x04\xb0\x01\x04(\n\x0c\n\x04\x04\x0f\x02\0\
+    \x12\x04\xb1\x01\x04\x1d\n\r\n\x05\x04\x0f\x02\0\x06\x12\x04\xb1\x01\x04\
+    \x11\n\r\n\x05\x04\x0f\x02\0\x01\x12\x04\xb1\x01\x12\x18\n\r\n\x05\x04\
+    \x0f\x02\0\x03\x12\x04\xb1\x01\x1b\x1c\n\x0c\n\x04\x04\x0f\x02\x01\x12\
+    \x04\xb2\x01\x04\x15\n\r\n\x05\x04\x0f\x02\x01\x05\x12\x04\xb2\x01\x04\n\
+    \n\r\n\x05\x04\x0f\x02\x01\x01\x12\x04\xb2\x01\x0b\x10\n\r\n\x05\x04\x0f\
+    \x02\x01\x03\x12\x04\xb2\x01\x13\x14b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -312,140 +312,101 @@ impl ::protobuf::reflect::ProtobufValue for Status {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Stream {
+pub struct AnnotatedImage {
     // message fields
-    pub path: ::std::string::String,
-    pub subject: ::std::string::String,
-    pub start_time: u64,
-    pub end_time: u64,
-    pub items: ::protobuf::RepeatedField<StreamSet>,
+    pub image: ::protobuf::SingularPtrField<super::primitives::Image>,
+    pub annotations: ::protobuf::SingularPtrField<super::primitives::ImageAnnotations>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Stream {
-    fn default() -> &'a Stream {
-        <Stream as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a AnnotatedImage {
+    fn default() -> &'a AnnotatedImage {
+        <AnnotatedImage as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Stream {
-    pub fn new() -> Stream {
+impl AnnotatedImage {
+    pub fn new() -> AnnotatedImage {
         ::std::default::Default::default()
     }
 
-    // string path = 1;
-
-
-    pub fn get_path(&self) -> &str {
-        &self.path
-    }
-    pub fn clear_path(&mut self) {
-        self.path.clear();
-    }
+    // .viz.Image image = 1;
 
-    // Param is passed by value, moved
-    pub fn set_path(&mut self, v: ::std::string::String) {
-        self.path = v;
-    }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_path(&mut self) -> &mut ::std::string::String {
-        &mut self.path
+    pub fn get_image(&self) -> &super::primitives::Image {
+        self.image.as_ref().unwrap_or_else(|| <super::primitives::Image as ::protobuf::Message>::default_instance())
     }
-
-    // Take field
-    pub fn take_path(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    pub fn clear_image(&mut self) {
+        self.image.clear();
     }
 
-    // string subject = 2;
-
-
-    pub fn get_subject(&self) -> &str {
-        &self.subject
-    }
-    pub fn clear_subject(&mut self) {
-        self.subject.clear();
+    pub fn has_image(&self) -> bool {
+        self.image.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_subject(&mut self, v: ::std::string::String) {
-        self.subject = v;
+    pub fn set_image(&mut self, v: super::primitives::Image) {
+        self.image = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_subject(&mut self) -> &mut ::std::string::String {
-        &mut self.subject
+    pub fn mut_image(&mut self) -> &mut super::primitives::Image {
+        if self.image.is_none() {
+            self.image.set_default();
+        }
+        self.image.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_subject(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.subject, ::std::string::String::new())
-    }
-
-    // uint64 start_time = 3;
-
-
-    pub fn get_start_time(&self) -> u64 {
-        self.start_time
-    }
-    pub fn clear_start_time(&mut self) {
-        self.start_time = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_start_time(&mut self, v: u64) {
-        self.start_time = v;
+    pub fn take_image(&mut self) -> super::primitives::Image {
+        self.image.take().unwrap_or_else(|| super::primitives::Image::new())
     }
 
-    // uint64 end_time = 4;
+    // .viz.ImageAnnotations annotations = 2;
 
 
-    pub fn get_end_time(&self) -> u64 {
-        self.end_time
-    }
-    pub fn clear_end_time(&mut self) {
-        self.end_time = 0;
+    pub fn get_annotations(&self) -> &super::primitives::ImageAnnotations {
+        self.annotations.as_ref().unwrap_or_else(|| <super::primitives::ImageAnnotations as ::protobuf::Message>::default_instance())
     }
-
-    // Param is passed by value, moved
-    pub fn set_end_time(&mut self, v: u64) {
-        self.end_time = v;
+    pub fn clear_annotations(&mut self) {
+        self.annotations.clear();
     }
 
-    // repeated .viz.StreamSet items = 5;
-
-
-    pub fn get_items(&self) -> &[StreamSet] {
-        &self.items
-    }
-    pub fn clear_items(&mut self) {
-        self.items.clear();
+    pub fn has_annotations(&self) -> bool {
+        self.annotations.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<StreamSet>) {
-        self.items = v;
+    pub fn set_annotations(&mut self, v: super::primitives::ImageAnnotations) {
+        self.annotations = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<StreamSet> {
-        &mut self.items
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_annotations(&mut self) -> &mut super::primitives::ImageAnnotations {
+        if self.annotations.is_none() {
+            self.annotations.set_default();
+        }
+        self.annotations.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<StreamSet> {
-        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    pub fn take_annotations(&mut self) -> super::primitives::ImageAnnotations {
+        self.annotations.take().unwrap_or_else(|| super::primitives::ImageAnnotations::new())
     }
 }
 
-impl ::protobuf::Message for Stream {
+impl ::protobuf::Message for AnnotatedImage {
     fn is_initialized(&self) -> bool {
-        for v in &self.items {
+        for v in &self.image {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.annotations {
             if !v.is_initialized() {
                 return false;
             }
@@ -458,27 +419,10 @@ impl ::protobuf::Message for Stream {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.image)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.subject)?;
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.start_time = tmp;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.end_time = tmp;
-                },
-                5 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.annotations)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -492,45 +436,30 @@ impl ::protobuf::Message for Stream {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.path.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.path);
-        }
-        if !self.subject.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.subject);
-        }
-        if self.start_time != 0 {
-            my_size += ::protobuf::rt::value_size(3, self.start_time, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.end_time != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.end_time, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.image.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
-        for value in &self.items {
-            let len = value.compute_size();
+        if let Some(ref v) = self.annotations.as_ref() {
+            let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.path.is_empty() {
-            os.write_string(1, &self.path)?;
-        }
-        if !self.subject.is_empty() {
-            os.write_string(2, &self.subject)?;
-        }
-        if self.start_time != 0 {
-            os.write_uint64(3, self.start_time)?;
-        }
-        if self.end_time != 0 {
-            os.write_uint64(4, self.end_time)?;
+        if let Some(ref v) = self.image.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
-        for v in &self.items {
-            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+        if let Some(ref v) = self.annotations.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
-        };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -561,395 +490,2629 @@ impl ::protobuf::Message for Stream {
         Self::descriptor_static()
     }
 
-    fn new() -> Stream {
-        Stream::new()
+    fn new() -> AnnotatedImage {
+        AnnotatedImage::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "path",
-                |m: &Stream| { &m.path },
-                |m: &mut Stream| { &mut m.path },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "subject",
-                |m: &Stream| { &m.subject },
-                |m: &mut Stream| { &mut m.subject },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                "start_time",
-                |m: &Stream| { &m.start_time },
-                |m: &mut Stream| { &mut m.start_time },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                "end_time",
-                |m: &Stream| { &m.end_time },
-                |m: &mut Stream| { &mut m.end_time },
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::primitives::Image>>(
+                "image",
+                |m: &AnnotatedImage| { &m.image },
+                |m: &mut AnnotatedImage| { &mut m.image },
             ));
-            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StreamSet>>(
-                "items",
-                |m: &Stream| { &m.items },
-                |m: &mut Stream| { &mut m.items },
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::primitives::ImageAnnotations>>(
+                "annotations",
+                |m: &AnnotatedImage| { &m.annotations },
+                |m: &mut AnnotatedImage| { &mut m.annotations },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Stream>(
-                "Stream",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AnnotatedImage>(
+                "AnnotatedImage",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static Stream {
-        static instance: ::protobuf::rt::LazyV2<Stream> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Stream::new)
+    fn default_instance() -> &'static AnnotatedImage {
+        static instance: ::protobuf::rt::LazyV2<AnnotatedImage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AnnotatedImage::new)
     }
 }
 
-impl ::protobuf::Clear for Stream {
+impl ::protobuf::Clear for AnnotatedImage {
     fn clear(&mut self) {
-        self.path.clear();
-        self.subject.clear();
-        self.start_time = 0;
-        self.end_time = 0;
-        self.items.clear();
+        self.image.clear();
+        self.annotations.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Stream {
+impl ::std::fmt::Debug for AnnotatedImage {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Stream {
+impl ::protobuf::reflect::ProtobufValue for AnnotatedImage {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct StreamSet {
+pub struct SeriesBucket {
     // message fields
-    pub timestamp: u64,
-    // message oneof groups
-    pub Schema: ::std::option::Option<StreamSet_oneof_Schema>,
+    pub end_time: u64,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub count: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a StreamSet {
-    fn default() -> &'a StreamSet {
-        <StreamSet as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SeriesBucket {
+    fn default() -> &'a SeriesBucket {
+        <SeriesBucket as ::protobuf::Message>::default_instance()
     }
 }
 
-#[derive(Clone,PartialEq,Debug)]
-pub enum StreamSet_oneof_Schema {
-    status(Status),
-    text(super::primitives::Text),
-    point2d(super::primitives::Point2d),
-    point3d(super::primitives::Point3d),
-    image(super::primitives::Image),
-}
-
-impl StreamSet {
-    pub fn new() -> StreamSet {
+impl SeriesBucket {
+    pub fn new() -> SeriesBucket {
         ::std::default::Default::default()
     }
 
-    // uint64 timestamp = 1;
+    // uint64 end_time = 1;
 
 
-    pub fn get_timestamp(&self) -> u64 {
-        self.timestamp
+    pub fn get_end_time(&self) -> u64 {
+        self.end_time
     }
-    pub fn clear_timestamp(&mut self) {
-        self.timestamp = 0;
+    pub fn clear_end_time(&mut self) {
+        self.end_time = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_timestamp(&mut self, v: u64) {
-        self.timestamp = v;
+    pub fn set_end_time(&mut self, v: u64) {
+        self.end_time = v;
     }
 
-    // .viz.Status status = 2;
+    // double min = 2;
 
 
-    pub fn get_status(&self) -> &Status {
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::status(ref v)) => v,
-            _ => <Status as ::protobuf::Message>::default_instance(),
-        }
+    pub fn get_min(&self) -> f64 {
+        self.min
     }
-    pub fn clear_status(&mut self) {
-        self.Schema = ::std::option::Option::None;
+    pub fn clear_min(&mut self) {
+        self.min = 0.;
     }
 
-    pub fn has_status(&self) -> bool {
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::status(..)) => true,
-            _ => false,
-        }
+    // Param is passed by value, moved
+    pub fn set_min(&mut self, v: f64) {
+        self.min = v;
+    }
+
+    // double max = 3;
+
+
+    pub fn get_max(&self) -> f64 {
+        self.max
+    }
+    pub fn clear_max(&mut self) {
+        self.max = 0.;
     }
 
     // Param is passed by value, moved
-    pub fn set_status(&mut self, v: Status) {
-        self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::status(v))
+    pub fn set_max(&mut self, v: f64) {
+        self.max = v;
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_status(&mut self) -> &mut Status {
-        if let ::std::option::Option::Some(StreamSet_oneof_Schema::status(_)) = self.Schema {
-        } else {
-            self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::status(Status::new()));
-        }
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::status(ref mut v)) => v,
+    // double mean = 4;
+
+
+    pub fn get_mean(&self) -> f64 {
+        self.mean
+    }
+    pub fn clear_mean(&mut self) {
+        self.mean = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mean(&mut self, v: f64) {
+        self.mean = v;
+    }
+
+    // uint32 count = 5;
+
+
+    pub fn get_count(&self) -> u32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u32) {
+        self.count = v;
+    }
+}
+
+impl ::protobuf::Message for SeriesBucket {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.end_time = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.min = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.max = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.mean = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.end_time != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.end_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.min != 0. {
+            my_size += 9;
+        }
+        if self.max != 0. {
+            my_size += 9;
+        }
+        if self.mean != 0. {
+            my_size += 9;
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.end_time != 0 {
+            os.write_uint64(1, self.end_time)?;
+        }
+        if self.min != 0. {
+            os.write_double(2, self.min)?;
+        }
+        if self.max != 0. {
+            os.write_double(3, self.max)?;
+        }
+        if self.mean != 0. {
+            os.write_double(4, self.mean)?;
+        }
+        if self.count != 0 {
+            os.write_uint32(5, self.count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SeriesBucket {
+        SeriesBucket::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "end_time",
+                |m: &SeriesBucket| { &m.end_time },
+                |m: &mut SeriesBucket| { &mut m.end_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "min",
+                |m: &SeriesBucket| { &m.min },
+                |m: &mut SeriesBucket| { &mut m.min },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "max",
+                |m: &SeriesBucket| { &m.max },
+                |m: &mut SeriesBucket| { &mut m.max },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "mean",
+                |m: &SeriesBucket| { &m.mean },
+                |m: &mut SeriesBucket| { &mut m.mean },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "count",
+                |m: &SeriesBucket| { &m.count },
+                |m: &mut SeriesBucket| { &mut m.count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SeriesBucket>(
+                "SeriesBucket",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SeriesBucket {
+        static instance: ::protobuf::rt::LazyV2<SeriesBucket> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SeriesBucket::new)
+    }
+}
+
+impl ::protobuf::Clear for SeriesBucket {
+    fn clear(&mut self) {
+        self.end_time = 0;
+        self.min = 0.;
+        self.max = 0.;
+        self.mean = 0.;
+        self.count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SeriesBucket {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SeriesBucket {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Stream {
+    // message fields
+    pub path: ::std::string::String,
+    pub subject: ::std::string::String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub items: ::protobuf::RepeatedField<StreamSet>,
+    pub sequence: u32,
+    pub end_of_stream: bool,
+    pub stream_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Stream {
+    fn default() -> &'a Stream {
+        <Stream as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Stream {
+    pub fn new() -> Stream {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // string subject = 2;
+
+
+    pub fn get_subject(&self) -> &str {
+        &self.subject
+    }
+    pub fn clear_subject(&mut self) {
+        self.subject.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subject(&mut self, v: ::std::string::String) {
+        self.subject = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_subject(&mut self) -> &mut ::std::string::String {
+        &mut self.subject
+    }
+
+    // Take field
+    pub fn take_subject(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.subject, ::std::string::String::new())
+    }
+
+    // uint64 start_time = 3;
+
+
+    pub fn get_start_time(&self) -> u64 {
+        self.start_time
+    }
+    pub fn clear_start_time(&mut self) {
+        self.start_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_time(&mut self, v: u64) {
+        self.start_time = v;
+    }
+
+    // uint64 end_time = 4;
+
+
+    pub fn get_end_time(&self) -> u64 {
+        self.end_time
+    }
+    pub fn clear_end_time(&mut self) {
+        self.end_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_time(&mut self, v: u64) {
+        self.end_time = v;
+    }
+
+    // repeated .viz.StreamSet items = 5;
+
+
+    pub fn get_items(&self) -> &[StreamSet] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<StreamSet>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<StreamSet> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<StreamSet> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+
+    // uint32 sequence = 6;
+
+
+    pub fn get_sequence(&self) -> u32 {
+        self.sequence
+    }
+    pub fn clear_sequence(&mut self) {
+        self.sequence = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sequence(&mut self, v: u32) {
+        self.sequence = v;
+    }
+
+    // bool end_of_stream = 7;
+
+
+    pub fn get_end_of_stream(&self) -> bool {
+        self.end_of_stream
+    }
+    pub fn clear_end_of_stream(&mut self) {
+        self.end_of_stream = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_of_stream(&mut self, v: bool) {
+        self.end_of_stream = v;
+    }
+
+    // uint32 stream_id = 8;
+
+
+    pub fn get_stream_id(&self) -> u32 {
+        self.stream_id
+    }
+    pub fn clear_stream_id(&mut self) {
+        self.stream_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stream_id(&mut self, v: u32) {
+        self.stream_id = v;
+    }
+}
+
+impl ::protobuf::Message for Stream {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.subject)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_time = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.end_time = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.sequence = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.end_of_stream = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.stream_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if !self.subject.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.subject);
+        }
+        if self.start_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.start_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end_time != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.end_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.sequence, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end_of_stream != false {
+            my_size += 2;
+        }
+        if self.stream_id != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.stream_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if !self.subject.is_empty() {
+            os.write_string(2, &self.subject)?;
+        }
+        if self.start_time != 0 {
+            os.write_uint64(3, self.start_time)?;
+        }
+        if self.end_time != 0 {
+            os.write_uint64(4, self.end_time)?;
+        }
+        for v in &self.items {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.sequence != 0 {
+            os.write_uint32(6, self.sequence)?;
+        }
+        if self.end_of_stream != false {
+            os.write_bool(7, self.end_of_stream)?;
+        }
+        if self.stream_id != 0 {
+            os.write_uint32(8, self.stream_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Stream {
+        Stream::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &Stream| { &m.path },
+                |m: &mut Stream| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "subject",
+                |m: &Stream| { &m.subject },
+                |m: &mut Stream| { &mut m.subject },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "start_time",
+                |m: &Stream| { &m.start_time },
+                |m: &mut Stream| { &mut m.start_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "end_time",
+                |m: &Stream| { &m.end_time },
+                |m: &mut Stream| { &mut m.end_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StreamSet>>(
+                "items",
+                |m: &Stream| { &m.items },
+                |m: &mut Stream| { &mut m.items },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "sequence",
+                |m: &Stream| { &m.sequence },
+                |m: &mut Stream| { &mut m.sequence },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "end_of_stream",
+                |m: &Stream| { &m.end_of_stream },
+                |m: &mut Stream| { &mut m.end_of_stream },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "stream_id",
+                |m: &Stream| { &m.stream_id },
+                |m: &mut Stream| { &mut m.stream_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Stream>(
+                "Stream",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Stream {
+        static instance: ::protobuf::rt::LazyV2<Stream> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Stream::new)
+    }
+}
+
+impl ::protobuf::Clear for Stream {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.subject.clear();
+        self.start_time = 0;
+        self.end_time = 0;
+        self.items.clear();
+        self.sequence = 0;
+        self.end_of_stream = false;
+        self.stream_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Stream {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Stream {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StreamSet {
+    // message fields
+    pub timestamp: u64,
+    pub trace_id: ::std::string::String,
+    // message oneof groups
+    pub Schema: ::std::option::Option<StreamSet_oneof_Schema>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StreamSet {
+    fn default() -> &'a StreamSet {
+        <StreamSet as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum StreamSet_oneof_Schema {
+    status(Status),
+    text(super::primitives::Text),
+    point2d(super::primitives::Point2d),
+    point3d(super::primitives::Point3d),
+    image(super::primitives::Image),
+    annotated_image(AnnotatedImage),
+    series_bucket(SeriesBucket),
+}
+
+impl StreamSet {
+    pub fn new() -> StreamSet {
+        ::std::default::Default::default()
+    }
+
+    // uint64 timestamp = 1;
+
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: u64) {
+        self.timestamp = v;
+    }
+
+    // .viz.Status status = 2;
+
+
+    pub fn get_status(&self) -> &Status {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::status(ref v)) => v,
+            _ => <Status as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_status(&mut self) {
+        self.Schema = ::std::option::Option::None;
+    }
+
+    pub fn has_status(&self) -> bool {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::status(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: Status) {
+        self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::status(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_status(&mut self) -> &mut Status {
+        if let ::std::option::Option::Some(StreamSet_oneof_Schema::status(_)) = self.Schema {
+        } else {
+            self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::status(Status::new()));
+        }
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::status(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_status(&mut self) -> Status {
+        if self.has_status() {
+            match self.Schema.take() {
+                ::std::option::Option::Some(StreamSet_oneof_Schema::status(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Status::new()
+        }
+    }
+
+    // .viz.Text text = 3;
+
+
+    pub fn get_text(&self) -> &super::primitives::Text {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::text(ref v)) => v,
+            _ => <super::primitives::Text as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_text(&mut self) {
+        self.Schema = ::std::option::Option::None;
+    }
+
+    pub fn has_text(&self) -> bool {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::text(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: super::primitives::Text) {
+        self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::text(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_text(&mut self) -> &mut super::primitives::Text {
+        if let ::std::option::Option::Some(StreamSet_oneof_Schema::text(_)) = self.Schema {
+        } else {
+            self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::text(super::primitives::Text::new()));
+        }
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::text(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> super::primitives::Text {
+        if self.has_text() {
+            match self.Schema.take() {
+                ::std::option::Option::Some(StreamSet_oneof_Schema::text(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::primitives::Text::new()
+        }
+    }
+
+    // .viz.Point2d point2d = 4;
+
+
+    pub fn get_point2d(&self) -> &super::primitives::Point2d {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(ref v)) => v,
+            _ => <super::primitives::Point2d as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_point2d(&mut self) {
+        self.Schema = ::std::option::Option::None;
+    }
+
+    pub fn has_point2d(&self) -> bool {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_point2d(&mut self, v: super::primitives::Point2d) {
+        self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_point2d(&mut self) -> &mut super::primitives::Point2d {
+        if let ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(_)) = self.Schema {
+        } else {
+            self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(super::primitives::Point2d::new()));
+        }
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_point2d(&mut self) -> super::primitives::Point2d {
+        if self.has_point2d() {
+            match self.Schema.take() {
+                ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::primitives::Point2d::new()
+        }
+    }
+
+    // .viz.Point3d point3d = 5;
+
+
+    pub fn get_point3d(&self) -> &super::primitives::Point3d {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(ref v)) => v,
+            _ => <super::primitives::Point3d as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_point3d(&mut self) {
+        self.Schema = ::std::option::Option::None;
+    }
+
+    pub fn has_point3d(&self) -> bool {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_point3d(&mut self, v: super::primitives::Point3d) {
+        self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_point3d(&mut self) -> &mut super::primitives::Point3d {
+        if let ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(_)) = self.Schema {
+        } else {
+            self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(super::primitives::Point3d::new()));
+        }
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_point3d(&mut self) -> super::primitives::Point3d {
+        if self.has_point3d() {
+            match self.Schema.take() {
+                ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::primitives::Point3d::new()
+        }
+    }
+
+    // .viz.Image image = 6;
+
+
+    pub fn get_image(&self) -> &super::primitives::Image {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::image(ref v)) => v,
+            _ => <super::primitives::Image as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_image(&mut self) {
+        self.Schema = ::std::option::Option::None;
+    }
+
+    pub fn has_image(&self) -> bool {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::image(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_image(&mut self, v: super::primitives::Image) {
+        self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::image(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_image(&mut self) -> &mut super::primitives::Image {
+        if let ::std::option::Option::Some(StreamSet_oneof_Schema::image(_)) = self.Schema {
+        } else {
+            self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::image(super::primitives::Image::new()));
+        }
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::image(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_image(&mut self) -> super::primitives::Image {
+        if self.has_image() {
+            match self.Schema.take() {
+                ::std::option::Option::Some(StreamSet_oneof_Schema::image(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::primitives::Image::new()
+        }
+    }
+
+    // .viz.AnnotatedImage annotated_image = 7;
+
+
+    pub fn get_annotated_image(&self) -> &AnnotatedImage {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::annotated_image(ref v)) => v,
+            _ => <AnnotatedImage as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_annotated_image(&mut self) {
+        self.Schema = ::std::option::Option::None;
+    }
+
+    pub fn has_annotated_image(&self) -> bool {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::annotated_image(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_annotated_image(&mut self, v: AnnotatedImage) {
+        self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::annotated_image(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_annotated_image(&mut self) -> &mut AnnotatedImage {
+        if let ::std::option::Option::Some(StreamSet_oneof_Schema::annotated_image(_)) = self.Schema {
+        } else {
+            self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::annotated_image(AnnotatedImage::new()));
+        }
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::annotated_image(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_annotated_image(&mut self) -> AnnotatedImage {
+        if self.has_annotated_image() {
+            match self.Schema.take() {
+                ::std::option::Option::Some(StreamSet_oneof_Schema::annotated_image(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AnnotatedImage::new()
+        }
+    }
+
+    // .viz.SeriesBucket series_bucket = 8;
+
+
+    pub fn get_series_bucket(&self) -> &SeriesBucket {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::series_bucket(ref v)) => v,
+            _ => <SeriesBucket as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_series_bucket(&mut self) {
+        self.Schema = ::std::option::Option::None;
+    }
+
+    pub fn has_series_bucket(&self) -> bool {
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::series_bucket(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_series_bucket(&mut self, v: SeriesBucket) {
+        self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::series_bucket(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_series_bucket(&mut self) -> &mut SeriesBucket {
+        if let ::std::option::Option::Some(StreamSet_oneof_Schema::series_bucket(_)) = self.Schema {
+        } else {
+            self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::series_bucket(SeriesBucket::new()));
+        }
+        match self.Schema {
+            ::std::option::Option::Some(StreamSet_oneof_Schema::series_bucket(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_series_bucket(&mut self) -> SeriesBucket {
+        if self.has_series_bucket() {
+            match self.Schema.take() {
+                ::std::option::Option::Some(StreamSet_oneof_Schema::series_bucket(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SeriesBucket::new()
+        }
+    }
+
+    // string trace_id = 9;
+
+
+    pub fn get_trace_id(&self) -> &str {
+        &self.trace_id
+    }
+    pub fn clear_trace_id(&mut self) {
+        self.trace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_trace_id(&mut self, v: ::std::string::String) {
+        self.trace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_trace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.trace_id
+    }
+
+    // Take field
+    pub fn take_trace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.trace_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for StreamSet {
+    fn is_initialized(&self) -> bool {
+        if let Some(StreamSet_oneof_Schema::status(ref v)) = self.Schema {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(StreamSet_oneof_Schema::text(ref v)) = self.Schema {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(StreamSet_oneof_Schema::point2d(ref v)) = self.Schema {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(StreamSet_oneof_Schema::point3d(ref v)) = self.Schema {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(StreamSet_oneof_Schema::image(ref v)) = self.Schema {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(StreamSet_oneof_Schema::annotated_image(ref v)) = self.Schema {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(StreamSet_oneof_Schema::series_bucket(ref v)) = self.Schema {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.timestamp = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::status(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::text(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::image(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::annotated_image(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::series_bucket(is.read_message()?));
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.trace_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.trace_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.Schema {
+            match v {
+                &StreamSet_oneof_Schema::status(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &StreamSet_oneof_Schema::text(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &StreamSet_oneof_Schema::point2d(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &StreamSet_oneof_Schema::point3d(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &StreamSet_oneof_Schema::image(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &StreamSet_oneof_Schema::annotated_image(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &StreamSet_oneof_Schema::series_bucket(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.timestamp != 0 {
+            os.write_uint64(1, self.timestamp)?;
+        }
+        if !self.trace_id.is_empty() {
+            os.write_string(9, &self.trace_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.Schema {
+            match v {
+                &StreamSet_oneof_Schema::status(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &StreamSet_oneof_Schema::text(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &StreamSet_oneof_Schema::point2d(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &StreamSet_oneof_Schema::point3d(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &StreamSet_oneof_Schema::image(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &StreamSet_oneof_Schema::annotated_image(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &StreamSet_oneof_Schema::series_bucket(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StreamSet {
+        StreamSet::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "timestamp",
+                |m: &StreamSet| { &m.timestamp },
+                |m: &mut StreamSet| { &mut m.timestamp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Status>(
+                "status",
+                StreamSet::has_status,
+                StreamSet::get_status,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::primitives::Text>(
+                "text",
+                StreamSet::has_text,
+                StreamSet::get_text,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::primitives::Point2d>(
+                "point2d",
+                StreamSet::has_point2d,
+                StreamSet::get_point2d,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::primitives::Point3d>(
+                "point3d",
+                StreamSet::has_point3d,
+                StreamSet::get_point3d,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::primitives::Image>(
+                "image",
+                StreamSet::has_image,
+                StreamSet::get_image,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, AnnotatedImage>(
+                "annotated_image",
+                StreamSet::has_annotated_image,
+                StreamSet::get_annotated_image,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SeriesBucket>(
+                "series_bucket",
+                StreamSet::has_series_bucket,
+                StreamSet::get_series_bucket,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "trace_id",
+                |m: &StreamSet| { &m.trace_id },
+                |m: &mut StreamSet| { &mut m.trace_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StreamSet>(
+                "StreamSet",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StreamSet {
+        static instance: ::protobuf::rt::LazyV2<StreamSet> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StreamSet::new)
+    }
+}
+
+impl ::protobuf::Clear for StreamSet {
+    fn clear(&mut self) {
+        self.timestamp = 0;
+        self.Schema = ::std::option::Option::None;
+        self.Schema = ::std::option::Option::None;
+        self.Schema = ::std::option::Option::None;
+        self.Schema = ::std::option::Option::None;
+        self.Schema = ::std::option::Option::None;
+        self.Schema = ::std::option::Option::None;
+        self.Schema = ::std::option::Option::None;
+        self.trace_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StreamSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StreamSet {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Event {
+    // message fields
+    pub timestamp: u64,
+    // message oneof groups
+    pub Kind: ::std::option::Option<Event_oneof_Kind>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Event {
+    fn default() -> &'a Event {
+        <Event as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Event_oneof_Kind {
+    record_changed(RecordChanged),
+    topic_added(TopicAdded),
+    pubsub_connection(PubsubConnection),
+    disk_space(DiskSpace),
+}
+
+impl Event {
+    pub fn new() -> Event {
+        ::std::default::Default::default()
+    }
+
+    // uint64 timestamp = 1;
+
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: u64) {
+        self.timestamp = v;
+    }
+
+    // .viz.RecordChanged record_changed = 2;
+
+
+    pub fn get_record_changed(&self) -> &RecordChanged {
+        match self.Kind {
+            ::std::option::Option::Some(Event_oneof_Kind::record_changed(ref v)) => v,
+            _ => <RecordChanged as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_record_changed(&mut self) {
+        self.Kind = ::std::option::Option::None;
+    }
+
+    pub fn has_record_changed(&self) -> bool {
+        match self.Kind {
+            ::std::option::Option::Some(Event_oneof_Kind::record_changed(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_record_changed(&mut self, v: RecordChanged) {
+        self.Kind = ::std::option::Option::Some(Event_oneof_Kind::record_changed(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_record_changed(&mut self) -> &mut RecordChanged {
+        if let ::std::option::Option::Some(Event_oneof_Kind::record_changed(_)) = self.Kind {
+        } else {
+            self.Kind = ::std::option::Option::Some(Event_oneof_Kind::record_changed(RecordChanged::new()));
+        }
+        match self.Kind {
+            ::std::option::Option::Some(Event_oneof_Kind::record_changed(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_record_changed(&mut self) -> RecordChanged {
+        if self.has_record_changed() {
+            match self.Kind.take() {
+                ::std::option::Option::Some(Event_oneof_Kind::record_changed(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RecordChanged::new()
+        }
+    }
+
+    // .viz.TopicAdded topic_added = 3;
+
+
+    pub fn get_topic_added(&self) -> &TopicAdded {
+        match self.Kind {
+            ::std::option::Option::Some(Event_oneof_Kind::topic_added(ref v)) => v,
+            _ => <TopicAdded as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_topic_added(&mut self) {
+        self.Kind = ::std::option::Option::None;
+    }
+
+    pub fn has_topic_added(&self) -> bool {
+        match self.Kind {
+            ::std::option::Option::Some(Event_oneof_Kind::topic_added(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_topic_added(&mut self, v: TopicAdded) {
+        self.Kind = ::std::option::Option::Some(Event_oneof_Kind::topic_added(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_topic_added(&mut self) -> &mut TopicAdded {
+        if let ::std::option::Option::Some(Event_oneof_Kind::topic_added(_)) = self.Kind {
+        } else {
+            self.Kind = ::std::option::Option::Some(Event_oneof_Kind::topic_added(TopicAdded::new()));
+        }
+        match self.Kind {
+            ::std::option::Option::Some(Event_oneof_Kind::topic_added(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_topic_added(&mut self) -> TopicAdded {
+        if self.has_topic_added() {
+            match self.Kind.take() {
+                ::std::option::Option::Some(Event_oneof_Kind::topic_added(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            TopicAdded::new()
+        }
+    }
+
+    // .viz.PubsubConnection pubsub_connection = 4;
+
+
+    pub fn get_pubsub_connection(&self) -> &PubsubConnection {
+        match self.Kind {
+            ::std::option::Option::Some(Event_oneof_Kind::pubsub_connection(ref v)) => v,
+            _ => <PubsubConnection as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_pubsub_connection(&mut self) {
+        self.Kind = ::std::option::Option::None;
+    }
+
+    pub fn has_pubsub_connection(&self) -> bool {
+        match self.Kind {
+            ::std::option::Option::Some(Event_oneof_Kind::pubsub_connection(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pubsub_connection(&mut self, v: PubsubConnection) {
+        self.Kind = ::std::option::Option::Some(Event_oneof_Kind::pubsub_connection(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_pubsub_connection(&mut self) -> &mut PubsubConnection {
+        if let ::std::option::Option::Some(Event_oneof_Kind::pubsub_connection(_)) = self.Kind {
+        } else {
+            self.Kind = ::std::option::Option::Some(Event_oneof_Kind::pubsub_connection(PubsubConnection::new()));
+        }
+        match self.Kind {
+            ::std::option::Option::Some(Event_oneof_Kind::pubsub_connection(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_pubsub_connection(&mut self) -> PubsubConnection {
+        if self.has_pubsub_connection() {
+            match self.Kind.take() {
+                ::std::option::Option::Some(Event_oneof_Kind::pubsub_connection(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PubsubConnection::new()
+        }
+    }
+
+    // .viz.DiskSpace disk_space = 5;
+
+
+    pub fn get_disk_space(&self) -> &DiskSpace {
+        match self.Kind {
+            ::std::option::Option::Some(Event_oneof_Kind::disk_space(ref v)) => v,
+            _ => <DiskSpace as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_disk_space(&mut self) {
+        self.Kind = ::std::option::Option::None;
+    }
+
+    pub fn has_disk_space(&self) -> bool {
+        match self.Kind {
+            ::std::option::Option::Some(Event_oneof_Kind::disk_space(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_disk_space(&mut self, v: DiskSpace) {
+        self.Kind = ::std::option::Option::Some(Event_oneof_Kind::disk_space(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_disk_space(&mut self) -> &mut DiskSpace {
+        if let ::std::option::Option::Some(Event_oneof_Kind::disk_space(_)) = self.Kind {
+        } else {
+            self.Kind = ::std::option::Option::Some(Event_oneof_Kind::disk_space(DiskSpace::new()));
+        }
+        match self.Kind {
+            ::std::option::Option::Some(Event_oneof_Kind::disk_space(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
-    // Take field
-    pub fn take_status(&mut self) -> Status {
-        if self.has_status() {
-            match self.Schema.take() {
-                ::std::option::Option::Some(StreamSet_oneof_Schema::status(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Status::new()
-        }
+    // Take field
+    pub fn take_disk_space(&mut self) -> DiskSpace {
+        if self.has_disk_space() {
+            match self.Kind.take() {
+                ::std::option::Option::Some(Event_oneof_Kind::disk_space(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DiskSpace::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for Event {
+    fn is_initialized(&self) -> bool {
+        if let Some(Event_oneof_Kind::record_changed(ref v)) = self.Kind {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Event_oneof_Kind::topic_added(ref v)) = self.Kind {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Event_oneof_Kind::pubsub_connection(ref v)) = self.Kind {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Event_oneof_Kind::disk_space(ref v)) = self.Kind {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.timestamp = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.Kind = ::std::option::Option::Some(Event_oneof_Kind::record_changed(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.Kind = ::std::option::Option::Some(Event_oneof_Kind::topic_added(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.Kind = ::std::option::Option::Some(Event_oneof_Kind::pubsub_connection(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.Kind = ::std::option::Option::Some(Event_oneof_Kind::disk_space(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let ::std::option::Option::Some(ref v) = self.Kind {
+            match v {
+                &Event_oneof_Kind::record_changed(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Event_oneof_Kind::topic_added(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Event_oneof_Kind::pubsub_connection(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Event_oneof_Kind::disk_space(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.timestamp != 0 {
+            os.write_uint64(1, self.timestamp)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.Kind {
+            match v {
+                &Event_oneof_Kind::record_changed(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Event_oneof_Kind::topic_added(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Event_oneof_Kind::pubsub_connection(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Event_oneof_Kind::disk_space(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Event {
+        Event::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "timestamp",
+                |m: &Event| { &m.timestamp },
+                |m: &mut Event| { &mut m.timestamp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, RecordChanged>(
+                "record_changed",
+                Event::has_record_changed,
+                Event::get_record_changed,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, TopicAdded>(
+                "topic_added",
+                Event::has_topic_added,
+                Event::get_topic_added,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PubsubConnection>(
+                "pubsub_connection",
+                Event::has_pubsub_connection,
+                Event::get_pubsub_connection,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, DiskSpace>(
+                "disk_space",
+                Event::has_disk_space,
+                Event::get_disk_space,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Event>(
+                "Event",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Event {
+        static instance: ::protobuf::rt::LazyV2<Event> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Event::new)
+    }
+}
+
+impl ::protobuf::Clear for Event {
+    fn clear(&mut self) {
+        self.timestamp = 0;
+        self.Kind = ::std::option::Option::None;
+        self.Kind = ::std::option::Option::None;
+        self.Kind = ::std::option::Option::None;
+        self.Kind = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Event {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Event {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RecordChanged {
+    // message fields
+    pub enabled: bool,
+    pub changed_by: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RecordChanged {
+    fn default() -> &'a RecordChanged {
+        <RecordChanged as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RecordChanged {
+    pub fn new() -> RecordChanged {
+        ::std::default::Default::default()
+    }
+
+    // bool enabled = 1;
+
+
+    pub fn get_enabled(&self) -> bool {
+        self.enabled
+    }
+    pub fn clear_enabled(&mut self) {
+        self.enabled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enabled(&mut self, v: bool) {
+        self.enabled = v;
+    }
+
+    // string changed_by = 2;
+
+
+    pub fn get_changed_by(&self) -> &str {
+        &self.changed_by
+    }
+    pub fn clear_changed_by(&mut self) {
+        self.changed_by.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_changed_by(&mut self, v: ::std::string::String) {
+        self.changed_by = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_changed_by(&mut self) -> &mut ::std::string::String {
+        &mut self.changed_by
+    }
+
+    // Take field
+    pub fn take_changed_by(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.changed_by, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RecordChanged {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.enabled = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.changed_by)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.enabled != false {
+            my_size += 2;
+        }
+        if !self.changed_by.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.changed_by);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.enabled != false {
+            os.write_bool(1, self.enabled)?;
+        }
+        if !self.changed_by.is_empty() {
+            os.write_string(2, &self.changed_by)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RecordChanged {
+        RecordChanged::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "enabled",
+                |m: &RecordChanged| { &m.enabled },
+                |m: &mut RecordChanged| { &mut m.enabled },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "changed_by",
+                |m: &RecordChanged| { &m.changed_by },
+                |m: &mut RecordChanged| { &mut m.changed_by },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RecordChanged>(
+                "RecordChanged",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RecordChanged {
+        static instance: ::protobuf::rt::LazyV2<RecordChanged> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RecordChanged::new)
+    }
+}
+
+impl ::protobuf::Clear for RecordChanged {
+    fn clear(&mut self) {
+        self.enabled = false;
+        self.changed_by.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RecordChanged {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RecordChanged {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TopicAdded {
+    // message fields
+    pub topic: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TopicAdded {
+    fn default() -> &'a TopicAdded {
+        <TopicAdded as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TopicAdded {
+    pub fn new() -> TopicAdded {
+        ::std::default::Default::default()
+    }
+
+    // string topic = 1;
+
+
+    pub fn get_topic(&self) -> &str {
+        &self.topic
+    }
+    pub fn clear_topic(&mut self) {
+        self.topic.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_topic(&mut self, v: ::std::string::String) {
+        self.topic = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_topic(&mut self) -> &mut ::std::string::String {
+        &mut self.topic
+    }
+
+    // Take field
+    pub fn take_topic(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.topic, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for TopicAdded {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.topic)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.topic.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.topic);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.topic.is_empty() {
+            os.write_string(1, &self.topic)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
     }
 
-    // .viz.Text text = 3;
+    fn new() -> TopicAdded {
+        TopicAdded::new()
+    }
 
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "topic",
+                |m: &TopicAdded| { &m.topic },
+                |m: &mut TopicAdded| { &mut m.topic },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TopicAdded>(
+                "TopicAdded",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
 
-    pub fn get_text(&self) -> &super::primitives::Text {
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::text(ref v)) => v,
-            _ => <super::primitives::Text as ::protobuf::Message>::default_instance(),
-        }
+    fn default_instance() -> &'static TopicAdded {
+        static instance: ::protobuf::rt::LazyV2<TopicAdded> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TopicAdded::new)
     }
-    pub fn clear_text(&mut self) {
-        self.Schema = ::std::option::Option::None;
+}
+
+impl ::protobuf::Clear for TopicAdded {
+    fn clear(&mut self) {
+        self.topic.clear();
+        self.unknown_fields.clear();
     }
+}
 
-    pub fn has_text(&self) -> bool {
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::text(..)) => true,
-            _ => false,
-        }
+impl ::std::fmt::Debug for TopicAdded {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_text(&mut self, v: super::primitives::Text) {
-        self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::text(v))
+impl ::protobuf::reflect::ProtobufValue for TopicAdded {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
     }
+}
 
-    // Mutable pointer to the field.
-    pub fn mut_text(&mut self) -> &mut super::primitives::Text {
-        if let ::std::option::Option::Some(StreamSet_oneof_Schema::text(_)) = self.Schema {
-        } else {
-            self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::text(super::primitives::Text::new()));
-        }
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::text(ref mut v)) => v,
-            _ => panic!(),
-        }
+#[derive(PartialEq,Clone,Default)]
+pub struct PubsubConnection {
+    // message fields
+    pub connected: bool,
+    pub reason: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PubsubConnection {
+    fn default() -> &'a PubsubConnection {
+        <PubsubConnection as ::protobuf::Message>::default_instance()
     }
+}
 
-    // Take field
-    pub fn take_text(&mut self) -> super::primitives::Text {
-        if self.has_text() {
-            match self.Schema.take() {
-                ::std::option::Option::Some(StreamSet_oneof_Schema::text(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            super::primitives::Text::new()
-        }
+impl PubsubConnection {
+    pub fn new() -> PubsubConnection {
+        ::std::default::Default::default()
     }
 
-    // .viz.Point2d point2d = 4;
+    // bool connected = 1;
 
 
-    pub fn get_point2d(&self) -> &super::primitives::Point2d {
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(ref v)) => v,
-            _ => <super::primitives::Point2d as ::protobuf::Message>::default_instance(),
-        }
+    pub fn get_connected(&self) -> bool {
+        self.connected
     }
-    pub fn clear_point2d(&mut self) {
-        self.Schema = ::std::option::Option::None;
+    pub fn clear_connected(&mut self) {
+        self.connected = false;
     }
 
-    pub fn has_point2d(&self) -> bool {
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(..)) => true,
-            _ => false,
-        }
+    // Param is passed by value, moved
+    pub fn set_connected(&mut self, v: bool) {
+        self.connected = v;
+    }
+
+    // string reason = 2;
+
+
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_point2d(&mut self, v: super::primitives::Point2d) {
-        self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(v))
+    pub fn set_reason(&mut self, v: ::std::string::String) {
+        self.reason = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_point2d(&mut self) -> &mut super::primitives::Point2d {
-        if let ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(_)) = self.Schema {
-        } else {
-            self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(super::primitives::Point2d::new()));
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.reason
+    }
+
+    // Take field
+    pub fn take_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reason, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PubsubConnection {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.connected = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reason)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(ref mut v)) => v,
-            _ => panic!(),
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.connected != false {
+            my_size += 2;
         }
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.reason);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Take field
-    pub fn take_point2d(&mut self) -> super::primitives::Point2d {
-        if self.has_point2d() {
-            match self.Schema.take() {
-                ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            super::primitives::Point2d::new()
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.connected != false {
+            os.write_bool(1, self.connected)?;
         }
+        if !self.reason.is_empty() {
+            os.write_string(2, &self.reason)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // .viz.Point3d point3d = 5;
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
 
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
 
-    pub fn get_point3d(&self) -> &super::primitives::Point3d {
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(ref v)) => v,
-            _ => <super::primitives::Point3d as ::protobuf::Message>::default_instance(),
-        }
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
-    pub fn clear_point3d(&mut self) {
-        self.Schema = ::std::option::Option::None;
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
     }
 
-    pub fn has_point3d(&self) -> bool {
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(..)) => true,
-            _ => false,
-        }
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PubsubConnection {
+        PubsubConnection::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "connected",
+                |m: &PubsubConnection| { &m.connected },
+                |m: &mut PubsubConnection| { &mut m.connected },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reason",
+                |m: &PubsubConnection| { &m.reason },
+                |m: &mut PubsubConnection| { &mut m.reason },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PubsubConnection>(
+                "PubsubConnection",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PubsubConnection {
+        static instance: ::protobuf::rt::LazyV2<PubsubConnection> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PubsubConnection::new)
+    }
+}
+
+impl ::protobuf::Clear for PubsubConnection {
+    fn clear(&mut self) {
+        self.connected = false;
+        self.reason.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PubsubConnection {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PubsubConnection {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DiskSpace {
+    // message fields
+    pub path: ::std::string::String,
+    pub available: u64,
+    pub total: u64,
+    pub low: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DiskSpace {
+    fn default() -> &'a DiskSpace {
+        <DiskSpace as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DiskSpace {
+    pub fn new() -> DiskSpace {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_point3d(&mut self, v: super::primitives::Point3d) {
-        self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(v))
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_point3d(&mut self) -> &mut super::primitives::Point3d {
-        if let ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(_)) = self.Schema {
-        } else {
-            self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(super::primitives::Point3d::new()));
-        }
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
     }
 
     // Take field
-    pub fn take_point3d(&mut self) -> super::primitives::Point3d {
-        if self.has_point3d() {
-            match self.Schema.take() {
-                ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            super::primitives::Point3d::new()
-        }
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
     }
 
-    // .viz.Image image = 6;
+    // uint64 available = 2;
 
 
-    pub fn get_image(&self) -> &super::primitives::Image {
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::image(ref v)) => v,
-            _ => <super::primitives::Image as ::protobuf::Message>::default_instance(),
-        }
+    pub fn get_available(&self) -> u64 {
+        self.available
     }
-    pub fn clear_image(&mut self) {
-        self.Schema = ::std::option::Option::None;
+    pub fn clear_available(&mut self) {
+        self.available = 0;
     }
 
-    pub fn has_image(&self) -> bool {
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::image(..)) => true,
-            _ => false,
-        }
+    // Param is passed by value, moved
+    pub fn set_available(&mut self, v: u64) {
+        self.available = v;
+    }
+
+    // uint64 total = 3;
+
+
+    pub fn get_total(&self) -> u64 {
+        self.total
+    }
+    pub fn clear_total(&mut self) {
+        self.total = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_image(&mut self, v: super::primitives::Image) {
-        self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::image(v))
+    pub fn set_total(&mut self, v: u64) {
+        self.total = v;
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_image(&mut self) -> &mut super::primitives::Image {
-        if let ::std::option::Option::Some(StreamSet_oneof_Schema::image(_)) = self.Schema {
-        } else {
-            self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::image(super::primitives::Image::new()));
-        }
-        match self.Schema {
-            ::std::option::Option::Some(StreamSet_oneof_Schema::image(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // bool low = 4;
+
+
+    pub fn get_low(&self) -> bool {
+        self.low
+    }
+    pub fn clear_low(&mut self) {
+        self.low = false;
     }
 
-    // Take field
-    pub fn take_image(&mut self) -> super::primitives::Image {
-        if self.has_image() {
-            match self.Schema.take() {
-                ::std::option::Option::Some(StreamSet_oneof_Schema::image(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            super::primitives::Image::new()
-        }
+    // Param is passed by value, moved
+    pub fn set_low(&mut self, v: bool) {
+        self.low = v;
     }
 }
 
-impl ::protobuf::Message for StreamSet {
+impl ::protobuf::Message for DiskSpace {
     fn is_initialized(&self) -> bool {
-        if let Some(StreamSet_oneof_Schema::status(ref v)) = self.Schema {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(StreamSet_oneof_Schema::text(ref v)) = self.Schema {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(StreamSet_oneof_Schema::point2d(ref v)) = self.Schema {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(StreamSet_oneof_Schema::point3d(ref v)) = self.Schema {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(StreamSet_oneof_Schema::image(ref v)) = self.Schema {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
         true
     }
 
@@ -958,41 +3121,28 @@ impl ::protobuf::Message for StreamSet {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.timestamp = tmp;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::status(is.read_message()?));
+                    let tmp = is.read_uint64()?;
+                    self.available = tmp;
                 },
                 3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::text(is.read_message()?));
+                    let tmp = is.read_uint64()?;
+                    self.total = tmp;
                 },
                 4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::point2d(is.read_message()?));
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::point3d(is.read_message()?));
-                },
-                6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.Schema = ::std::option::Option::Some(StreamSet_oneof_Schema::image(is.read_message()?));
+                    let tmp = is.read_bool()?;
+                    self.low = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1006,32 +3156,17 @@ impl ::protobuf::Message for StreamSet {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.timestamp != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
         }
-        if let ::std::option::Option::Some(ref v) = self.Schema {
-            match v {
-                &StreamSet_oneof_Schema::status(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &StreamSet_oneof_Schema::text(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &StreamSet_oneof_Schema::point2d(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &StreamSet_oneof_Schema::point3d(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &StreamSet_oneof_Schema::image(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
+        if self.available != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.available, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.total, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.low != false {
+            my_size += 2;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1039,37 +3174,17 @@ impl ::protobuf::Message for StreamSet {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if self.timestamp != 0 {
-            os.write_uint64(1, self.timestamp)?;
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
         }
-        if let ::std::option::Option::Some(ref v) = self.Schema {
-            match v {
-                &StreamSet_oneof_Schema::status(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &StreamSet_oneof_Schema::text(ref v) => {
-                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &StreamSet_oneof_Schema::point2d(ref v) => {
-                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &StreamSet_oneof_Schema::point3d(ref v) => {
-                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &StreamSet_oneof_Schema::image(ref v) => {
-                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if self.available != 0 {
+            os.write_uint64(2, self.available)?;
+        }
+        if self.total != 0 {
+            os.write_uint64(3, self.total)?;
+        }
+        if self.low != false {
+            os.write_bool(4, self.low)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1101,77 +3216,65 @@ impl ::protobuf::Message for StreamSet {
         Self::descriptor_static()
     }
 
-    fn new() -> StreamSet {
-        StreamSet::new()
+    fn new() -> DiskSpace {
+        DiskSpace::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                "timestamp",
-                |m: &StreamSet| { &m.timestamp },
-                |m: &mut StreamSet| { &mut m.timestamp },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Status>(
-                "status",
-                StreamSet::has_status,
-                StreamSet::get_status,
-            ));
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::primitives::Text>(
-                "text",
-                StreamSet::has_text,
-                StreamSet::get_text,
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &DiskSpace| { &m.path },
+                |m: &mut DiskSpace| { &mut m.path },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::primitives::Point2d>(
-                "point2d",
-                StreamSet::has_point2d,
-                StreamSet::get_point2d,
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "available",
+                |m: &DiskSpace| { &m.available },
+                |m: &mut DiskSpace| { &mut m.available },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::primitives::Point3d>(
-                "point3d",
-                StreamSet::has_point3d,
-                StreamSet::get_point3d,
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "total",
+                |m: &DiskSpace| { &m.total },
+                |m: &mut DiskSpace| { &mut m.total },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::primitives::Image>(
-                "image",
-                StreamSet::has_image,
-                StreamSet::get_image,
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "low",
+                |m: &DiskSpace| { &m.low },
+                |m: &mut DiskSpace| { &mut m.low },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StreamSet>(
-                "StreamSet",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DiskSpace>(
+                "DiskSpace",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static StreamSet {
-        static instance: ::protobuf::rt::LazyV2<StreamSet> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(StreamSet::new)
+    fn default_instance() -> &'static DiskSpace {
+        static instance: ::protobuf::rt::LazyV2<DiskSpace> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DiskSpace::new)
     }
 }
 
-impl ::protobuf::Clear for StreamSet {
+impl ::protobuf::Clear for DiskSpace {
     fn clear(&mut self) {
-        self.timestamp = 0;
-        self.Schema = ::std::option::Option::None;
-        self.Schema = ::std::option::Option::None;
-        self.Schema = ::std::option::Option::None;
-        self.Schema = ::std::option::Option::None;
-        self.Schema = ::std::option::Option::None;
+        self.path.clear();
+        self.available = 0;
+        self.total = 0;
+        self.low = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for StreamSet {
+impl ::std::fmt::Debug for DiskSpace {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for StreamSet {
+impl ::protobuf::reflect::ProtobufValue for DiskSpace {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
@@ -1182,6 +3285,7 @@ pub struct WSResponse {
     // message fields
     pub path: ::std::string::String,
     pub data: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
+    pub trace_id: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -1256,6 +3360,32 @@ impl WSResponse {
     pub fn take_data(&mut self) -> ::protobuf::well_known_types::Any {
         self.data.take().unwrap_or_else(|| ::protobuf::well_known_types::Any::new())
     }
+
+    // string trace_id = 3;
+
+
+    pub fn get_trace_id(&self) -> &str {
+        &self.trace_id
+    }
+    pub fn clear_trace_id(&mut self) {
+        self.trace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_trace_id(&mut self, v: ::std::string::String) {
+        self.trace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_trace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.trace_id
+    }
+
+    // Take field
+    pub fn take_trace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.trace_id, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for WSResponse {
@@ -1278,6 +3408,9 @@ impl ::protobuf::Message for WSResponse {
                 2 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.data)?;
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.trace_id)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1297,6 +3430,9 @@ impl ::protobuf::Message for WSResponse {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.trace_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1311,6 +3447,9 @@ impl ::protobuf::Message for WSResponse {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if !self.trace_id.is_empty() {
+            os.write_string(3, &self.trace_id)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1359,6 +3498,11 @@ impl ::protobuf::Message for WSResponse {
                 |m: &WSResponse| { &m.data },
                 |m: &mut WSResponse| { &mut m.data },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "trace_id",
+                |m: &WSResponse| { &m.trace_id },
+                |m: &mut WSResponse| { &mut m.trace_id },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<WSResponse>(
                 "WSResponse",
                 fields,
@@ -1377,6 +3521,7 @@ impl ::protobuf::Clear for WSResponse {
     fn clear(&mut self) {
         self.path.clear();
         self.data.clear();
+        self.trace_id.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1398,73 +3543,195 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x1a\x19google/protobuf/any.proto\x1a\x10primitives.proto\"w\n\x06Status\
     \x12\x1d\n\x04text\x18\x01\x20\x01(\x0b2\t.viz.TextR\x04text\x12&\n\x07p\
     oint3d\x18\x02\x20\x01(\x0b2\x0c.viz.Point3dR\x07point3d\x12&\n\x07point\
-    2d\x18\x03\x20\x01(\x0b2\x0c.viz.Point2dR\x07point2d\"\x96\x01\n\x06Stre\
-    am\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\x12\x18\n\x07subject\
-    \x18\x02\x20\x01(\tR\x07subject\x12\x1d\n\nstart_time\x18\x03\x20\x01(\
+    2d\x18\x03\x20\x01(\x0b2\x0c.viz.Point2dR\x07point2d\"k\n\x0eAnnotatedIm\
+    age\x12\x20\n\x05image\x18\x01\x20\x01(\x0b2\n.viz.ImageR\x05image\x127\
+    \n\x0bannotations\x18\x02\x20\x01(\x0b2\x15.viz.ImageAnnotationsR\x0bann\
+    otations\"w\n\x0cSeriesBucket\x12\x19\n\x08end_time\x18\x01\x20\x01(\x04\
+    R\x07endTime\x12\x10\n\x03min\x18\x02\x20\x01(\x01R\x03min\x12\x10\n\x03\
+    max\x18\x03\x20\x01(\x01R\x03max\x12\x12\n\x04mean\x18\x04\x20\x01(\x01R\
+    \x04mean\x12\x14\n\x05count\x18\x05\x20\x01(\rR\x05count\"\xf3\x01\n\x06\
+    Stream\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\x12\x18\n\x07subjec\
+    t\x18\x02\x20\x01(\tR\x07subject\x12\x1d\n\nstart_time\x18\x03\x20\x01(\
     \x04R\tstartTime\x12\x19\n\x08end_time\x18\x04\x20\x01(\x04R\x07endTime\
-    \x12$\n\x05items\x18\x05\x20\x03(\x0b2\x0e.viz.StreamSetR\x05items\"\xf3\
-    \x01\n\tStreamSet\x12\x1c\n\ttimestamp\x18\x01\x20\x01(\x04R\ttimestamp\
-    \x12%\n\x06status\x18\x02\x20\x01(\x0b2\x0b.viz.StatusH\0R\x06status\x12\
-    \x1f\n\x04text\x18\x03\x20\x01(\x0b2\t.viz.TextH\0R\x04text\x12(\n\x07po\
-    int2d\x18\x04\x20\x01(\x0b2\x0c.viz.Point2dH\0R\x07point2d\x12(\n\x07poi\
-    nt3d\x18\x05\x20\x01(\x0b2\x0c.viz.Point3dH\0R\x07point3d\x12\"\n\x05ima\
-    ge\x18\x06\x20\x01(\x0b2\n.viz.ImageH\0R\x05imageB\x08\n\x06Schema\"J\n\
-    \nWSResponse\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\x12(\n\x04dat\
-    a\x18\x02\x20\x01(\x0b2\x14.google.protobuf.AnyR\x04dataJ\xc6\x08\n\x06\
-    \x12\x04\0\0(\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\
-    \x03\x02\0\x0c\n\t\n\x02\x03\0\x12\x03\x04\0*\n\t\n\x02\x03\x01\x12\x03\
-    \x05\0#\n\t\n\x02\x03\x02\x12\x03\x07\0\x1a\n\n\n\x02\x04\0\x12\x04\n\0\
-    \x0e\x01\n\n\n\x03\x04\0\x01\x12\x03\n\x08\x0e\n\x0b\n\x04\x04\0\x02\0\
-    \x12\x03\x0b\x04\x12\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x0b\x04\x08\n\
-    \x0c\n\x05\x04\0\x02\0\x01\x12\x03\x0b\t\r\n\x0c\n\x05\x04\0\x02\0\x03\
-    \x12\x03\x0b\x10\x11\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x0c\x04\x18\n\x0c\
-    \n\x05\x04\0\x02\x01\x06\x12\x03\x0c\x04\x0b\n\x0c\n\x05\x04\0\x02\x01\
-    \x01\x12\x03\x0c\x0c\x13\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x0c\x16\
-    \x17\n\x0b\n\x04\x04\0\x02\x02\x12\x03\r\x04\x18\n\x0c\n\x05\x04\0\x02\
-    \x02\x06\x12\x03\r\x04\x0b\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\r\x0c\
-    \x13\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\r\x16\x17\n\n\n\x02\x04\x01\
-    \x12\x04\x10\0\x16\x01\n\n\n\x03\x04\x01\x01\x12\x03\x10\x08\x0e\n\x0b\n\
-    \x04\x04\x01\x02\0\x12\x03\x11\x04\x14\n\x0c\n\x05\x04\x01\x02\0\x05\x12\
-    \x03\x11\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x11\x0b\x0f\n\x0c\n\
-    \x05\x04\x01\x02\0\x03\x12\x03\x11\x12\x13\n\x0b\n\x04\x04\x01\x02\x01\
-    \x12\x03\x12\x04\x17\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x12\x04\n\n\
-    \x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x12\x0b\x12\n\x0c\n\x05\x04\x01\
-    \x02\x01\x03\x12\x03\x12\x15\x16\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x13\
-    \x04\x1a\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x13\x04\n\n\x0c\n\x05\
-    \x04\x01\x02\x02\x01\x12\x03\x13\x0b\x15\n\x0c\n\x05\x04\x01\x02\x02\x03\
-    \x12\x03\x13\x18\x19\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x14\x04\x18\n\
-    \x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x01\x02\
-    \x03\x01\x12\x03\x14\x0b\x13\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x14\
-    \x16\x17\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\x15\x04!\n\x0c\n\x05\x04\
-    \x01\x02\x04\x04\x12\x03\x15\x04\x0c\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\
-    \x03\x15\r\x16\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x15\x17\x1c\n\x0c\
-    \n\x05\x04\x01\x02\x04\x03\x12\x03\x15\x1f\x20\n\n\n\x02\x04\x02\x12\x04\
-    \x18\0!\x01\n\n\n\x03\x04\x02\x01\x12\x03\x18\x08\x11\n\x0b\n\x04\x04\
-    \x02\x02\0\x12\x03\x19\x04\x19\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x19\
-    \x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x19\x0b\x14\n\x0c\n\x05\x04\
-    \x02\x02\0\x03\x12\x03\x19\x17\x18\n\x0c\n\x04\x04\x02\x08\0\x12\x04\x1a\
-    \x04\x20\x05\n\x0c\n\x05\x04\x02\x08\0\x01\x12\x03\x1a\n\x10\n\x0b\n\x04\
-    \x04\x02\x02\x01\x12\x03\x1b\x08\x1a\n\x0c\n\x05\x04\x02\x02\x01\x06\x12\
-    \x03\x1b\x08\x0e\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x1b\x0f\x15\n\
-    \x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x1b\x18\x19\n\x0b\n\x04\x04\x02\
-    \x02\x02\x12\x03\x1c\x08\x16\n\x0c\n\x05\x04\x02\x02\x02\x06\x12\x03\x1c\
-    \x08\x0c\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x1c\r\x11\n\x0c\n\x05\
-    \x04\x02\x02\x02\x03\x12\x03\x1c\x14\x15\n\x0b\n\x04\x04\x02\x02\x03\x12\
-    \x03\x1d\x08\x1c\n\x0c\n\x05\x04\x02\x02\x03\x06\x12\x03\x1d\x08\x0f\n\
-    \x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x1d\x10\x17\n\x0c\n\x05\x04\x02\
-    \x02\x03\x03\x12\x03\x1d\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x1e\
-    \x08\x1c\n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x1e\x08\x0f\n\x0c\n\x05\
-    \x04\x02\x02\x04\x01\x12\x03\x1e\x10\x17\n\x0c\n\x05\x04\x02\x02\x04\x03\
-    \x12\x03\x1e\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\x1f\x08\x18\n\
-    \x0c\n\x05\x04\x02\x02\x05\x06\x12\x03\x1f\x08\r\n\x0c\n\x05\x04\x02\x02\
-    \x05\x01\x12\x03\x1f\x0e\x13\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03\x1f\
-    \x16\x17\n\n\n\x02\x04\x03\x12\x04$\0(\x01\n\n\n\x03\x04\x03\x01\x12\x03\
-    $\x08\x12\n\x0b\n\x04\x04\x03\x02\0\x12\x03%\x04\x14\n\x0c\n\x05\x04\x03\
-    \x02\0\x05\x12\x03%\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03%\x0b\x0f\
-    \n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03%\x12\x13\n\x1a\n\x04\x04\x03\x02\
-    \x01\x12\x03'\x04!\x1a\r\x20Stream,\x20...\n\n\x0c\n\x05\x04\x03\x02\x01\
-    \x06\x12\x03'\x04\x17\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03'\x18\x1c\n\
-    \x0c\n\x05\x04\x03\x02\x01\x03\x12\x03'\x1f\x20b\x06proto3\
+    \x12$\n\x05items\x18\x05\x20\x03(\x0b2\x0e.viz.StreamSetR\x05items\x12\
+    \x1a\n\x08sequence\x18\x06\x20\x01(\rR\x08sequence\x12\"\n\rend_of_strea\
+    m\x18\x07\x20\x01(\x08R\x0bendOfStream\x12\x1b\n\tstream_id\x18\x08\x20\
+    \x01(\rR\x08streamId\"\x88\x03\n\tStreamSet\x12\x1c\n\ttimestamp\x18\x01\
+    \x20\x01(\x04R\ttimestamp\x12%\n\x06status\x18\x02\x20\x01(\x0b2\x0b.viz\
+    .StatusH\0R\x06status\x12\x1f\n\x04text\x18\x03\x20\x01(\x0b2\t.viz.Text\
+    H\0R\x04text\x12(\n\x07point2d\x18\x04\x20\x01(\x0b2\x0c.viz.Point2dH\0R\
+    \x07point2d\x12(\n\x07point3d\x18\x05\x20\x01(\x0b2\x0c.viz.Point3dH\0R\
+    \x07point3d\x12\"\n\x05image\x18\x06\x20\x01(\x0b2\n.viz.ImageH\0R\x05im\
+    age\x12>\n\x0fannotated_image\x18\x07\x20\x01(\x0b2\x13.viz.AnnotatedIma\
+    geH\0R\x0eannotatedImage\x128\n\rseries_bucket\x18\x08\x20\x01(\x0b2\x11\
+    .viz.SeriesBucketH\0R\x0cseriesBucket\x12\x19\n\x08trace_id\x18\t\x20\
+    \x01(\tR\x07traceIdB\x08\n\x06Schema\"\x95\x02\n\x05Event\x12\x1c\n\ttim\
+    estamp\x18\x01\x20\x01(\x04R\ttimestamp\x12;\n\x0erecord_changed\x18\x02\
+    \x20\x01(\x0b2\x12.viz.RecordChangedH\0R\rrecordChanged\x122\n\x0btopic_\
+    added\x18\x03\x20\x01(\x0b2\x0f.viz.TopicAddedH\0R\ntopicAdded\x12D\n\
+    \x11pubsub_connection\x18\x04\x20\x01(\x0b2\x15.viz.PubsubConnectionH\0R\
+    \x10pubsubConnection\x12/\n\ndisk_space\x18\x05\x20\x01(\x0b2\x0e.viz.Di\
+    skSpaceH\0R\tdiskSpaceB\x06\n\x04Kind\"H\n\rRecordChanged\x12\x18\n\x07e\
+    nabled\x18\x01\x20\x01(\x08R\x07enabled\x12\x1d\n\nchanged_by\x18\x02\
+    \x20\x01(\tR\tchangedBy\"\"\n\nTopicAdded\x12\x14\n\x05topic\x18\x01\x20\
+    \x01(\tR\x05topic\"H\n\x10PubsubConnection\x12\x1c\n\tconnected\x18\x01\
+    \x20\x01(\x08R\tconnected\x12\x16\n\x06reason\x18\x02\x20\x01(\tR\x06rea\
+    son\"e\n\tDiskSpace\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\x12\
+    \x1c\n\tavailable\x18\x02\x20\x01(\x04R\tavailable\x12\x14\n\x05total\
+    \x18\x03\x20\x01(\x04R\x05total\x12\x10\n\x03low\x18\x04\x20\x01(\x08R\
+    \x03low\"e\n\nWSResponse\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\
+    \x12(\n\x04data\x18\x02\x20\x01(\x0b2\x14.google.protobuf.AnyR\x04data\
+    \x12\x19\n\x08trace_id\x18\x03\x20\x01(\tR\x07traceIdJ\xa8\x1b\n\x06\x12\
+    \x04\0\0h\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\
+    \x02\0\x0c\n\t\n\x02\x03\0\x12\x03\x04\0*\n\t\n\x02\x03\x01\x12\x03\x05\
+    \0#\n\t\n\x02\x03\x02\x12\x03\x07\0\x1a\n\n\n\x02\x04\0\x12\x04\n\0\x0e\
+    \x01\n\n\n\x03\x04\0\x01\x12\x03\n\x08\x0e\n\x0b\n\x04\x04\0\x02\0\x12\
+    \x03\x0b\x04\x12\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x0b\x04\x08\n\x0c\n\
+    \x05\x04\0\x02\0\x01\x12\x03\x0b\t\r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\
+    \x0b\x10\x11\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x0c\x04\x18\n\x0c\n\x05\
+    \x04\0\x02\x01\x06\x12\x03\x0c\x04\x0b\n\x0c\n\x05\x04\0\x02\x01\x01\x12\
+    \x03\x0c\x0c\x13\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x0c\x16\x17\n\x0b\
+    \n\x04\x04\0\x02\x02\x12\x03\r\x04\x18\n\x0c\n\x05\x04\0\x02\x02\x06\x12\
+    \x03\r\x04\x0b\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\r\x0c\x13\n\x0c\n\
+    \x05\x04\0\x02\x02\x03\x12\x03\r\x16\x17\n\n\n\x02\x04\x01\x12\x04\x10\0\
+    \x13\x01\n\n\n\x03\x04\x01\x01\x12\x03\x10\x08\x16\n\x0b\n\x04\x04\x01\
+    \x02\0\x12\x03\x11\x04\x14\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x11\x04\
+    \t\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x11\n\x0f\n\x0c\n\x05\x04\x01\
+    \x02\0\x03\x12\x03\x11\x12\x13\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x12\
+    \x04%\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\x12\x04\x14\n\x0c\n\x05\
+    \x04\x01\x02\x01\x01\x12\x03\x12\x15\x20\n\x0c\n\x05\x04\x01\x02\x01\x03\
+    \x12\x03\x12#$\nw\n\x02\x04\x02\x12\x04\x17\0\x1d\x01\x1ak\x20Downsample\
+    d\x20numeric\x20series,\x20StreamSet.timestamp\x20is\x20the\x20bucket\
+    \x20start\n\x20LTTB\x20points\x20are\x20buckets\x20of\x20one\x20value\n\
+    \n\n\n\x03\x04\x02\x01\x12\x03\x17\x08\x14\n\x0b\n\x04\x04\x02\x02\0\x12\
+    \x03\x18\x04\x18\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x18\x04\n\n\x0c\n\
+    \x05\x04\x02\x02\0\x01\x12\x03\x18\x0b\x13\n\x0c\n\x05\x04\x02\x02\0\x03\
+    \x12\x03\x18\x16\x17\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x19\x04\x13\n\
+    \x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x19\x04\n\n\x0c\n\x05\x04\x02\x02\
+    \x01\x01\x12\x03\x19\x0b\x0e\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x19\
+    \x11\x12\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x1a\x04\x13\n\x0c\n\x05\x04\
+    \x02\x02\x02\x05\x12\x03\x1a\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\
+    \x03\x1a\x0b\x0e\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x1a\x11\x12\n\
+    \x0b\n\x04\x04\x02\x02\x03\x12\x03\x1b\x04\x14\n\x0c\n\x05\x04\x02\x02\
+    \x03\x05\x12\x03\x1b\x04\n\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x1b\
+    \x0b\x0f\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x1b\x12\x13\n\x0b\n\x04\
+    \x04\x02\x02\x04\x12\x03\x1c\x04\x15\n\x0c\n\x05\x04\x02\x02\x04\x05\x12\
+    \x03\x1c\x04\n\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x1c\x0b\x10\n\x0c\
+    \n\x05\x04\x02\x02\x04\x03\x12\x03\x1c\x13\x14\n\n\n\x02\x04\x03\x12\x04\
+    \x1f\0+\x01\n\n\n\x03\x04\x03\x01\x12\x03\x1f\x08\x0e\n\x0b\n\x04\x04\
+    \x03\x02\0\x12\x03\x20\x04\x14\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x20\
+    \x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x20\x0b\x0f\n\x0c\n\x05\x04\
+    \x03\x02\0\x03\x12\x03\x20\x12\x13\n\x0b\n\x04\x04\x03\x02\x01\x12\x03!\
+    \x04\x17\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03!\x04\n\n\x0c\n\x05\x04\
+    \x03\x02\x01\x01\x12\x03!\x0b\x12\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\
+    \x03!\x15\x16\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\"\x04\x1a\n\x0c\n\x05\
+    \x04\x03\x02\x02\x05\x12\x03\"\x04\n\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\
+    \x03\"\x0b\x15\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\"\x18\x19\n\x0b\n\
+    \x04\x04\x03\x02\x03\x12\x03#\x04\x18\n\x0c\n\x05\x04\x03\x02\x03\x05\
+    \x12\x03#\x04\n\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x03#\x0b\x13\n\x0c\n\
+    \x05\x04\x03\x02\x03\x03\x12\x03#\x16\x17\n\x0b\n\x04\x04\x03\x02\x04\
+    \x12\x03$\x04!\n\x0c\n\x05\x04\x03\x02\x04\x04\x12\x03$\x04\x0c\n\x0c\n\
+    \x05\x04\x03\x02\x04\x06\x12\x03$\r\x16\n\x0c\n\x05\x04\x03\x02\x04\x01\
+    \x12\x03$\x17\x1c\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x03$\x1f\x20\n7\n\
+    \x04\x04\x03\x02\x05\x12\x03&\x04\x18\x1a*\x20chunk\x20number\x20of\x20a\
+    \x20range\x20response,\x20from\x200\n\n\x0c\n\x05\x04\x03\x02\x05\x05\
+    \x12\x03&\x04\n\n\x0c\n\x05\x04\x03\x02\x05\x01\x12\x03&\x0b\x13\n\x0c\n\
+    \x05\x04\x03\x02\x05\x03\x12\x03&\x16\x17\n&\n\x04\x04\x03\x02\x06\x12\
+    \x03(\x04\x1b\x1a\x19\x20last\x20chunk\x20of\x20the\x20range\n\n\x0c\n\
+    \x05\x04\x03\x02\x06\x05\x12\x03(\x04\x08\n\x0c\n\x05\x04\x03\x02\x06\
+    \x01\x12\x03(\t\x16\n\x0c\n\x05\x04\x03\x02\x06\x03\x12\x03(\x19\x1a\n[\
+    \n\x04\x04\x03\x02\x07\x12\x03*\x04\x19\x1aN\x20response\x20of\x20this\
+    \x20connection\x20the\x20chunk\x20belongs\x20to,\x20Stream/Credit\x20ref\
+    ers\x20to\x20it\n\n\x0c\n\x05\x04\x03\x02\x07\x05\x12\x03*\x04\n\n\x0c\n\
+    \x05\x04\x03\x02\x07\x01\x12\x03*\x0b\x14\n\x0c\n\x05\x04\x03\x02\x07\
+    \x03\x12\x03*\x17\x18\n\n\n\x02\x04\x04\x12\x04-\0:\x01\n\n\n\x03\x04\
+    \x04\x01\x12\x03-\x08\x11\n\x0b\n\x04\x04\x04\x02\0\x12\x03.\x04\x19\n\
+    \x0c\n\x05\x04\x04\x02\0\x05\x12\x03.\x04\n\n\x0c\n\x05\x04\x04\x02\0\
+    \x01\x12\x03.\x0b\x14\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03.\x17\x18\n\
+    \x0c\n\x04\x04\x04\x08\0\x12\x04/\x047\x05\n\x0c\n\x05\x04\x04\x08\0\x01\
+    \x12\x03/\n\x10\n\x0b\n\x04\x04\x04\x02\x01\x12\x030\x08\x1a\n\x0c\n\x05\
+    \x04\x04\x02\x01\x06\x12\x030\x08\x0e\n\x0c\n\x05\x04\x04\x02\x01\x01\
+    \x12\x030\x0f\x15\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x030\x18\x19\n\x0b\
+    \n\x04\x04\x04\x02\x02\x12\x031\x08\x16\n\x0c\n\x05\x04\x04\x02\x02\x06\
+    \x12\x031\x08\x0c\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x031\r\x11\n\x0c\n\
+    \x05\x04\x04\x02\x02\x03\x12\x031\x14\x15\n\x0b\n\x04\x04\x04\x02\x03\
+    \x12\x032\x08\x1c\n\x0c\n\x05\x04\x04\x02\x03\x06\x12\x032\x08\x0f\n\x0c\
+    \n\x05\x04\x04\x02\x03\x01\x12\x032\x10\x17\n\x0c\n\x05\x04\x04\x02\x03\
+    \x03\x12\x032\x1a\x1b\n\x0b\n\x04\x04\x04\x02\x04\x12\x033\x08\x1c\n\x0c\
+    \n\x05\x04\x04\x02\x04\x06\x12\x033\x08\x0f\n\x0c\n\x05\x04\x04\x02\x04\
+    \x01\x12\x033\x10\x17\n\x0c\n\x05\x04\x04\x02\x04\x03\x12\x033\x1a\x1b\n\
+    \x0b\n\x04\x04\x04\x02\x05\x12\x034\x08\x18\n\x0c\n\x05\x04\x04\x02\x05\
+    \x06\x12\x034\x08\r\n\x0c\n\x05\x04\x04\x02\x05\x01\x12\x034\x0e\x13\n\
+    \x0c\n\x05\x04\x04\x02\x05\x03\x12\x034\x16\x17\n\x0b\n\x04\x04\x04\x02\
+    \x06\x12\x035\x08+\n\x0c\n\x05\x04\x04\x02\x06\x06\x12\x035\x08\x16\n\
+    \x0c\n\x05\x04\x04\x02\x06\x01\x12\x035\x17&\n\x0c\n\x05\x04\x04\x02\x06\
+    \x03\x12\x035)*\n\x0b\n\x04\x04\x04\x02\x07\x12\x036\x08'\n\x0c\n\x05\
+    \x04\x04\x02\x07\x06\x12\x036\x08\x14\n\x0c\n\x05\x04\x04\x02\x07\x01\
+    \x12\x036\x15\"\n\x0c\n\x05\x04\x04\x02\x07\x03\x12\x036%&\nX\n\x04\x04\
+    \x04\x02\x08\x12\x039\x04\x18\x1aK\x20trace_id\x20of\x20the\x20pubsub\
+    \x20message,\x20empty\x20if\x20the\x20item\x20is\x20not\x20from\x20one\
+    \x20message\n\n\x0c\n\x05\x04\x04\x02\x08\x05\x12\x039\x04\n\n\x0c\n\x05\
+    \x04\x04\x02\x08\x01\x12\x039\x0b\x13\n\x0c\n\x05\x04\x04\x02\x08\x03\
+    \x12\x039\x16\x17\nN\n\x02\x04\x05\x12\x04=\0F\x01\x1aB\x20Server-wide\
+    \x20notification,\x20sent\x20to\x20every\x20client\x20with\x20path\x20\"\
+    Event\"\n\n\n\n\x03\x04\x05\x01\x12\x03=\x08\r\n#\n\x04\x04\x05\x02\0\
+    \x12\x03?\x04\x19\x1a\x16\x20msec\x20since\x20the\x20epoch\n\n\x0c\n\x05\
+    \x04\x05\x02\0\x05\x12\x03?\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03?\
+    \x0b\x14\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03?\x17\x18\n\x0c\n\x04\x04\
+    \x05\x08\0\x12\x04@\x04E\x05\n\x0c\n\x05\x04\x05\x08\0\x01\x12\x03@\n\
+    \x0e\n\x0b\n\x04\x04\x05\x02\x01\x12\x03A\x08)\n\x0c\n\x05\x04\x05\x02\
+    \x01\x06\x12\x03A\x08\x15\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03A\x16$\
+    \n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03A'(\n\x0b\n\x04\x04\x05\x02\x02\
+    \x12\x03B\x08#\n\x0c\n\x05\x04\x05\x02\x02\x06\x12\x03B\x08\x12\n\x0c\n\
+    \x05\x04\x05\x02\x02\x01\x12\x03B\x13\x1e\n\x0c\n\x05\x04\x05\x02\x02\
+    \x03\x12\x03B!\"\n\x0b\n\x04\x04\x05\x02\x03\x12\x03C\x08/\n\x0c\n\x05\
+    \x04\x05\x02\x03\x06\x12\x03C\x08\x18\n\x0c\n\x05\x04\x05\x02\x03\x01\
+    \x12\x03C\x19*\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\x03C-.\n\x0b\n\x04\
+    \x04\x05\x02\x04\x12\x03D\x08!\n\x0c\n\x05\x04\x05\x02\x04\x06\x12\x03D\
+    \x08\x11\n\x0c\n\x05\x04\x05\x02\x04\x01\x12\x03D\x12\x1c\n\x0c\n\x05\
+    \x04\x05\x02\x04\x03\x12\x03D\x1f\x20\n\n\n\x02\x04\x06\x12\x04H\0L\x01\
+    \n\n\n\x03\x04\x06\x01\x12\x03H\x08\x15\n\x0b\n\x04\x04\x06\x02\0\x12\
+    \x03I\x04\x15\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03I\x04\x08\n\x0c\n\x05\
+    \x04\x06\x02\0\x01\x12\x03I\t\x10\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03I\
+    \x13\x14\n>\n\x04\x04\x06\x02\x01\x12\x03K\x04\x1a\x1a1\x20user,\x20sess\
+    ion\x20id\x20and\x20peer\x20address\x20of\x20the\x20change\n\n\x0c\n\x05\
+    \x04\x06\x02\x01\x05\x12\x03K\x04\n\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\
+    \x03K\x0b\x15\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03K\x18\x19\n?\n\x02\
+    \x04\x07\x12\x04O\0Q\x01\x1a3\x20First\x20message\x20of\x20a\x20topic\
+    \x20since\x20the\x20server\x20started\n\n\n\n\x03\x04\x07\x01\x12\x03O\
+    \x08\x12\n\x0b\n\x04\x04\x07\x02\0\x12\x03P\x04\x15\n\x0c\n\x05\x04\x07\
+    \x02\0\x05\x12\x03P\x04\n\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03P\x0b\x10\
+    \n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03P\x13\x14\n\n\n\x02\x04\x08\x12\
+    \x04S\0V\x01\n\n\n\x03\x04\x08\x01\x12\x03S\x08\x18\n\x0b\n\x04\x04\x08\
+    \x02\0\x12\x03T\x04\x17\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03T\x04\x08\n\
+    \x0c\n\x05\x04\x08\x02\0\x01\x12\x03T\t\x12\n\x0c\n\x05\x04\x08\x02\0\
+    \x03\x12\x03T\x15\x16\n\x0b\n\x04\x04\x08\x02\x01\x12\x03U\x04\x16\n\x0c\
+    \n\x05\x04\x08\x02\x01\x05\x12\x03U\x04\n\n\x0c\n\x05\x04\x08\x02\x01\
+    \x01\x12\x03U\x0b\x11\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x03U\x14\x15\n\
+    ?\n\x02\x04\t\x12\x04Y\0_\x01\x1a3\x20Recording\x20disk\x20is\x20nearly\
+    \x20full,\x20or\x20has\x20space\x20again\n\n\n\n\x03\x04\t\x01\x12\x03Y\
+    \x08\x11\n\x0b\n\x04\x04\t\x02\0\x12\x03Z\x04\x14\n\x0c\n\x05\x04\t\x02\
+    \0\x05\x12\x03Z\x04\n\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03Z\x0b\x0f\n\x0c\
+    \n\x05\x04\t\x02\0\x03\x12\x03Z\x12\x13\n\x14\n\x04\x04\t\x02\x01\x12\
+    \x03\\\x04\x19\x1a\x07\x20bytes\n\n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03\
+    \\\x04\n\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03\\\x0b\x14\n\x0c\n\x05\x04\
+    \t\x02\x01\x03\x12\x03\\\x17\x18\n\x0b\n\x04\x04\t\x02\x02\x12\x03]\x04\
+    \x15\n\x0c\n\x05\x04\t\x02\x02\x05\x12\x03]\x04\n\n\x0c\n\x05\x04\t\x02\
+    \x02\x01\x12\x03]\x0b\x10\n\x0c\n\x05\x04\t\x02\x02\x03\x12\x03]\x13\x14\
+    \n\x0b\n\x04\x04\t\x02\x03\x12\x03^\x04\x11\n\x0c\n\x05\x04\t\x02\x03\
+    \x05\x12\x03^\x04\x08\n\x0c\n\x05\x04\t\x02\x03\x01\x12\x03^\t\x0c\n\x0c\
+    \n\x05\x04\t\x02\x03\x03\x12\x03^\x0f\x10\n\n\n\x02\x04\n\x12\x04b\0h\
+    \x01\n\n\n\x03\x04\n\x01\x12\x03b\x08\x12\n\x0b\n\x04\x04\n\x02\0\x12\
+    \x03c\x04\x14\n\x0c\n\x05\x04\n\x02\0\x05\x12\x03c\x04\n\n\x0c\n\x05\x04\
+    \n\x02\0\x01\x12\x03c\x0b\x0f\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03c\x12\
+    \x13\n\x1a\n\x04\x04\n\x02\x01\x12\x03e\x04!\x1a\r\x20Stream,\x20...\n\n\
+    \x0c\n\x05\x04\n\x02\x01\x06\x12\x03e\x04\x17\n\x0c\n\x05\x04\n\x02\x01\
+    \x01\x12\x03e\x18\x1c\n\x0c\n\x05\x04\n\x02\x01\x03\x12\x03e\x1f\x20\nR\
+    \n\x04\x04\n\x02\x02\x12\x03g\x04\x18\x1aE\x20trace_id\x20of\x20the\x20p\
+    ubsub\x20message,\x20if\x20the\x20response\x20carries\x20one\x20message\
+    \n\n\x0c\n\x05\x04\n\x02\x02\x05\x12\x03g\x04\n\n\x0c\n\x05\x04\n\x02\
+    \x02\x01\x12\x03g\x0b\x13\n\x0c\n\x05\x04\n\x02\x02\x03\x12\x03g\x16\x17\
+    b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,351 @@
+use actix_web::{web, HttpRequest};
+use ring::hmac;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::session::now_millis;
+
+// Lifetime of issued session tokens if the config has no session_ttl
+const DEFAULT_SESSION_TTL: u64 = 12 * 60 * 60;
+
+// Ordered: an operator can do everything a viewer can
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Role {
+    // live and replay, no commands
+    Viewer,
+    // also Command/*
+    Operator,
+}
+
+impl Role {
+    pub fn parse(role: &str) -> Option<Self> {
+        match role {
+            "viewer" => Some(Role::Viewer),
+            "operator" => Some(Role::Operator),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Operator => "operator",
+        }
+    }
+
+    // Role needed for a websocket request path
+    pub fn required(path: &str) -> Self {
+        match path.split('/').next() {
+            Some("Command") => Role::Operator,
+            _ => Role::Viewer,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identity {
+    pub name: String,
+    pub role: Role,
+}
+
+impl Identity {
+    pub fn to_json(&self) -> Value {
+        json!({ "name": self.name, "role": self.role.as_str() })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuthError {
+    // no token
+    Missing,
+    // unknown, expired or badly signed token
+    Invalid,
+    // valid token, role too low
+    Forbidden,
+}
+
+impl From<AuthError> for actix_web::Error {
+    fn from(err: AuthError) -> Self {
+        match err {
+            AuthError::Missing => actix_web::error::ErrorUnauthorized("missing token"),
+            AuthError::Invalid => actix_web::error::ErrorUnauthorized("invalid token"),
+            AuthError::Forbidden => actix_web::error::ErrorForbidden("forbidden"),
+        }
+    }
+}
+
+// A way to turn a token into an identity
+pub trait Authenticator: Send + Sync {
+    fn authenticate(&self, token: &str) -> Option<Identity>;
+}
+
+impl<T: Authenticator> Authenticator for Arc<T> {
+    fn authenticate(&self, token: &str) -> Option<Identity> {
+        self.as_ref().authenticate(token)
+    }
+}
+
+// Fixed tokens of the config file
+#[derive(Default)]
+pub struct StaticTokens {
+    tokens: HashMap<String, Identity>,
+}
+
+impl StaticTokens {
+    pub fn insert(&mut self, token: String, identity: Identity) {
+        self.tokens.insert(token, identity);
+    }
+}
+
+impl Authenticator for StaticTokens {
+    fn authenticate(&self, token: &str) -> Option<Identity> {
+        // compare every token in constant time
+        self.tokens
+            .iter()
+            .fold(None, |found, (known, identity)| {
+                if constant_time_eq(known.as_bytes(), token.as_bytes()) {
+                    Some(identity.clone())
+                } else {
+                    found
+                }
+            })
+    }
+}
+
+// Signed tokens issued by POST /auth/token: base64(name \n role \n expires).base64(hmac-sha256)
+pub struct SessionTokens {
+    key: hmac::Key,
+    // seconds
+    ttl: u64,
+}
+
+impl SessionTokens {
+    pub fn new(secret: &[u8], ttl: u64) -> Self {
+        Self { key: hmac::Key::new(hmac::HMAC_SHA256, secret), ttl }
+    }
+
+    pub fn issue(&self, identity: &Identity) -> String {
+        let expires = now_millis() + self.ttl * 1000;
+        self.sign(identity, expires)
+    }
+
+    fn sign(&self, identity: &Identity, expires: u64) -> String {
+        let payload = format!("{}\n{}\n{}", identity.name, identity.role.as_str(), expires);
+        let signature = hmac::sign(&self.key, payload.as_bytes());
+        format!(
+            "{}.{}",
+            base64::encode_config(payload, base64::URL_SAFE_NO_PAD),
+            base64::encode_config(signature, base64::URL_SAFE_NO_PAD)
+        )
+    }
+}
+
+impl Authenticator for SessionTokens {
+    fn authenticate(&self, token: &str) -> Option<Identity> {
+        let mut parts = token.splitn(2, '.');
+        let payload = base64::decode_config(parts.next()?, base64::URL_SAFE_NO_PAD).ok()?;
+        let signature = base64::decode_config(parts.next()?, base64::URL_SAFE_NO_PAD).ok()?;
+        // constant time
+        hmac::verify(&self.key, &payload, &signature).ok()?;
+
+        let payload = String::from_utf8(payload).ok()?;
+        let fields = payload.split('\n').collect::<Vec<_>>();
+        match fields.as_slice() {
+            [name, role, expires] if expires.parse::<u64>().ok()? > now_millis() => Some(Identity {
+                name: name.to_string(),
+                role: Role::parse(role)?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+// Checks the tokens of websocket upgrades and HTTP requests
+pub struct Auth {
+    authenticators: Vec<Box<dyn Authenticator>>,
+    session_tokens: Option<Arc<SessionTokens>>,
+    // no config: everyone is an operator
+    enabled: bool,
+    // GET /metrics needs a viewer token, off by default like the health checks
+    metrics_auth: bool,
+}
+
+impl Auth {
+    pub fn disabled() -> Self {
+        Self {
+            authenticators: Vec::new(),
+            session_tokens: None,
+            enabled: false,
+            metrics_auth: false,
+        }
+    }
+
+    // {
+    //   "session_secret": "...", "session_ttl": 43200, "metrics_auth": false,
+    //   "tokens": [{ "name": "alice", "token": "...", "role": "operator" }]
+    // }
+    pub fn load(path: &str) -> Result<Self, String> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(_) => {
+                tracing::warn!("no {}, authentication is disabled", path);
+                return Ok(Self::disabled());
+            }
+        };
+
+        let config: Value = serde_json::from_str(&text).map_err(|err| format!("Error: {} {}", path, err))?;
+        let mut auth = Self {
+            authenticators: Vec::new(),
+            session_tokens: None,
+            enabled: true,
+            metrics_auth: config["metrics_auth"].as_bool().unwrap_or(false),
+        };
+
+        let mut tokens = StaticTokens::default();
+        for item in config["tokens"].as_array().into_iter().flatten() {
+            let field = |name: &str| {
+                item[name].as_str().ok_or_else(|| format!("Error: {} token without {}", path, name))
+            };
+            let role = Role::parse(field("role")?).ok_or_else(|| format!("Error: {} unknown role {}", path, item["role"]))?;
+            tokens.insert(field("token")?.to_string(), Identity { name: field("name")?.to_string(), role });
+        }
+        auth.register(Box::new(tokens));
+
+        if let Some(secret) = config["session_secret"].as_str() {
+            let ttl = config["session_ttl"].as_u64().unwrap_or(DEFAULT_SESSION_TTL);
+            let session_tokens = Arc::new(SessionTokens::new(secret.as_bytes(), ttl));
+            auth.session_tokens = Some(session_tokens.clone());
+            auth.register(Box::new(session_tokens));
+        }
+        Ok(auth)
+    }
+
+    pub fn register(&mut self, authenticator: Box<dyn Authenticator>) {
+        self.authenticators.push(authenticator);
+    }
+
+    pub fn authenticate(&self, token: Option<&str>, role: Role) -> Result<Identity, AuthError> {
+        if !self.enabled {
+            return Ok(Identity { name: "anonymous".into(), role: Role::Operator });
+        }
+
+        let token = token.filter(|token| !token.is_empty()).ok_or(AuthError::Missing)?;
+        let identity = self
+            .authenticators
+            .iter()
+            .find_map(|authenticator| authenticator.authenticate(token))
+            .ok_or(AuthError::Invalid)?;
+        if identity.role < role {
+            return Err(AuthError::Forbidden);
+        }
+        Ok(identity)
+    }
+
+    // "Authorization: Bearer <token>", or ?token= for websockets (browsers can't set headers)
+    pub fn authorize(&self, req: &HttpRequest, role: Role) -> Result<Identity, AuthError> {
+        let header = req
+            .headers()
+            .get("Authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(|token| token.trim().to_string());
+        let query = || query_token(req.query_string());
+        self.authenticate(header.or_else(query).as_deref(), role)
+    }
+
+    // Prometheus scrapers usually have no token
+    pub fn authorize_metrics(&self, req: &HttpRequest) -> Result<(), AuthError> {
+        if self.metrics_auth {
+            self.authorize(req, Role::Viewer)?;
+        }
+        Ok(())
+    }
+
+    // None if session tokens are not configured
+    pub fn issue(&self, identity: &Identity) -> Option<String> {
+        self.session_tokens.as_ref().map(|tokens| tokens.issue(identity))
+    }
+}
+
+// Percent-decoded token of the query string
+fn query_token(query: &str) -> Option<String> {
+    web::Query::<HashMap<String, String>>::from_query(query)
+        .ok()
+        .and_then(|mut query| query.remove("token"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity(name: &str, role: Role) -> Identity {
+        Identity { name: name.into(), role }
+    }
+
+    #[test]
+    fn static_token_test() {
+        let mut auth = Auth::disabled();
+        auth.enabled = true;
+        let mut tokens = StaticTokens::default();
+        tokens.insert("viewer-token".into(), identity("bob", Role::Viewer));
+        tokens.insert("operator-token".into(), identity("alice", Role::Operator));
+        auth.register(Box::new(tokens));
+
+        assert_eq!(auth.authenticate(Some("viewer-token"), Role::Viewer), Ok(identity("bob", Role::Viewer)));
+        assert_eq!(auth.authenticate(Some("viewer-token"), Role::Operator), Err(AuthError::Forbidden));
+        assert_eq!(auth.authenticate(Some("operator-token"), Role::Operator).unwrap().name, "alice");
+        assert_eq!(auth.authenticate(Some("other"), Role::Viewer), Err(AuthError::Invalid));
+        assert_eq!(auth.authenticate(None, Role::Viewer), Err(AuthError::Missing));
+
+        assert!(Auth::disabled().authenticate(None, Role::Operator).is_ok());
+        assert_eq!(Role::required("Command/Record"), Role::Operator);
+        assert_eq!(Role::required("Status/Status"), Role::Viewer);
+    }
+
+    #[test]
+    fn session_token_test() {
+        let tokens = SessionTokens::new(b"secret", 60);
+        let alice = identity("alice.a", Role::Operator);
+        let token = tokens.issue(&alice);
+        assert_eq!(tokens.authenticate(&token), Some(alice.clone()));
+
+        // other secret, tampered or expired
+        assert_eq!(SessionTokens::new(b"other", 60).authenticate(&token), None);
+        let forged = format!("{}.{}", base64::encode_config("mallory\noperator\n99999999999999", base64::URL_SAFE_NO_PAD), token.split('.').nth(1).unwrap());
+        assert_eq!(tokens.authenticate(&forged), None);
+        assert_eq!(tokens.authenticate(&tokens.sign(&alice, now_millis() - 1)), None);
+        assert_eq!(tokens.authenticate("garbage"), None);
+    }
+
+    #[test]
+    fn query_token_test() {
+        assert_eq!(query_token("a=1&token=a%2Bb%2Fc%3D%3D").as_deref(), Some("a+b/c=="));
+        assert_eq!(query_token("token=plain&resume=1").as_deref(), Some("plain"));
+        assert_eq!(query_token("resume=1"), None);
+
+        let mut auth = Auth::disabled();
+        auth.enabled = true;
+        let mut tokens = StaticTokens::default();
+        tokens.insert("a+b/c==".into(), identity("alice", Role::Viewer));
+        auth.register(Box::new(tokens));
+        let req = actix_web::test::TestRequest::with_uri("/ws?token=a%2Bb%2Fc%3D%3D").to_http_request();
+        assert_eq!(auth.authorize(&req, Role::Viewer).unwrap().name, "alice");
+        let req = actix_web::test::TestRequest::with_uri("/ws?token=a%2Bb").to_http_request();
+        assert_eq!(auth.authorize(&req, Role::Viewer), Err(AuthError::Invalid));
+    }
+
+    #[test]
+    fn metrics_auth_test() {
+        let mut auth = Auth::disabled();
+        auth.enabled = true;
+        let req = actix_web::test::TestRequest::with_uri("/metrics").to_http_request();
+        assert_eq!(auth.authorize_metrics(&req), Ok(()));
+        auth.metrics_auth = true;
+        assert_eq!(auth.authorize_metrics(&req), Err(AuthError::Missing));
+    }
+}
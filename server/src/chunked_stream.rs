@@ -0,0 +1,421 @@
+use protobuf::Message as _;
+use std::collections::HashMap;
+
+use crate::api::proto::response::{Stream, StreamSet};
+
+// Max encoded size of the items in one Stream chunk
+pub const MAX_FRAME_SIZE: usize = 4 * 1024 * 1024;
+// Chunks sent before the client grants more (Stream/Credit)
+pub const INITIAL_CREDITS: u32 = 4;
+
+// Range response sent as a sequence of bounded Stream chunks.
+// Items are pulled lazily when a chunk is produced, so a lazy iterator (e.g. decoding
+// images by timestamp) only holds one chunk in memory at a time.
+pub struct ChunkedStream {
+    // per connection, assigned by StreamRegistry
+    id: u32,
+    path: String,
+    subject: String,
+    start_time: u64,
+    end_time: u64,
+    items: std::iter::Peekable<Box<dyn Iterator<Item = StreamSet> + Send>>,
+    max_frame_size: usize,
+    sequence: u32,
+    credits: u32,
+    finished: bool,
+}
+
+impl ChunkedStream {
+    pub fn new<I>(path: String, subject: String, start_time: u64, end_time: u64, items: I) -> Self
+    where
+        I: Iterator<Item = StreamSet> + Send + 'static,
+    {
+        let items: Box<dyn Iterator<Item = StreamSet> + Send> = Box::new(items);
+        Self {
+            id: 0,
+            path,
+            subject,
+            start_time,
+            end_time,
+            items: items.peekable(),
+            max_frame_size: MAX_FRAME_SIZE,
+            sequence: 0,
+            credits: INITIAL_CREDITS,
+            finished: false,
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn grant(&mut self, credits: u32) {
+        self.credits = self.credits.saturating_add(credits);
+    }
+
+    // chunks in flight are lost when the connection drops and are not sent again,
+    // the client sees a gap in sequence and restarts the request
+    pub fn reset_credits(&mut self) {
+        self.credits = INITIAL_CREDITS;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn is_ready(&self) -> bool {
+        !self.finished && self.credits > 0
+    }
+
+    // Next chunk if the client has credit left.
+    // The last chunk has end_of_stream set, it may have no items.
+    pub fn next_chunk(&mut self) -> Option<Stream> {
+        if !self.is_ready() {
+            return None;
+        }
+
+        let mut items = Vec::new();
+        let mut size = 0;
+        while let Some(item) = self.items.peek() {
+            let item_size = item.compute_size() as usize;
+            if item_size > self.max_frame_size {
+                tracing::warn!(
+                    "{} item at {} is {} bytes, over the max frame size {}",
+                    self.path, item.timestamp, item_size, self.max_frame_size
+                );
+                self.items.next();
+                continue;
+            }
+            if size + item_size > self.max_frame_size {
+                break;
+            }
+            size += item_size;
+            items.extend(self.items.next());
+        }
+
+        self.finished = self.items.peek().is_none();
+        self.credits -= 1;
+
+        let mut stream = Stream::new();
+        stream.set_stream_id(self.id);
+        stream.set_path(self.path.clone());
+        stream.set_subject(self.subject.clone());
+        stream.set_start_time(self.start_time);
+        stream.set_end_time(self.end_time);
+        stream.set_items(items.into());
+        stream.set_sequence(self.sequence);
+        stream.set_end_of_stream(self.finished);
+        self.sequence += 1;
+        Some(stream)
+    }
+}
+
+// Live frames of a subscription as an open-ended stream, one chunk per frame.
+// Frames without credit are dropped, the client has not handled the previous ones yet.
+pub struct LiveStream {
+    id: u32,
+    sequence: u32,
+    credits: u32,
+}
+
+impl LiveStream {
+    fn chunk(&mut self, path: &str, subject: &str, item: StreamSet) -> Option<Stream> {
+        if self.credits == 0 {
+            return None;
+        }
+        self.credits -= 1;
+
+        let mut stream = Stream::new();
+        stream.set_stream_id(self.id);
+        stream.set_path(path.into());
+        stream.set_subject(subject.into());
+        stream.set_start_time(item.timestamp);
+        stream.set_end_time(item.timestamp);
+        stream.set_items(vec![item].into());
+        stream.set_sequence(self.sequence);
+        self.sequence += 1;
+        Some(stream)
+    }
+}
+
+enum Slot {
+    // waiting for credits
+    Idle(Box<ChunkedStream>),
+    // producing a chunk elsewhere (worker pool), credits granted meanwhile
+    Busy(u32),
+    Live(LiveStream),
+}
+
+// Streams of one connection by id, Stream/Credit names the id of the chunks
+#[derive(Default)]
+pub struct StreamRegistry {
+    last_id: u32,
+    slots: HashMap<u32, Slot>,
+    // live stream id by key, e.g. path and topic of a subscription
+    live: HashMap<String, u32>,
+}
+
+impl StreamRegistry {
+    fn next_id(&mut self) -> u32 {
+        self.last_id = self.last_id.wrapping_add(1).max(1);
+        self.last_id
+    }
+
+    // New streams get the next id, resumed ones keep theirs
+    pub fn insert(&mut self, mut stream: ChunkedStream) -> u32 {
+        if stream.id == 0 {
+            stream.id = self.next_id();
+        } else {
+            self.last_id = self.last_id.max(stream.id);
+        }
+        let id = stream.id;
+        self.slots.insert(id, Slot::Idle(Box::new(stream)));
+        id
+    }
+
+    // false if there is no such stream, e.g. it has finished
+    pub fn grant(&mut self, id: u32, credits: u32) -> bool {
+        match self.slots.get_mut(&id) {
+            Some(Slot::Idle(stream)) => stream.grant(credits),
+            Some(Slot::Busy(granted)) => *granted = granted.saturating_add(credits),
+            Some(Slot::Live(live)) => live.credits = live.credits.saturating_add(credits),
+            None => return false,
+        }
+        true
+    }
+
+    // Up to `max` streams which can send a chunk now, busy until put back
+    pub fn take_ready(&mut self, max: usize) -> Vec<ChunkedStream> {
+        let mut ids = self
+            .slots
+            .iter()
+            .filter(|(_, slot)| matches!(slot, Slot::Idle(stream) if stream.is_ready()))
+            .map(|(&id, _)| id)
+            .collect::<Vec<_>>();
+        // oldest first
+        ids.sort_unstable();
+        ids.truncate(max);
+
+        ids.into_iter()
+            .filter_map(|id| match self.slots.insert(id, Slot::Busy(0)) {
+                Some(Slot::Idle(stream)) => Some(*stream),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // After a chunk of a taken stream: finished streams and streams drained meanwhile are dropped
+    pub fn put_back(&mut self, mut stream: ChunkedStream) {
+        let granted = match self.slots.remove(&stream.id) {
+            Some(Slot::Busy(granted)) => granted,
+            _ => return,
+        };
+        if !stream.is_finished() {
+            stream.grant(granted);
+            self.slots.insert(stream.id, Slot::Idle(Box::new(stream)));
+        }
+    }
+
+    // Chunk of a live frame, None when the client has no credit left for the stream
+    pub fn live_chunk(&mut self, key: &str, path: &str, subject: &str, item: StreamSet) -> Option<Stream> {
+        let id = match self.live.get(key) {
+            Some(&id) => id,
+            None => {
+                let id = self.next_id();
+                self.live.insert(key.into(), id);
+                self.slots.insert(id, Slot::Live(LiveStream { id, sequence: 0, credits: INITIAL_CREDITS }));
+                id
+            }
+        };
+        match self.slots.get_mut(&id) {
+            Some(Slot::Live(live)) => live.chunk(path, subject, item),
+            _ => None,
+        }
+    }
+
+    // stream whose chunk could not be produced
+    pub fn remove(&mut self, id: u32) {
+        self.slots.remove(&id);
+    }
+
+    pub fn end_live(&mut self, key: &str) {
+        if let Some(id) = self.live.remove(key) {
+            self.slots.remove(&id);
+        }
+    }
+
+    // Unfinished range responses, for parking the session.
+    // A stream producing a chunk right now is lost with the connection.
+    pub fn drain(&mut self) -> Vec<ChunkedStream> {
+        self.live.clear();
+        let mut streams = self
+            .slots
+            .drain()
+            .filter_map(|(_, slot)| match slot {
+                Slot::Idle(stream) => Some(*stream),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        streams.sort_unstable_by_key(|stream| stream.id);
+        streams
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::proto::primitives::Text;
+
+    fn item(timestamp: u64, len: usize) -> StreamSet {
+        let mut text = Text::new();
+        text.set_text("a".repeat(len));
+        let mut item = StreamSet::new();
+        item.set_timestamp(timestamp);
+        item.set_text(text);
+        item
+    }
+
+    #[test]
+    fn chunk_test() {
+        let items = (1..=10).map(|t| item(t, 100)).collect::<Vec<_>>();
+        let item_size = items[0].compute_size() as usize;
+        let mut stream = ChunkedStream::new("Status/Status".into(), "/status".into(), 1, 10, items.into_iter());
+        stream.max_frame_size = item_size * 2;
+
+        // 5 chunks are needed, the client granted 4
+        let chunks = std::iter::from_fn(|| stream.next_chunk()).collect::<Vec<_>>();
+        assert_eq!(chunks.len(), INITIAL_CREDITS as usize);
+        assert_eq!(chunks.iter().map(|chunk| chunk.items.len()).collect::<Vec<_>>(), [2, 2, 2, 2]);
+        assert_eq!(chunks.iter().map(|chunk| chunk.sequence).collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert!(chunks.iter().all(|chunk| !chunk.end_of_stream));
+
+        // stalled until the next credit
+        assert!(stream.next_chunk().is_none());
+        assert!(stream.next_chunk().is_none());
+        assert!(!stream.is_finished());
+
+        stream.grant(1);
+        let chunk = stream.next_chunk().unwrap();
+        assert_eq!(chunk.sequence, 4);
+        assert_eq!(chunk.items.iter().map(|item| item.timestamp).collect::<Vec<_>>(), [9, 10]);
+        assert!(chunk.end_of_stream);
+        assert!(stream.is_finished());
+        assert!(stream.next_chunk().is_none());
+    }
+
+    #[test]
+    fn credit_test() {
+        let items = (1..=10).map(|t| item(t, 100)).collect::<Vec<_>>();
+        let item_size = items[0].compute_size() as usize;
+        let mut stream = ChunkedStream::new("Status/Status".into(), "/status".into(), 1, 10, items.into_iter());
+        stream.max_frame_size = item_size;
+
+        assert_eq!(std::iter::from_fn(|| stream.next_chunk()).count(), INITIAL_CREDITS as usize);
+        stream.grant(2);
+        assert_eq!(std::iter::from_fn(|| stream.next_chunk()).count(), 2);
+        assert!(!stream.is_finished());
+    }
+
+    #[test]
+    fn oversize_and_empty_test() {
+        let items = vec![item(0, 10), item(1, 1000), item(2, 10)];
+        let mut stream = ChunkedStream::new("Status/Status".into(), "/status".into(), 0, 2, items.into_iter());
+        stream.max_frame_size = 100;
+        let chunk = stream.next_chunk().unwrap();
+        assert_eq!(chunk.items.iter().map(|item| item.timestamp).collect::<Vec<_>>(), [0, 2]);
+        assert!(chunk.end_of_stream);
+
+        // empty range still ends the stream
+        let mut stream = ChunkedStream::new("Status/Status".into(), "/status".into(), 0, 0, std::iter::empty());
+        let chunk = stream.next_chunk().unwrap();
+        assert!(chunk.items.is_empty() && chunk.end_of_stream);
+    }
+
+    fn stream(path: &str, count: u64) -> ChunkedStream {
+        let mut stream = ChunkedStream::new(path.into(), "/subject".into(), 0, count, (0..count).map(|t| item(t, 100)));
+        // one item per chunk
+        stream.max_frame_size = 200;
+        stream
+    }
+
+    // produce one chunk of every ready stream, like the gateway does on the worker pool
+    fn produce(registry: &mut StreamRegistry) -> Vec<Stream> {
+        let mut chunks = Vec::new();
+        for mut stream in registry.take_ready(usize::MAX) {
+            chunks.extend(stream.next_chunk());
+            registry.put_back(stream);
+        }
+        chunks.sort_unstable_by_key(|chunk| chunk.stream_id);
+        chunks
+    }
+
+    #[test]
+    fn interleaved_streams_test() {
+        let mut registry = StreamRegistry::default();
+        // two requests of the same path for different subjects
+        let a = registry.insert(stream("Image/Image", 6));
+        let b = registry.insert(stream("Image/Image", 5));
+        assert_ne!(a, b);
+
+        let mut sent = HashMap::<u32, Vec<u32>>::new();
+        for _ in 0..INITIAL_CREDITS {
+            for chunk in produce(&mut registry) {
+                sent.entry(chunk.stream_id).or_default().push(chunk.sequence);
+            }
+        }
+        assert_eq!(sent[&a], [0, 1, 2, 3]);
+        assert_eq!(sent[&b], [0, 1, 2, 3]);
+        assert!(produce(&mut registry).is_empty());
+
+        // a credit is for one stream only
+        assert!(registry.grant(b, 1));
+        let chunks = produce(&mut registry);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!((chunks[0].stream_id, chunks[0].sequence, chunks[0].end_of_stream), (b, 4, true));
+        assert!(!registry.grant(b, 1));
+
+        // credits granted while a chunk is produced are kept
+        assert!(registry.grant(a, 1));
+        let mut taken = registry.take_ready(usize::MAX);
+        assert_eq!(taken.len(), 1);
+        assert!(registry.grant(a, 1));
+        let chunk = taken[0].next_chunk().unwrap();
+        assert_eq!((chunk.stream_id, chunk.sequence), (a, 4));
+        registry.put_back(taken.remove(0));
+        let chunks = produce(&mut registry);
+        assert_eq!((chunks[0].stream_id, chunks[0].sequence, chunks[0].end_of_stream), (a, 5, true));
+
+        assert!(registry.take_ready(usize::MAX).is_empty());
+
+        // parked streams keep their id when the session resumes
+        let c = registry.insert(stream("Image/Image", 1));
+        let parked = registry.drain();
+        assert_eq!(parked.iter().map(|stream| stream.id()).collect::<Vec<_>>(), [c]);
+        let mut registry = StreamRegistry::default();
+        assert_eq!(registry.insert(parked.into_iter().next().unwrap()), c);
+        assert!(registry.insert(stream("Image/Image", 1)) > c);
+    }
+
+    #[test]
+    fn live_stream_test() {
+        let mut registry = StreamRegistry::default();
+        let chunks = (0..6)
+            .filter_map(|t| registry.live_chunk("Image/Live /camera", "Image/Live", "/camera", item(t, 10)))
+            .collect::<Vec<_>>();
+        // frames after the initial credits are dropped
+        assert_eq!(chunks.iter().map(|chunk| chunk.items[0].timestamp).collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert!(chunks.iter().all(|chunk| !chunk.end_of_stream));
+
+        let id = chunks[0].stream_id;
+        assert!(registry.grant(id, 1));
+        let chunk = registry.live_chunk("Image/Live /camera", "Image/Live", "/camera", item(6, 10)).unwrap();
+        assert_eq!((chunk.stream_id, chunk.sequence), (id, 4));
+
+        registry.end_live("Image/Live /camera");
+        assert!(!registry.grant(id, 1));
+    }
+}
@@ -0,0 +1,65 @@
+use std::ffi::CString;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::event_bus::{self, EventBus};
+use crate::metrics::metrics;
+
+// Check the recording disk this often
+pub const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+// Nearly full below this share of free space
+const LOW_RATIO: f64 = 0.05;
+
+// (available, total) bytes of the file system of path
+pub fn free_space(path: &str) -> std::io::Result<(u64, u64)> {
+    let c_path = CString::new(path).map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let block_size = stat.f_frsize as u64;
+    Ok((stat.f_bavail as u64 * block_size, stat.f_blocks as u64 * block_size))
+}
+
+fn is_low(available: u64, total: u64) -> bool {
+    total > 0 && (available as f64) < (total as f64) * LOW_RATIO
+}
+
+// Publish DiskSpace when the disk gets nearly full and when it has space again
+pub async fn watch(events: Arc<EventBus>, path: String) {
+    let mut low = false;
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        let (available, total) = match free_space(&path) {
+            Ok(space) => space,
+            Err(err) => {
+                tracing::warn!("disk space of {}: {}", path, err);
+                continue;
+            }
+        };
+        metrics().set_disk_space(available, total);
+
+        if is_low(available, total) != low {
+            low = !low;
+            tracing::info!("Disk space of {}: {} of {} bytes available", path, available, total);
+            events.publish(event_bus::disk_space(&path, available, total, low));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn free_space_test() {
+        let (available, total) = free_space("/").unwrap();
+        assert!(total > 0 && available <= total);
+        assert!(free_space("/no/such/directory").is_err());
+
+        assert!(is_low(4, 100));
+        assert!(!is_low(5, 100));
+        assert!(!is_low(0, 0));
+    }
+}
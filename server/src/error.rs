@@ -0,0 +1,112 @@
+use serde_json::{json, Value};
+
+// Errors of request handling, reported to the client instead of panicking
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    // missing or out of range request fields
+    InvalidRequest(String),
+    // unknown topic, no message at the timestamp
+    NotFound(String),
+    // role too low for the request
+    Forbidden(String),
+    // malformed message data
+    Decode(String),
+    // poisoned lock, encoding failure
+    Internal(String),
+}
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::InvalidRequest(_) => "invalid_request",
+            Error::NotFound(_) => "not_found",
+            Error::Forbidden(_) => "forbidden",
+            Error::Decode(_) => "decode",
+            Error::Internal(_) => "internal",
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Error::InvalidRequest(message)
+            | Error::NotFound(message)
+            | Error::Forbidden(message)
+            | Error::Decode(message)
+            | Error::Internal(message) => message,
+        }
+    }
+
+    // request path which no responder handles
+    pub fn unknown_path(path: &str) -> Self {
+        Error::NotFound(format!("Error: unknown path {}", path))
+    }
+
+    // WebSocket error response of the request path
+    pub fn to_json(&self, path: &str) -> Value {
+        json!({ "path": path, "error": self.message(), "code": self.code() })
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl<T> From<std::sync::PoisonError<T>> for Error {
+    fn from(_: std::sync::PoisonError<T>) -> Self {
+        Error::Internal("Error: lock poisoned by a panicked thread".into())
+    }
+}
+
+impl From<protobuf::ProtobufError> for Error {
+    fn from(err: protobuf::ProtobufError) -> Self {
+        Error::Decode(format!("Error: {}", err))
+    }
+}
+
+impl From<Error> for actix_web::Error {
+    fn from(err: Error) -> Self {
+        let message = err.message().to_string();
+        match err {
+            Error::InvalidRequest(_) | Error::Decode(_) => actix_web::error::ErrorBadRequest(message),
+            Error::NotFound(_) => actix_web::error::ErrorNotFound(message),
+            Error::Forbidden(_) => actix_web::error::ErrorForbidden(message),
+            Error::Internal(_) => actix_web::error::ErrorInternalServerError(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::Message as _;
+
+    #[test]
+    fn to_json_test() {
+        let err = Error::NotFound("Error: Not Found /camera".into());
+        assert_eq!(
+            err.to_json("Image/Image"),
+            json!({ "path": "Image/Image", "error": "Error: Not Found /camera", "code": "not_found" })
+        );
+
+        let lock = std::sync::Arc::new(std::sync::RwLock::new(0));
+        let poisoned = lock.clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoned.write().unwrap();
+            panic!("poison the lock");
+        })
+        .join();
+        let err: Error = lock.read().map(|_| ()).unwrap_err().into();
+        assert_eq!(err.code(), "internal");
+    }
+
+    #[test]
+    fn malformed_proto_test() {
+        // truncated length delimited field
+        let err: Error = crate::api::proto::primitives::Image::parse_from_bytes(&[0x0a, 0x05, 0x01]).unwrap_err().into();
+        assert_eq!(err.code(), "decode");
+    }
+}
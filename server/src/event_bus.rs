@@ -0,0 +1,96 @@
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+use crate::api::proto::response::{DiskSpace, Event, PubsubConnection, RecordChanged, TopicAdded};
+use crate::record_control::RecordState;
+use crate::session::now_millis;
+
+// Events kept for slow gateways, older ones are dropped
+const EVENT_BUFFER: usize = 64;
+
+// Server-wide notifications, every websocket gateway subscribes when started
+pub struct EventBus {
+    sender: broadcast::Sender<Arc<Event>>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_BUFFER);
+        Self { sender }
+    }
+}
+
+impl EventBus {
+    pub fn publish(&self, mut event: Event) {
+        if event.timestamp == 0 {
+            event.set_timestamp(now_millis());
+        }
+        // no client is not an error
+        let _ = self.sender.send(Arc::new(event));
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<Event>> {
+        self.sender.subscribe()
+    }
+}
+
+pub fn record_changed(state: &RecordState) -> Event {
+    let mut record_changed = RecordChanged::new();
+    record_changed.set_enabled(state.enabled);
+    record_changed.set_changed_by(state.changed_by.clone());
+    let mut event = Event::new();
+    event.set_timestamp(state.changed_at);
+    event.set_record_changed(record_changed);
+    event
+}
+
+pub fn topic_added(topic: &str) -> Event {
+    let mut topic_added = TopicAdded::new();
+    topic_added.set_topic(topic.to_string());
+    let mut event = Event::new();
+    event.set_topic_added(topic_added);
+    event
+}
+
+pub fn pubsub_connection(connected: bool, reason: &str) -> Event {
+    let mut connection = PubsubConnection::new();
+    connection.set_connected(connected);
+    connection.set_reason(reason.to_string());
+    let mut event = Event::new();
+    event.set_pubsub_connection(connection);
+    event
+}
+
+pub fn disk_space(path: &str, available: u64, total: u64, low: bool) -> Event {
+    let mut disk_space = DiskSpace::new();
+    disk_space.set_path(path.to_string());
+    disk_space.set_available(available);
+    disk_space.set_total(total);
+    disk_space.set_low(low);
+    let mut event = Event::new();
+    event.set_disk_space(disk_space);
+    event
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn publish_test() {
+        let events = EventBus::default();
+        // published before anyone listens: lost
+        events.publish(topic_added("/before"));
+
+        let mut first = events.subscribe();
+        let mut second = events.subscribe();
+        events.publish(topic_added("/status"));
+
+        for receiver in [&mut first, &mut second].iter_mut() {
+            let event = receiver.try_recv().unwrap();
+            assert_eq!(event.get_topic_added().get_topic(), "/status");
+            assert!(event.timestamp > 0);
+            assert!(receiver.try_recv().is_err());
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use serde_json::json;
+
+// One readiness check of GET /readyz
+#[derive(Debug)]
+pub struct Check {
+    pub name: &'static str,
+    pub result: Result<(), String>,
+}
+
+impl Check {
+    pub fn new(name: &'static str, result: Result<(), String>) -> Self {
+        Self { name, result }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Readiness {
+    pub checks: Vec<Check>,
+}
+
+impl Readiness {
+    pub fn is_ready(&self) -> bool {
+        self.checks.iter().all(|check| check.result.is_ok())
+    }
+
+    // { "ready": bool, "checks": { <name>: { "ok": bool, "error"?: string } } }
+    pub fn to_json(&self) -> serde_json::Value {
+        let checks = self
+            .checks
+            .iter()
+            .map(|check| {
+                let value = match &check.result {
+                    Ok(()) => json!({ "ok": true }),
+                    Err(err) => json!({ "ok": false, "error": err }),
+                };
+                (check.name.to_string(), value)
+            })
+            .collect::<serde_json::Map<_, _>>();
+        json!({ "ready": self.is_ready(), "checks": checks })
+    }
+}
+
+// Creates and removes a probe file, a unique name for concurrent probes
+pub fn check_writable(dir: &std::path::Path) -> Result<(), String> {
+    let probe = dir.join(format!(".readyz-{}", uuid::Uuid::new_v4().to_simple()));
+    std::fs::write(&probe, b"")
+        .and_then(|_| std::fs::remove_file(&probe))
+        .map_err(|err| format!("Error: {} is not writable {}", dir.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readiness_test() {
+        let mut readiness = Readiness::default();
+        readiness.checks.push(Check::new("pubsub", Ok(())));
+        assert!(readiness.is_ready());
+        assert_eq!(readiness.to_json(), json!({ "ready": true, "checks": { "pubsub": { "ok": true } } }));
+
+        readiness.checks.push(Check::new("subscriptions", Err("not subscribed: a".into())));
+        assert!(!readiness.is_ready());
+        assert_eq!(readiness.to_json()["checks"]["subscriptions"], json!({ "ok": false, "error": "not subscribed: a" }));
+    }
+
+    #[test]
+    fn writable_test() {
+        let dir = std::env::temp_dir();
+        assert!(check_writable(&dir).is_ok());
+        assert_eq!(std::fs::read_dir(&dir).unwrap().filter_map(Result::ok)
+            .filter(|entry| entry.file_name().to_string_lossy().starts_with(".readyz-")).count(), 0);
+        assert!(check_writable(std::path::Path::new("/no/such/directory")).is_err());
+    }
+}
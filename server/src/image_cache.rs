@@ -0,0 +1,96 @@
+use protobuf::Message;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+use crate::api::proto::primitives::{Image, ImageAnnotations};
+use crate::metrics::metrics;
+
+// Images of a few seconds of replay at 30 fps
+pub const IMAGE_CACHE_SIZE: usize = 256;
+
+// Key of a rendered image. A frame republished with the same timestamp (POST /topics/.../publish)
+// replaces the recorded one, so the key hashes the message data and the burnt-in annotations.
+pub fn key(topic: &str, timestamp: u64, data: &[u8], scale_x: f64, scale_y: f64, overlay: Option<&ImageAnnotations>) -> String {
+    let mut hasher = DefaultHasher::new();
+    data.hash(&mut hasher);
+    if let Some(overlay) = overlay {
+        overlay.write_to_bytes().unwrap_or_default().hash(&mut hasher);
+    }
+    format!("{} {} {} {} {:x}", topic, timestamp, scale_x, scale_y, hasher.finish())
+}
+
+// Rendered replay images.
+// Scrubbing back and forth and several clients on the same range hit the same frames.
+pub struct ImageCache {
+    capacity: usize,
+    // oldest first
+    entries: Mutex<(HashMap<String, Image>, VecDeque<String>)>,
+}
+
+impl ImageCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new((HashMap::new(), VecDeque::new())),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<Image> {
+        let image = self.entries.lock().ok()?.0.get(key).cloned();
+        metrics().image_cache_lookup(image.is_some());
+        image
+    }
+
+    pub fn insert(&self, key: String, image: Image) {
+        let mut entries = match self.entries.lock() {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+        let (images, order) = &mut *entries;
+        if images.insert(key.clone(), image).is_none() {
+            order.push_back(key);
+        }
+        while order.len() > self.capacity {
+            if let Some(oldest) = order.pop_front() {
+                images.remove(&oldest);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(data: &[u8]) -> Image {
+        let mut image = Image::new();
+        image.set_data(data.to_vec());
+        image
+    }
+
+    #[test]
+    fn evict_test() {
+        let cache = ImageCache::new(2);
+        cache.insert("a".into(), image(b"a"));
+        cache.insert("b".into(), image(b"b"));
+        cache.insert("a".into(), image(b"a2"));
+        assert_eq!(cache.get("a").unwrap().get_data(), b"a2");
+
+        cache.insert("c".into(), image(b"c"));
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_some());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn key_test() {
+        let frame = key("/camera", 100, b"frame", 0.5, 0.5, None);
+        assert_eq!(frame, key("/camera", 100, b"frame", 0.5, 0.5, None));
+        // republished frame, other scale, burnt-in annotations
+        assert_ne!(frame, key("/camera", 100, b"frame2", 0.5, 0.5, None));
+        assert_ne!(frame, key("/camera", 100, b"frame", 1.0, 0.5, None));
+        assert_ne!(frame, key("/camera", 100, b"frame", 0.5, 0.5, Some(&ImageAnnotations::new())));
+    }
+}
@@ -0,0 +1,435 @@
+use image::{DynamicImage, RgbImage};
+
+use crate::api::proto::primitives::{Color, ImageAnnotations, Point2d};
+
+const DEFAULT_COLOR: [u8; 4] = [0, 255, 0, 255];
+const LINE_WIDTH: i32 = 2;
+const KEYPOINT_RADIUS: i32 = 3;
+// glyph pixel size
+const FONT_SCALE: i32 = 2;
+// coordinates are clamped to this, far outside of any frame, so offsets can't overflow
+const MAX_COORDINATE: f32 = 1e7;
+
+// Scale annotations to fit to a resized image
+pub fn scale(annotations: &ImageAnnotations, scale_x: f64, scale_y: f64) -> ImageAnnotations {
+    let scale_point = |point: &Point2d| {
+        let mut scaled = point.clone();
+        scaled.set_x((point.x as f64 * scale_x) as f32);
+        scaled.set_y((point.y as f64 * scale_y) as f32);
+        scaled
+    };
+
+    let mut res = annotations.clone();
+    for bbox in res.mut_boxes().iter_mut() {
+        bbox.set_x((bbox.x as f64 * scale_x) as f32);
+        bbox.set_y((bbox.y as f64 * scale_y) as f32);
+        bbox.set_width((bbox.width as f64 * scale_x) as f32);
+        bbox.set_height((bbox.height as f64 * scale_y) as f32);
+    }
+    for polygon in res.mut_polygons().iter_mut() {
+        let points = polygon.get_points().iter().map(scale_point).collect::<Vec<_>>();
+        polygon.set_points(points.into());
+    }
+    for polyline in res.mut_polylines().iter_mut() {
+        let points = polyline.get_points().iter().map(scale_point).collect::<Vec<_>>();
+        polyline.set_points(points.into());
+    }
+    for keypoints in res.mut_keypoints().iter_mut() {
+        let points = keypoints.get_points().iter().map(scale_point).collect::<Vec<_>>();
+        keypoints.set_points(points.into());
+    }
+    for label in res.mut_labels().iter_mut() {
+        let position = scale_point(label.get_position());
+        label.set_position(position);
+    }
+    res
+}
+
+// Burn annotations into the image, for clients which can't draw them
+pub fn draw(image: DynamicImage, annotations: &ImageAnnotations) -> DynamicImage {
+    let mut canvas = Canvas {
+        image: image.to_rgb8(),
+    };
+
+    for bbox in annotations.get_boxes() {
+        let color = rgba(bbox.color.as_ref());
+        let corners = (pixel(bbox.x, bbox.y), pixel(bbox.x + bbox.width, bbox.y + bbox.height));
+        let ((x0, y0), (x1, y1)) = match corners {
+            (Some(p0), Some(p1)) => (p0, p1),
+            _ => continue,
+        };
+        canvas.polyline(&[(x0, y0), (x1, y0), (x1, y1), (x0, y1)], true, color);
+        canvas.label(x0, y0 - 7 * FONT_SCALE - 2, &bbox.label, color);
+    }
+    for polygon in annotations.get_polygons() {
+        let color = rgba(polygon.color.as_ref());
+        let points = to_pixels(polygon.get_points());
+        canvas.polyline(&points, true, color);
+        if let Some(&(x, y)) = points.first() {
+            canvas.label(x, y - 7 * FONT_SCALE - 2, &polygon.label, color);
+        }
+    }
+    for polyline in annotations.get_polylines() {
+        let color = rgba(polyline.color.as_ref());
+        let points = to_pixels(polyline.get_points());
+        canvas.polyline(&points, false, color);
+        if let Some(&(x, y)) = points.first() {
+            canvas.label(x, y - 7 * FONT_SCALE - 2, &polyline.label, color);
+        }
+    }
+    for keypoints in annotations.get_keypoints() {
+        let color = rgba(keypoints.color.as_ref());
+        let points = to_pixels(keypoints.get_points());
+        for &(x, y) in &points {
+            canvas.fill_rect(
+                x - KEYPOINT_RADIUS,
+                y - KEYPOINT_RADIUS,
+                x + KEYPOINT_RADIUS,
+                y + KEYPOINT_RADIUS,
+                color,
+            );
+        }
+        if let Some(&(x, y)) = points.first() {
+            canvas.label(x, y - 7 * FONT_SCALE - 2 - KEYPOINT_RADIUS, &keypoints.label, color);
+        }
+    }
+    for label in annotations.get_labels() {
+        let color = rgba(label.color.as_ref());
+        let position = label.get_position();
+        if let Some((x, y)) = pixel(position.x, position.y) {
+            canvas.label(x, y, &label.text, color);
+        }
+    }
+
+    DynamicImage::ImageRgb8(canvas.image)
+}
+
+// NaN and infinite points are skipped
+fn to_pixels(points: &[Point2d]) -> Vec<(i32, i32)> {
+    points.iter().filter_map(|p| pixel(p.x, p.y)).collect()
+}
+
+fn pixel(x: f32, y: f32) -> Option<(i32, i32)> {
+    if !x.is_finite() || !y.is_finite() {
+        return None;
+    }
+    Some((
+        x.clamp(-MAX_COORDINATE, MAX_COORDINATE) as i32,
+        y.clamp(-MAX_COORDINATE, MAX_COORDINATE) as i32,
+    ))
+}
+
+fn rgba(color: Option<&Color>) -> [u8; 4] {
+    match color {
+        // alpha 0 means unset in proto3, treat it as opaque
+        Some(color) => [
+            color.r.min(255) as u8,
+            color.g.min(255) as u8,
+            color.b.min(255) as u8,
+            if color.a == 0 { 255 } else { color.a.min(255) as u8 },
+        ],
+        None => DEFAULT_COLOR,
+    }
+}
+
+struct Canvas {
+    image: RgbImage,
+}
+
+impl Canvas {
+    fn blend(&mut self, x: i32, y: i32, color: [u8; 4]) {
+        if x < 0 || y < 0 || x >= self.image.width() as i32 || y >= self.image.height() as i32 {
+            return;
+        }
+        let pixel = self.image.get_pixel_mut(x as u32, y as u32);
+        let alpha = color[3] as u32;
+        for i in 0..3 {
+            pixel[i] = ((color[i] as u32 * alpha + pixel[i] as u32 * (255 - alpha)) / 255) as u8;
+        }
+    }
+
+    // clipped to the image
+    fn fill_rect(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, color: [u8; 4]) {
+        let (x0, y0) = (x0.max(0), y0.max(0));
+        let x1 = x1.min(self.image.width() as i32 - 1);
+        let y1 = y1.min(self.image.height() as i32 - 1);
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                self.blend(x, y, color);
+            }
+        }
+    }
+
+    // Bresenham, stamped with LINE_WIDTH, of the segment part inside the image
+    fn line(&mut self, from: (i32, i32), to: (i32, i32), color: [u8; 4]) {
+        let half = LINE_WIDTH / 2;
+        let bounds = (
+            -half,
+            -half,
+            self.image.width() as i32 + half,
+            self.image.height() as i32 + half,
+        );
+        let ((mut x0, mut y0), (x1, y1)) = match clip(from, to, bounds) {
+            Some(segment) => segment,
+            None => return,
+        };
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        loop {
+            self.fill_rect(x0 - half, y0 - half, x0 - half + LINE_WIDTH - 1, y0 - half + LINE_WIDTH - 1, color);
+            if x0 == x1 && y0 == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x0 += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y0 += sy;
+            }
+        }
+    }
+
+    fn polyline(&mut self, points: &[(i32, i32)], closed: bool, color: [u8; 4]) {
+        for pair in points.windows(2) {
+            self.line(pair[0], pair[1], color);
+        }
+        if closed && points.len() > 2 {
+            self.line(points[points.len() - 1], points[0], color);
+        }
+    }
+
+    // text on a filled background, top-left at (x, y)
+    fn label(&mut self, x: i32, y: i32, text: &str, color: [u8; 4]) {
+        if text.is_empty() {
+            return;
+        }
+        let y = y.max(0);
+        let advance = 6 * FONT_SCALE;
+        let width = advance.saturating_mul(text.chars().count() as i32);
+        self.fill_rect(x, y, x + width, y + 8 * FONT_SCALE, color);
+
+        // black or white text, whichever is readable on the background
+        let luma = (color[0] as u32 * 299 + color[1] as u32 * 587 + color[2] as u32 * 114) / 1000;
+        let ink = if luma > 128 { [0, 0, 0, 255] } else { [255, 255, 255, 255] };
+
+        for (i, c) in text.chars().enumerate() {
+            let origin_x = x + 1 + advance * i as i32;
+            if origin_x >= self.image.width() as i32 {
+                break;
+            }
+            let origin_y = y + FONT_SCALE / 2;
+            for (col, bits) in glyph(c).iter().enumerate() {
+                for row in 0..7 {
+                    if bits & (1 << row) == 0 {
+                        continue;
+                    }
+                    let px = origin_x + col as i32 * FONT_SCALE;
+                    let py = origin_y + row * FONT_SCALE;
+                    self.fill_rect(px, py, px + FONT_SCALE - 1, py + FONT_SCALE - 1, ink);
+                }
+            }
+        }
+    }
+}
+
+// Cohen-Sutherland: part of the segment inside (x_min, y_min, x_max, y_max), None if outside
+fn clip(from: (i32, i32), to: (i32, i32), bounds: (i32, i32, i32, i32)) -> Option<((i32, i32), (i32, i32))> {
+    const LEFT: u8 = 1;
+    const RIGHT: u8 = 2;
+    const TOP: u8 = 4;
+    const BOTTOM: u8 = 8;
+
+    let (x_min, y_min, x_max, y_max) = (bounds.0 as f64, bounds.1 as f64, bounds.2 as f64, bounds.3 as f64);
+    let outcode = |x: f64, y: f64| {
+        let mut code = 0;
+        if x < x_min {
+            code |= LEFT;
+        } else if x > x_max {
+            code |= RIGHT;
+        }
+        if y < y_min {
+            code |= TOP;
+        } else if y > y_max {
+            code |= BOTTOM;
+        }
+        code
+    };
+
+    let (mut x0, mut y0) = (from.0 as f64, from.1 as f64);
+    let (mut x1, mut y1) = (to.0 as f64, to.1 as f64);
+    let (mut code0, mut code1) = (outcode(x0, y0), outcode(x1, y1));
+    loop {
+        if code0 | code1 == 0 {
+            return Some(((x0.round() as i32, y0.round() as i32), (x1.round() as i32, y1.round() as i32)));
+        }
+        if code0 & code1 != 0 {
+            return None;
+        }
+        // move the outside end point to the boundary
+        let code = if code0 != 0 { code0 } else { code1 };
+        let (x, y) = if code & TOP != 0 {
+            (x0 + (x1 - x0) * (y_min - y0) / (y1 - y0), y_min)
+        } else if code & BOTTOM != 0 {
+            (x0 + (x1 - x0) * (y_max - y0) / (y1 - y0), y_max)
+        } else if code & LEFT != 0 {
+            (x_min, y0 + (y1 - y0) * (x_min - x0) / (x1 - x0))
+        } else {
+            (x_max, y0 + (y1 - y0) * (x_max - x0) / (x1 - x0))
+        };
+        if code == code0 {
+            x0 = x;
+            y0 = y;
+            code0 = outcode(x0, y0);
+        } else {
+            x1 = x;
+            y1 = y;
+            code1 = outcode(x1, y1);
+        }
+    }
+}
+
+// 5x7 font, one byte per column, LSB is the top row
+fn glyph(c: char) -> [u8; 5] {
+    match c.to_ascii_uppercase() {
+        ' ' => [0x00, 0x00, 0x00, 0x00, 0x00],
+        '#' => [0x14, 0x7F, 0x14, 0x7F, 0x14],
+        '%' => [0x23, 0x13, 0x08, 0x64, 0x62],
+        '(' => [0x00, 0x1C, 0x22, 0x41, 0x00],
+        ')' => [0x00, 0x41, 0x22, 0x1C, 0x00],
+        '+' => [0x08, 0x08, 0x3E, 0x08, 0x08],
+        ',' => [0x00, 0x50, 0x30, 0x00, 0x00],
+        '-' => [0x08, 0x08, 0x08, 0x08, 0x08],
+        '.' => [0x00, 0x60, 0x60, 0x00, 0x00],
+        '/' => [0x20, 0x10, 0x08, 0x04, 0x02],
+        '0' => [0x3E, 0x51, 0x49, 0x45, 0x3E],
+        '1' => [0x00, 0x42, 0x7F, 0x40, 0x00],
+        '2' => [0x42, 0x61, 0x51, 0x49, 0x46],
+        '3' => [0x21, 0x41, 0x45, 0x4B, 0x31],
+        '4' => [0x18, 0x14, 0x12, 0x7F, 0x10],
+        '5' => [0x27, 0x45, 0x45, 0x45, 0x39],
+        '6' => [0x3C, 0x4A, 0x49, 0x49, 0x30],
+        '7' => [0x01, 0x71, 0x09, 0x05, 0x03],
+        '8' => [0x36, 0x49, 0x49, 0x49, 0x36],
+        '9' => [0x06, 0x49, 0x49, 0x29, 0x1E],
+        ':' => [0x00, 0x36, 0x36, 0x00, 0x00],
+        '=' => [0x14, 0x14, 0x14, 0x14, 0x14],
+        'A' => [0x7E, 0x11, 0x11, 0x11, 0x7E],
+        'B' => [0x7F, 0x49, 0x49, 0x49, 0x36],
+        'C' => [0x3E, 0x41, 0x41, 0x41, 0x22],
+        'D' => [0x7F, 0x41, 0x41, 0x22, 0x1C],
+        'E' => [0x7F, 0x49, 0x49, 0x49, 0x41],
+        'F' => [0x7F, 0x09, 0x09, 0x01, 0x01],
+        'G' => [0x3E, 0x41, 0x41, 0x51, 0x32],
+        'H' => [0x7F, 0x08, 0x08, 0x08, 0x7F],
+        'I' => [0x00, 0x41, 0x7F, 0x41, 0x00],
+        'J' => [0x20, 0x40, 0x41, 0x3F, 0x01],
+        'K' => [0x7F, 0x08, 0x14, 0x22, 0x41],
+        'L' => [0x7F, 0x40, 0x40, 0x40, 0x40],
+        'M' => [0x7F, 0x02, 0x04, 0x02, 0x7F],
+        'N' => [0x7F, 0x04, 0x08, 0x10, 0x7F],
+        'O' => [0x3E, 0x41, 0x41, 0x41, 0x3E],
+        'P' => [0x7F, 0x09, 0x09, 0x09, 0x06],
+        'Q' => [0x3E, 0x41, 0x51, 0x21, 0x5E],
+        'R' => [0x7F, 0x09, 0x19, 0x29, 0x46],
+        'S' => [0x46, 0x49, 0x49, 0x49, 0x31],
+        'T' => [0x01, 0x01, 0x7F, 0x01, 0x01],
+        'U' => [0x3F, 0x40, 0x40, 0x40, 0x3F],
+        'V' => [0x1F, 0x20, 0x40, 0x20, 0x1F],
+        'W' => [0x7F, 0x20, 0x18, 0x20, 0x7F],
+        'X' => [0x63, 0x14, 0x08, 0x14, 0x63],
+        'Y' => [0x03, 0x04, 0x78, 0x04, 0x03],
+        'Z' => [0x61, 0x51, 0x49, 0x45, 0x43],
+        '_' => [0x40, 0x40, 0x40, 0x40, 0x40],
+        // '?'
+        _ => [0x02, 0x01, 0x51, 0x09, 0x06],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::proto::primitives::BoundingBox2d;
+
+    #[test]
+    fn scale_test() {
+        let mut bbox = BoundingBox2d::new();
+        bbox.set_x(10.0);
+        bbox.set_y(20.0);
+        bbox.set_width(100.0);
+        bbox.set_height(50.0);
+        let mut annotations = ImageAnnotations::new();
+        annotations.mut_boxes().push(bbox);
+
+        let scaled = scale(&annotations, 0.5, 2.0);
+        let bbox = &scaled.get_boxes()[0];
+        assert_eq!((bbox.x, bbox.y), (5.0, 40.0));
+        assert_eq!((bbox.width, bbox.height), (50.0, 100.0));
+    }
+
+    #[test]
+    fn draw_test() {
+        let mut bbox = BoundingBox2d::new();
+        bbox.set_x(2.0);
+        bbox.set_y(2.0);
+        bbox.set_width(10.0);
+        bbox.set_height(10.0);
+        let mut annotations = ImageAnnotations::new();
+        annotations.mut_boxes().push(bbox);
+
+        let image = DynamicImage::ImageRgb8(RgbImage::new(16, 16));
+        let image = draw(image, &annotations).to_rgb8();
+
+        // edge is drawn with the default color, inside is untouched
+        assert_eq!(image.get_pixel(2, 7).0, [0, 255, 0]);
+        assert_eq!(image.get_pixel(7, 7).0, [0, 0, 0]);
+    }
+
+    #[test]
+    fn clip_test() {
+        let bounds = (0, 0, 15, 15);
+        assert_eq!(clip((2, 3), (10, 12), bounds), Some(((2, 3), (10, 12))));
+        assert_eq!(clip((-10, 5), (30, 5), bounds), Some(((0, 5), (15, 5))));
+        assert_eq!(clip((-20, -20), (-1, 30), bounds), None);
+        assert_eq!(
+            clip((-10_000_000, -10_000_000), (10_000_000, 10_000_000), bounds),
+            Some(((0, 0), (15, 15)))
+        );
+    }
+
+    #[test]
+    fn out_of_frame_test() {
+        let point = |x: f32, y: f32| {
+            let mut point = Point2d::new();
+            point.set_x(x);
+            point.set_y(y);
+            point
+        };
+        let mut annotations = ImageAnnotations::new();
+        let mut polyline = crate::api::proto::primitives::Polyline2d::new();
+        let points = vec![point(-4e9, 8.0), point(4e9, 8.0), point(f32::NAN, 1.0), point(f32::INFINITY, 2.0)];
+        polyline.set_points(points.into());
+        annotations.mut_polylines().push(polyline);
+        let mut keypoints = crate::api::proto::primitives::Keypoints2d::new();
+        keypoints.set_points(vec![point(f32::MAX, f32::MIN), point(f32::NEG_INFINITY, 0.0), point(15.0, 15.0)].into());
+        annotations.mut_keypoints().push(keypoints);
+        let mut bbox = BoundingBox2d::new();
+        bbox.set_x(f32::NAN);
+        bbox.set_width(10.0);
+        annotations.mut_boxes().push(bbox);
+
+        let image = DynamicImage::ImageRgb8(RgbImage::new(16, 16));
+        let image = draw(image, &annotations).to_rgb8();
+
+        // clipped line across the frame, keypoint on the corner, NaN and inf points skipped
+        assert_eq!(image.get_pixel(0, 8).0, [0, 255, 0]);
+        assert_eq!(image.get_pixel(15, 8).0, [0, 255, 0]);
+        assert_eq!(image.get_pixel(15, 15).0, [0, 255, 0]);
+        assert_eq!(image.get_pixel(0, 0).0, [0, 0, 0]);
+    }
+}
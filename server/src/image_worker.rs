@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
+
+// Bounded pool for image decode/resize/encode.
+// Jobs run on tokio's blocking threads, so the actix actor thread is never stalled
+// and at most `workers` images are processed at the same time.
+#[derive(Clone)]
+pub struct ImageWorkerPool {
+    permits: Arc<Semaphore>,
+}
+
+impl ImageWorkerPool {
+    pub fn new(workers: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(workers.max(1))),
+        }
+    }
+
+    // Wait for a free worker, then run the job.
+    // None if the job panicked.
+    pub async fn run<F, T>(&self, job: F) -> Option<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let permit = self.permits.clone().acquire_owned().await.ok()?;
+        tokio::task::spawn_blocking(move || {
+            let res = job();
+            drop(permit);
+            res
+        })
+        .await
+        .ok()
+    }
+
+    // Run the job only if a worker is free right now.
+    // None when all workers are busy, so that callers drop the frame instead of queueing it.
+    pub fn try_run<F, T>(&self, job: F) -> Option<JoinHandle<T>>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let permit = self.permits.clone().try_acquire_owned().ok()?;
+        Some(tokio::task::spawn_blocking(move || {
+            let res = job();
+            drop(permit);
+            res
+        }))
+    }
+}
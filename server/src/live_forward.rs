@@ -0,0 +1,105 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::{self, error::RecvError, error::TryRecvError};
+use tokio::time::Instant;
+
+use pubsub::proto::pubsub::PubsubMessage;
+
+// How live messages of a subscription are forwarded to the client
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ForwardOptions {
+    // messages per second, 0: unlimited
+    pub max_rate: f64,
+    // skip to the newest message when the client is behind
+    pub latest_only: bool,
+}
+
+impl ForwardOptions {
+    pub fn from_params(params: &serde_json::Value) -> Self {
+        Self {
+            max_rate: params["max_rate"].as_f64().unwrap_or(0.0).max(0.0),
+            latest_only: params["latest_only"].as_bool().unwrap_or(false),
+        }
+    }
+
+    fn min_interval(&self) -> Option<Duration> {
+        (self.max_rate > 0.0).then(|| Duration::from_secs_f64(1.0 / self.max_rate))
+    }
+}
+
+// Forward until the topic is closed (or the task is cancelled)
+pub async fn forward<F>(mut receiver: broadcast::Receiver<Arc<PubsubMessage>>, options: ForwardOptions, mut send: F)
+where
+    F: FnMut(Arc<PubsubMessage>),
+{
+    let mut last_sent: Option<Instant> = None;
+    loop {
+        // wait for the next slot, messages keep arriving in the buffer
+        if let (Some(interval), Some(last_sent)) = (options.min_interval(), last_sent) {
+            tokio::time::sleep_until(last_sent + interval).await;
+        }
+
+        let mut message = match receiver.recv().await {
+            Ok(message) => message,
+            Err(RecvError::Lagged(skipped)) => {
+                tracing::warn!("live subscriber skipped {} messages", skipped);
+                continue;
+            }
+            Err(RecvError::Closed) => return,
+        };
+
+        if options.latest_only {
+            loop {
+                match receiver.try_recv() {
+                    Ok(newer) => message = newer,
+                    Err(TryRecvError::Lagged(_)) => continue,
+                    Err(_) => break,
+                }
+            }
+        }
+
+        tracing::trace!(trace_id = %message.trace_id, timestamp = message.timestamp, "forward");
+        send(message);
+        last_sent = Some(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(timestamp: u64) -> Arc<PubsubMessage> {
+        Arc::new(PubsubMessage {
+            timestamp,
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn latest_only_test() {
+        let (sender, receiver) = broadcast::channel(16);
+        for timestamp in 1..=5 {
+            sender.send(message(timestamp)).unwrap();
+        }
+        drop(sender);
+
+        let mut received = Vec::new();
+        let options = ForwardOptions { max_rate: 0.0, latest_only: true };
+        forward(receiver, options, |message| received.push(message.timestamp)).await;
+        assert_eq!(received, [5]);
+    }
+
+    #[tokio::test]
+    async fn every_message_test() {
+        let (sender, receiver) = broadcast::channel(16);
+        for timestamp in 1..=5 {
+            sender.send(message(timestamp)).unwrap();
+        }
+        drop(sender);
+
+        let mut received = Vec::new();
+        let options = ForwardOptions { max_rate: 1000.0, latest_only: false };
+        forward(receiver, options, |message| received.push(message.timestamp)).await;
+        assert_eq!(received, [1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,75 @@
+use tracing_subscriber::EnvFilter;
+
+const DEFAULT_LEVEL: &str = "info";
+
+// LOG_LEVEL: filter directives, e.g. "debug" or "info,server=trace,pubsub=warn"
+// LOG_FORMAT: "json" for one JSON object per line with the spans of the event
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogConfig {
+    pub level: String,
+    pub json: bool,
+}
+
+impl LogConfig {
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars<F>(var: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        Self {
+            level: var("LOG_LEVEL").filter(|level| !level.is_empty()).unwrap_or_else(|| DEFAULT_LEVEL.into()),
+            json: var("LOG_FORMAT").as_deref() == Some("json"),
+        }
+    }
+
+    // falls back to the default level if LOG_LEVEL can't be parsed
+    pub fn filter(&self) -> Result<EnvFilter, String> {
+        EnvFilter::try_new(&self.level).map_err(|err| format!("Error: LOG_LEVEL {} {}", self.level, err))
+    }
+}
+
+pub fn init(config: &LogConfig) {
+    let (filter, warning) = match config.filter() {
+        Ok(filter) => (filter, None),
+        Err(err) => (EnvFilter::new(DEFAULT_LEVEL), Some(err)),
+    };
+
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+    let res = if config.json {
+        builder.json().with_current_span(true).with_span_list(true).try_init()
+    } else {
+        builder.try_init()
+    };
+    if let Err(err) = res {
+        eprintln!("Error: logging is already initialized {}", err);
+    }
+    if let Some(warning) = warning {
+        tracing::warn!("{}", warning);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_test() {
+        let config = LogConfig::from_vars(|_| None);
+        assert_eq!(config, LogConfig { level: "info".into(), json: false });
+        assert!(config.filter().is_ok());
+
+        let config = LogConfig::from_vars(|name| match name {
+            "LOG_LEVEL" => Some("info,server=trace".into()),
+            "LOG_FORMAT" => Some("json".into()),
+            _ => None,
+        });
+        assert!(config.json);
+        assert!(config.filter().is_ok());
+
+        let config = LogConfig::from_vars(|name| (name == "LOG_LEVEL").then(|| "server=loud".to_string()));
+        assert!(config.filter().is_err());
+    }
+}
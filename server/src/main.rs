@@ -10,138 +10,527 @@ use serde_json::{self, json};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use std::time;
+use tracing::{debug, error, info, info_span, trace, warn, Instrument};
 use pubsub::pubsub_service;
 
 mod pubsub_message_provider;
-use pubsub_message_provider::PubsubMessageProvider;
+use pubsub_message_provider::{PubsubMessageProvider, Recording};
+
+mod image_worker;
+use image_worker::ImageWorkerPool;
+
+mod image_overlay;
+mod image_cache;
+use image_cache::ImageCache;
+mod raw_image;
+mod video_export;
+
+mod schema_registry;
+use schema_registry::SchemaRegistry;
+
+mod viz_mapping;
+use viz_mapping::VizMappings;
+
+mod error;
+use error::Error as RequestError;
+
+mod series;
+mod validate;
+
+mod chunked_stream;
+use chunked_stream::{ChunkedStream, StreamRegistry};
+
+mod live_forward;
+
+mod record_control;
+use record_control::{RecordControl, RecordState};
+
+mod auth;
+use auth::{Auth, Identity, Role};
+
+mod tls_config;
+use tls_config::TlsConfig;
+
+mod event_bus;
+use event_bus::EventBus;
+
+mod disk_space;
+
+mod logging;
+use logging::LogConfig;
+
+mod metrics;
+use metrics::metrics;
+
+mod health;
+mod rest_api;
+
+mod session;
+use session::{ParkedSession, SessionInfo, SessionRegistry};
 
 mod api;
 #[path = "../../service/status/proto/status.rs"]
 mod service_status;
 
 
+// items: any IntoIterator of StreamSet, sent in chunks (ChunkedStream)
 macro_rules! ws_response_stream {
     ($name: expr, $subject: expr, $start_time: expr, $end_time: expr, $items: expr) => {
-        {
-            let mut stream = api::proto::response::Stream::new();
-            stream.set_path($name.to_string());
-            stream.set_subject($subject.to_string());
-            stream.set_start_time($start_time);
-            stream.set_end_time($end_time);
-            stream.set_items($items.into());
-            
-            let mut res = api::proto::response::WSResponse::new();
-            res.set_path($name.to_string());
-            res.set_data(Any::pack(&stream).unwrap());
-            res
-        };
+        ChunkedStream::new(
+            $name.to_string(),
+            $subject.to_string(),
+            $start_time,
+            $end_time,
+            $items.into_iter(),
+        )
     };
 }
 
 
 
 impl api::proto::response::WSResponse {
-    fn send<A>(self, ctx: &mut ws::WebsocketContext<A>)
-    where 
-        A: Actor<Context = ws::WebsocketContext<A>>
-    {
+    fn send(self, ctx: &mut ws::WebsocketContext<WebsocketGateway>) {
+        let path = self.path.clone();
         let _res = self
             .write_to_bytes()
-            .map(|msg| ctx.binary(msg))
-            .map_err(|e| eprintln!("send error {:?}", e));
+            .map(|msg| ctx.address().do_send(SendFrame { path, frame: Frame::Binary(msg) }))
+            .map_err(|e| error!("send error {:?}", e));
     }
 }
 
+// Queue a JSON response
+fn send_json(ctx: &mut ws::WebsocketContext<WebsocketGateway>, path: &str, message: serde_json::Value) {
+    ctx.address().do_send(SendFrame { path: path.into(), frame: Frame::Text(message.to_string()) });
+}
+
+
+pub enum Frame {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Priority {
+    // small, latency sensitive: status, commands
+    High,
+    // large: image frames
+    Low,
+}
+
+// Low priority frames queued for the socket. Beyond it range chunks wait and live frames are dropped.
+const MAX_QUEUED_FRAMES: usize = 8;
 
 pub struct WebsocketGateway {
-    route: HashMap<String, Box<dyn WebsocketResponder>> 
+    route: HashMap<String, Box<dyn WebsocketResponder>>,
+    // range responses and live image streams, sent as the client grants credits
+    streams: StreamRegistry,
+    // produces range chunks off the actor thread
+    workers: ImageWorkerPool,
+    // outgoing frames, high priority frames are sent first
+    high: std::collections::VecDeque<Frame>,
+    low: std::collections::VecDeque<Frame>,
+    flushing: bool,
+    session_id: String,
+    resume_token: String,
+    peer: String,
+    // who connected, checked for every request
+    identity: Identity,
+    sessions: Arc<SessionRegistry>,
+    events: Arc<EventBus>,
+    // state of the previous connection to restore when started
+    parked: Option<ParkedSession>,
+    last_heartbeat: time::Instant,
+    // "session" span of every log line of this connection, created when started
+    span: tracing::Span,
 }
 
 impl WebsocketGateway
 {
-    pub fn new() -> Self {
+    pub fn new(
+        sessions: Arc<SessionRegistry>,
+        events: Arc<EventBus>,
+        workers: ImageWorkerPool,
+        peer: String,
+        identity: Identity,
+    ) -> Self {
         Self { 
-            route: HashMap::new()
+            route: HashMap::new(),
+            streams: StreamRegistry::default(),
+            workers,
+            high: std::collections::VecDeque::new(),
+            low: std::collections::VecDeque::new(),
+            flushing: false,
+            session_id: uuid::Uuid::new_v4().to_hyphenated().to_string(),
+            resume_token: uuid::Uuid::new_v4().to_hyphenated().to_string(),
+            peer,
+            identity,
+            sessions,
+            events,
+            parked: None,
+            last_heartbeat: time::Instant::now(),
+            span: tracing::Span::none(),
          }
     }
 
+    // Continue a disconnected session
+    pub fn resume(&mut self, resume_token: String, parked: ParkedSession) {
+        self.session_id = parked.session_id.clone();
+        self.resume_token = resume_token;
+        self.parked = Some(parked);
+    }
+
+    // user, session and peer, never taken from a request
+    pub fn client(&self) -> String {
+        record_control::changed_by(&self.identity.name, &self.session_id, &self.peer)
+    }
+
     pub fn register(&mut self, responder: Box<dyn WebsocketResponder>) {
         self.route.insert(responder.name(), responder);
     }
 
+    fn enqueue(&mut self, path: &str, frame: Frame, ctx: &mut ws::WebsocketContext<Self>) {
+        let scope = path.split('/').next().unwrap_or("");
+        let priority = self.route.get(scope).map(|responder| responder.priority()).unwrap_or(Priority::High);
+        match priority {
+            Priority::High => self.high.push_back(frame),
+            Priority::Low => self.low.push_back(frame),
+        }
+
+        if !self.flushing {
+            self.flushing = true;
+            ctx.notify(Flush);
+        }
+    }
+
 
     fn dispatch(
         &mut self,
         msg: Result<ws::Message, ws::ProtocolError>,
         ctx: &mut ws::WebsocketContext<Self>,
     ) {
+        self.last_heartbeat = time::Instant::now();
         match msg {
             Ok(ws::Message::Text(text)) => {
                 let json_value: serde_json::Result<serde_json::Value> = serde_json::from_str(&text);
+                if let Err(err) = &json_value {
+                    let err = RequestError::InvalidRequest(format!("Error: malformed request {}", err));
+                    send_json(ctx, "Error", err.to_json("Error"));
+                }
 
                 let _ = json_value.map(|params| {
                     params["header"]["path"].as_str().map(|path| {
+                        // tasks spawned by the responder keep the span
+                        let _request = info_span!("request", path).entered();
+                        if self.identity.role < Role::required(path) {
+                            warn!("Forbidden: {} {}", self.identity.name, path);
+                            let err = RequestError::Forbidden(format!("Error: {} needs the operator role", path));
+                            send_json(ctx, path, err.to_json(path));
+                            return;
+                        }
+                        if path == "Stream/Credit" {
+                            self.grant_credits(&params, ctx);
+                            return;
+                        }
+
                         let scope = path.split('/').collect::<Vec<&str>>()[0];
-                        println!("Scope: {}", scope);
+                        debug!("Scope: {}", scope);
                         let responder = self.route.get_mut(scope);
-                        responder.map(|responder| {
-                            responder.execute(&params ,ctx);
-                        });
+                        let timer = time::Instant::now();
+                        let res = responder.map(|responder| responder.execute(&params, ctx));
+                        match res {
+                            // only paths of a responder, unknown ones would add a series each
+                            Some(Ok(())) => metrics().request_duration
+                                .with_label_values(&[path])
+                                .observe(timer.elapsed().as_secs_f64()),
+                            Some(Err(err)) => {
+                                warn!(code = err.code(), "{}", err);
+                                metrics().request_errors.with_label_values(&[err.code()]).inc();
+                                send_json(ctx, path, err.to_json(path));
+                            }
+                            None => (),
+                        }
 
                     });
                 });
             }
+            Ok(ws::Message::Ping(msg)) => {
+                ctx.pong(&msg);
+            }
+            Ok(ws::Message::Pong(_)) => {}
             Ok(ws::Message::Close(_)) => {
-                println!("Client websocket closed");
-
-                for (_, responder) in self.route.iter_mut() {
-                    responder.close(ctx);
-                }
-
+                info!("Client websocket closed");
+                // responders are closed in stopped()
                 ctx.stop();
             }
             Err(e) => {
-                eprintln!("Err: WsHandler received message error {:?}", e);
+                error!("WsHandler received message error {:?}", e);
             }
             _ => {
-                eprintln!("Warning: Unsupported message");
+                warn!("Unsupported message");
             }
         }
     }
+
+    // Stream/Credit: { "stream_id": <stream_id of the chunks>, "credits": 1 }
+    fn grant_credits(&mut self, params: &serde_json::Value, ctx: &mut ws::WebsocketContext<Self>) {
+        let id = params["stream_id"].as_u64().unwrap_or(0) as u32;
+        let credits = params["credits"].as_u64().unwrap_or(1) as u32;
+        if self.streams.grant(id, credits) {
+            self.produce_chunks(ctx);
+        }
+    }
+
+    // Next chunk of every stream with credit, encoded on the worker pool.
+    // Waits while the client is behind on the queued frames.
+    fn produce_chunks(&mut self, ctx: &mut ws::WebsocketContext<Self>) {
+        let room = MAX_QUEUED_FRAMES.saturating_sub(self.low.len());
+        for mut stream in self.streams.take_ready(room) {
+            let id = stream.id();
+            let job = move || {
+                let chunk = stream.next_chunk();
+                (stream, chunk)
+            };
+            let workers = self.workers.clone();
+            let task = actix::fut::wrap_future::<_, Self>(async move { workers.run(job).await }.in_current_span())
+                .map(move |res, act, ctx| match res {
+                    Some((stream, chunk)) => {
+                        let path = stream.path().to_string();
+                        act.streams.put_back(stream);
+                        if let Some(chunk) = chunk {
+                            act.send_chunk(&path, &chunk, ctx);
+                        }
+                        act.produce_chunks(ctx);
+                    }
+                    None => {
+                        error!("stream {} failed", id);
+                        act.streams.remove(id);
+                    }
+                });
+            ctx.spawn(task);
+        }
+    }
+
+    fn send_chunk(&mut self, path: &str, chunk: &api::proto::response::Stream, ctx: &mut ws::WebsocketContext<Self>) {
+        let mut res = api::proto::response::WSResponse::new();
+        res.set_path(path.to_string());
+        let _res = Any::pack(chunk)
+            .and_then(|data| {
+                res.set_data(data);
+                res.write_to_bytes()
+            })
+            .map(|msg| self.enqueue(path, Frame::Binary(msg), ctx))
+            .map_err(|e| error!("send error {:?}", e));
+    }
 }
 
-#[allow(unused_variables)]
 impl Actor for WebsocketGateway {
     type Context = ws::WebsocketContext<Self>;
 
-    // #[TODO]
     fn started(&mut self, ctx: &mut Self::Context){
+        self.span = info_span!("session", id = %self.session_id, peer = %self.peer, user = %self.identity.name);
+        let _session = self.span.clone().entered();
+        let services = self.route.keys().cloned().collect();
+        self.sessions.insert(SessionInfo::new(self.session_id.clone(), self.peer.clone(), services));
+        metrics().sessions.inc();
+        info!("Session started");
+        for (_, responder) in self.route.iter_mut() {
+            responder.started(ctx);
+        }
+        send_json(ctx, "Session/Started", json!({
+            "path": "Session/Started",
+            "data": {
+                "session_id": self.session_id,
+                // reconnect with /ws?resume=<token> to continue this session
+                "resume_token": self.resume_token,
+                "resumed": self.parked.is_some(),
+            },
+        }));
+
+        if let Some(parked) = self.parked.take() {
+            for (name, state) in parked.states {
+                if let Some(responder) = self.route.get_mut(&name) {
+                    responder.resume(state, ctx);
+                }
+            }
+            for mut stream in parked.streams {
+                stream.reset_credits();
+                self.streams.insert(stream);
+            }
+            self.produce_chunks(ctx);
+        }
+
+        // server-wide events, "Event" for every client
+        let mut events = self.events.subscribe();
+        let address = ctx.address();
+        let task = async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => {
+                        let mut res = api::proto::response::WSResponse::new();
+                        res.set_path("Event".into());
+                        let _res = Any::pack(event.as_ref())
+                            .and_then(|data| {
+                                res.set_data(data);
+                                res.write_to_bytes()
+                            })
+                            .map(|msg| address.do_send(SendFrame { path: "Event".into(), frame: Frame::Binary(msg) }))
+                            .map_err(|e| error!("send error {:?}", e));
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("session skipped {} events", skipped);
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        };
+        ctx.spawn(actix::fut::wrap_future(task.in_current_span()));
+
+        // ping, and drop clients which went away without a Close frame
+        ctx.run_interval(session::HEARTBEAT_INTERVAL, |act, ctx| {
+            if act.last_heartbeat.elapsed() > session::CLIENT_TIMEOUT {
+                let _session = act.span.clone().entered();
+                info!("Session timeout");
+                ctx.stop();
+                return;
+            }
+            ctx.ping(b"");
+        });
     }
 
-    // #[TODO]
+    // Close frame, timeout or connection error
     fn stopped(&mut self, ctx: &mut Self::Context) {
+        let _session = self.span.clone().entered();
+        let states = self.route.iter_mut()
+            .filter_map(|(name, responder)| responder.suspend().map(|state| (name.clone(), state)))
+            .collect();
+        for (_, responder) in self.route.iter_mut() {
+            responder.close(ctx);
+        }
+
+        // keep the state for a while, the client may reconnect
+        let streams = self.streams.drain();
+        self.sessions.park(self.resume_token.clone(), ParkedSession::new(self.session_id.clone(), self.identity.clone(), states, streams));
+        self.sessions.remove(&self.session_id);
+        metrics().sessions.dec();
+        info!("Session stopped");
     }
 
 }
 
 #[derive(Message)]
 #[rtype(result = "()")]
-pub struct SendWSResponse {
-    response: api::proto::response::WSResponse,
+pub struct SendFrame {
+    path: String,
+    frame: Frame,
+}
+
+impl Handler<SendFrame> for WebsocketGateway {
+    type Result = ();
+
+    fn handle(&mut self, msg: SendFrame, ctx: &mut Self::Context) {
+        self.enqueue(&msg.path, msg.frame, ctx);
+    }
 }
 
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Flush;
 
-impl Handler<SendWSResponse> for WebsocketGateway {
+impl Handler<Flush> for WebsocketGateway {
     type Result = ();
 
-    fn handle(&mut self, msg: SendWSResponse, ctx: &mut Self::Context) {
-        msg.response.send(ctx);
+    fn handle(&mut self, _msg: Flush, ctx: &mut Self::Context) {
+        let write = |frame: Frame, ctx: &mut Self::Context| match frame {
+            Frame::Text(text) => ctx.text(text),
+            Frame::Binary(bytes) => ctx.binary(bytes),
+        };
+
+        while let Some(frame) = self.high.pop_front() {
+            write(frame, ctx);
+        }
+        // one large frame per turn, frames queued meanwhile by other paths can go first
+        if let Some(frame) = self.low.pop_front() {
+            write(frame, ctx);
+        }
+
+        self.flushing = !self.low.is_empty();
+        if self.flushing {
+            ctx.notify(Flush);
+        }
+        // room for range chunks which waited for the queue
+        self.produce_chunks(ctx);
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct SendStream {
+    stream: ChunkedStream,
+}
+
+
+impl Handler<SendStream> for WebsocketGateway {
+    type Result = ();
+
+    fn handle(&mut self, msg: SendStream, ctx: &mut Self::Context) {
+        // concurrent responses of the same path are separate streams
+        self.streams.insert(msg.stream);
+        self.produce_chunks(ctx);
+    }
+}
+
+// Live frame of a subscription, dropped if the client has not handled the previous ones
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct SendLive {
+    // one stream per key, e.g. path and topic
+    key: String,
+    path: String,
+    subject: String,
+    item: api::proto::response::StreamSet,
+}
+
+impl Handler<SendLive> for WebsocketGateway {
+    type Result = ();
+
+    fn handle(&mut self, msg: SendLive, ctx: &mut Self::Context) {
+        if self.low.len() >= MAX_QUEUED_FRAMES {
+            debug!("Drop frame: {} {} (queue full)", msg.path, msg.item.timestamp);
+            return;
+        }
+        let timestamp = msg.item.timestamp;
+        match self.streams.live_chunk(&msg.key, &msg.path, &msg.subject, msg.item) {
+            Some(chunk) => self.send_chunk(&msg.path, &chunk, ctx),
+            None => debug!("Drop frame: {} {} (no credit)", msg.path, timestamp),
+        }
+    }
+}
+
+// The subscription of the live stream ended
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct EndLive {
+    key: String,
+}
+
+impl Handler<EndLive> for WebsocketGateway {
+    type Result = ();
+
+    fn handle(&mut self, msg: EndLive, _ctx: &mut Self::Context) {
+        self.streams.end_live(&msg.key);
+    }
+}
+
+impl ChunkedStream {
+    fn send(self, ctx: &mut ws::WebsocketContext<WebsocketGateway>) {
+        ctx.address().do_send(SendStream { stream: self });
     }
 }
 
 impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for WebsocketGateway
 {
     fn handle(&mut self, msg: Result<ws::Message, ws::ProtocolError>, ctx: &mut Self::Context) {
+        let _session = self.span.clone().entered();
         self.dispatch(msg, ctx);
     }
 }
@@ -152,37 +541,59 @@ pub trait WebsocketResponder {
  
     fn name(&self) -> String;
 
+    // priority of the responses
+    fn priority(&self) -> Priority {
+        Priority::High
+    }
+
+    // errors are sent to the client as { path, error, code }
     fn execute(
         &mut self,
         params: &serde_json::Value,
-        ctx: &mut ws::WebsocketContext<WebsocketGateway>);
+        ctx: &mut ws::WebsocketContext<WebsocketGateway>) -> Result<(), RequestError>;
 
 
+    // when websocket connected
+    fn started(
+        &mut self,
+        ctx: &mut ws::WebsocketContext<WebsocketGateway>){}
+
     // when websocket closed
     fn close(
         &mut self,
         ctx: &mut ws::WebsocketContext<WebsocketGateway>){}
+
+    // State to restore when the client resumes the session
+    fn suspend(&mut self) -> Option<serde_json::Value> {
+        None
+    }
+
+    // Restore the state of suspend() on the new connection
+    fn resume(
+        &mut self,
+        state: serde_json::Value,
+        ctx: &mut ws::WebsocketContext<WebsocketGateway>){}
 }
 
 
 pub struct CommandService{
-    pubsub: Arc<RwLock<pubsub_service::Client>>,
-    message_provider: Arc<RwLock<PubsubMessageProvider>>, 
+    // shared by every connection
+    record: Arc<RecordControl>,
+    // who connected, changed_by of Command/Record like POST /api/record
+    client: String,
 }
 
 impl CommandService{
-    async fn new(pubsub_address: String, message_provider: Arc<RwLock<PubsubMessageProvider>>) -> Self {
-        let pubsub = Arc::new(RwLock::new(
-            pubsub_service::Client::connect(pubsub_address)
-                .await
-                .unwrap(),
-        ));
-
+    fn new(record: Arc<RecordControl>, client: String) -> Self {
         Self {
-            pubsub,
-            message_provider
+            record,
+            client,
         }
     }
+
+    fn record_state_message(state: &RecordState) -> serde_json::Value {
+        json!({ "path": "Command/RecordState", "data": state.to_json() })
+    }
 }
 
 impl WebsocketResponder for CommandService {
@@ -195,53 +606,73 @@ impl WebsocketResponder for CommandService {
         &mut self,
         params: &serde_json::Value,
         ctx: &mut ws::WebsocketContext<WebsocketGateway>
-    ) {
+    ) -> Result<(), RequestError> {
         match params["header"]["path"].as_str() {
             Some("Command/Test") => {
-                println!("Receive Command/Test");
+                info!("Receive Command/Test");
             }
             Some("Command/Record") => {
-                let enable: bool = params["enable"].as_bool().unwrap_or(false);
-                println!("Command/Record {}", enable);
-                self.message_provider.read().unwrap().enable_record(enable);
+                let enable = params["enable"].as_bool()
+                    .ok_or_else(|| RequestError::InvalidRequest("Error: enable must be a boolean".into()))?;
+                info!("Command/Record {}", enable);
+                // every client gets the new state through started()
+                self.record.set(enable, &self.client);
+            }
+            Some("Command/RecordState") => {
+                send_json(ctx, "Command/RecordState", Self::record_state_message(&self.record.state()));
             }
-            _ => {}
+            path => return Err(RequestError::unknown_path(path.unwrap_or(""))),
         }
+        Ok(())
     }
 
-    fn close(
+    // the record state now and on every change
+    fn started(
         &mut self,
         ctx: &mut ws::WebsocketContext<WebsocketGateway>
-    ){
-        let pubsub = self.pubsub.clone();
+    ) {
+        send_json(ctx, "Command/RecordState", Self::record_state_message(&self.record.state()));
+
+        let mut changes = self.record.subscribe();
+        let address = ctx.address();
         let task = async move {
-            let _res = pubsub.write().unwrap().close().await;
+            loop {
+                match changes.recv().await {
+                    Ok(state) => {
+                        let message = Self::record_state_message(&state);
+                        address.do_send(SendFrame { path: "Command/RecordState".into(), frame: Frame::Text(message.to_string()) });
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
         };
-        let task = actix::fut::wrap_future(task);
-        ctx.wait(task);
+        ctx.spawn(actix::fut::wrap_future(task.in_current_span()));
     }
 }
 
 
+// Responds to the visualization paths of one scope (schemas/visualizations.json)
 pub struct StatusService {
+    scope: String,
     message_provider: Arc<RwLock<PubsubMessageProvider>>, 
-    live: bool
+    schemas: Arc<SchemaRegistry>,
+    mappings: Arc<VizMappings>,
 }
 
 impl StatusService {
-    async fn new(message_provider: Arc<RwLock<PubsubMessageProvider>>) -> Result<Self, String> {
-        Ok(Self {
+    fn new(
+        scope: String,
+        message_provider: Arc<RwLock<PubsubMessageProvider>>,
+        schemas: Arc<SchemaRegistry>,
+        mappings: Arc<VizMappings>,
+    ) -> Self {
+        Self {
+            scope,
             message_provider,
-            live: true,
-        })
-    }
-
-    pub fn enable_live(&mut self, enable: bool){
-        self.live = enable;
-    }
-
-    fn get_message(&self, topic: &str, timestamp: Option<u64>) -> Option<pubsub::proto::pubsub::PubsubMessage>{
-        self.message_provider.read().unwrap().get(topic, timestamp)
+            schemas,
+            mappings,
+        }
     }
 } 
 
@@ -249,27 +680,20 @@ impl StatusService {
 impl WebsocketResponder for StatusService {
 
     fn name(&self) -> String {
-        "Status".into()
+        self.scope.clone()
     }
 
     fn execute(
         &mut self,
         params: &serde_json::Value,
         ctx: &mut ws::WebsocketContext<WebsocketGateway>
-    ) {
+    ) -> Result<(), RequestError> {
         let start_time: u64 = params["start_time"].as_u64().unwrap_or(0);
         let end_time: u64 = params["end_time"].as_u64().unwrap_or(0);
+        let path = params["header"]["path"].as_str().unwrap_or("");
 
-            
-        let timestamps = if self.live {
-            // one shot
-            vec![end_time]
-        } else {
-            self.message_provider.read().unwrap().collect_timestamps("status/status", start_time, end_time)
-        };
-
-        match params["header"]["path"].as_str() {
-            Some("Status/type1") => {
+        match path {
+            "Status/type1" => {
                 let message = json!({
                     "path": "Status/type1",
                     "data": {
@@ -285,118 +709,440 @@ impl WebsocketResponder for StatusService {
                         ],
                     },
                 });
-                ctx.text(message.to_string())
-            }
-            Some("Status/Live") => {
-                let enable: bool = params["enable"].as_bool().unwrap_or(false);
-                println!("Status/Live {}", enable);
-                self.enable_live(enable);
-            }
-            Some("Status/Status") => {
-                let mut items = Vec::new();
-            
-                for timestamp in timestamps {
-
-                    let timestamp = (!self.live).then(|| timestamp);
-                    let message = self.get_message("/status/status", timestamp);
-                    let _ = message.map(|msg| {
-                        // convert PubsubMessage.data to Status proto
-                        let res: protobuf::ProtobufResult<service_status::Status> =
-                            protobuf::Message::parse_from_bytes(&msg.data);
-
-                        if let Ok(data) = res {                   
-                            let mut status = api::proto::response::Status::new();
-    
-                            // Position
-                            let mut point3d = api::proto::primitives::Point3d::new();
-                            let pos = data.position.unwrap();
-                            point3d.set_x(pos.x);
-                            point3d.set_y(pos.y);
-                            point3d.set_z(pos.z);
-                            status.set_point3d(point3d);
-    
-                            // debug
-                            let mut text = api::proto::primitives::Text::new();
-                            text.set_text(data.debug);
-                            status.set_text(text);
-    
-                            // chart
-                            let mut point2d = api::proto::primitives::Point2d::new();
-                            point2d.set_x(data.timestamp as f32);
-                            point2d.set_y(pos.y as f32);
-                            status.set_point2d(point2d);
-    
-                            let mut streamset = api::proto::response::StreamSet::new();
-                            streamset.set_timestamp(data.timestamp);
-                            streamset.set_status(status);
-                            items.push(streamset);
+                send_json(ctx, "Status/type1", message)
+            }
+            _ => {
+                let mapping = self.mappings.get(path)
+                    .ok_or_else(|| RequestError::unknown_path(path))?;
+
+                // per request, live if not given
+                let live = match params["mode"].as_str().unwrap_or("") {
+                    "" | "live" => true,
+                    "replay" => false,
+                    mode => return Err(RequestError::InvalidRequest(format!("Error: mode {:?}, live or replay", mode))),
+                };
+                let timestamps = if live {
+                    // one shot
+                    vec![end_time]
+                } else {
+                    validate::range(params)?;
+                    let timestamps = self.message_provider.read()?.collect_timestamps(&mapping.topic, start_time, end_time);
+                    validate::item_count(timestamps.len(), validate::MAX_ITEMS)?;
+                    timestamps
+                };
+
+                // decoded chunk by chunk while the client reads the stream
+                let items = {
+                    let path = path.to_string();
+                    let message_provider = self.message_provider.clone();
+                    let schemas = self.schemas.clone();
+                    let mappings = self.mappings.clone();
+                    timestamps.into_iter().filter_map(move |timestamp| {
+                        let mapping = mappings.get(&path)?;
+                        let timestamp = (!live).then_some(timestamp);
+                        let msg = message_provider.read().ok()?.get(&mapping.topic, timestamp)?;
+                        // PubsubMessage.data -> fields -> viz primitives
+                        match schemas.decode(&mapping.topic, &msg.data) {
+                            Ok(value) => mapping.build_streamset(&value, msg.timestamp).map(|mut streamset| {
+                                streamset.set_trace_id(msg.trace_id);
+                                streamset
+                            }),
+                            Err(err) => {
+                                warn!(trace_id = %msg.trace_id, "{}", err);
+                                None
+                            }
                         }
-                    });
-                }
-                ws_response_stream!("Status/Status", "/status/status", start_time, end_time, items).send(ctx);
+                    })
+                };
+                ws_response_stream!(path, &mapping.subject, start_time, end_time, items).send(ctx);
+            }
+        }
+        Ok(())
+    }
+
+}
+
+// Topic message in the format the client asked for
+#[derive(Message)]
+#[rtype(result = "()")]
+pub enum TopicReply {
+    // decoded message or error
+    Json(serde_json::Value),
+    // dynamic proto: undecoded data with its type
+    Proto(api::proto::response::WSResponse),
+}
 
+impl TopicReply {
+    fn new(schemas: &SchemaRegistry, path: &str, topic: &str, message: &pubsub::proto::pubsub::PubsubMessage, format: &str) -> Self {
+        let type_name = match schemas.message_type(topic) {
+            Some(type_name) => type_name,
+            None => {
+                let err = RequestError::NotFound(format!("Error: no schema for {}", topic));
+                return TopicReply::Json(err.to_json(path));
             }
-            Some("Status/Debug") => {
-                let mut items = Vec::new();
-                for timestamp in timestamps {
-                    let timestamp = (!self.live).then(|| timestamp);
+        };
 
-                    let message = self.get_message("/status/status", timestamp);
-                    let _ = message.map(|msg| {
-                        // convert PubsubMessage.data to Status proto
-                        let res: protobuf::ProtobufResult<service_status::Status> =
-                            protobuf::Message::parse_from_bytes(&msg.data);
+        if format == "proto" {
+            let mut any = Any::new();
+            any.set_type_url(format!("type.googleapis.com/{}", type_name));
+            any.set_value(message.data.clone());
 
-                        if let Ok(data) = res {
-                            let mut debug = api::proto::primitives::Text::new();
-                            debug.set_text(data.debug);
+            let mut res = api::proto::response::WSResponse::new();
+            res.set_path(path.into());
+            res.set_data(any);
+            res.set_trace_id(message.trace_id.clone());
+            return TopicReply::Proto(res);
+        }
 
-                            let mut streamset = api::proto::response::StreamSet::new();
-                            streamset.set_timestamp(data.timestamp);
-                            streamset.set_text(debug);
-                            items.push(streamset);
-                        }
-                    });
+        TopicReply::Json(match Self::message_json(schemas, topic, message) {
+            Ok(data) => json!({ "path": path, "data": data }),
+            Err(err) => err.to_json(path),
+        })
+    }
+
+    // decoded message with its topic, timestamp, trace id and type
+    fn message_json(
+        schemas: &SchemaRegistry,
+        topic: &str,
+        message: &pubsub::proto::pubsub::PubsubMessage,
+    ) -> Result<serde_json::Value, RequestError> {
+        let type_name = schemas.message_type(topic)
+            .ok_or_else(|| RequestError::NotFound(format!("Error: no schema for {}", topic)))?;
+        let value = schemas.decode(topic, &message.data).map_err(RequestError::Decode)?;
+        Ok(json!({
+            "topic": topic,
+            "timestamp": message.timestamp,
+            "trace_id": message.trace_id,
+            "type": type_name,
+            "message": value,
+        }))
+    }
+
+    fn send(self, ctx: &mut ws::WebsocketContext<WebsocketGateway>) {
+        match self {
+            TopicReply::Json(value) => {
+                let path = value["path"].as_str().unwrap_or("").to_string();
+                send_json(ctx, &path, value)
+            }
+            TopicReply::Proto(res) => res.send(ctx),
+        }
+    }
+}
+
+impl Handler<TopicReply> for WebsocketGateway {
+    type Result = ();
+
+    fn handle(&mut self, msg: TopicReply, ctx: &mut Self::Context) {
+        msg.send(ctx);
+    }
+}
+
+pub struct TopicService {
+    message_provider: Arc<RwLock<PubsubMessageProvider>>,
+    schemas: Arc<SchemaRegistry>,
+    // live subscriptions of this connection by topic, with the request
+    subscriptions: HashMap<String, (SpawnHandle, serde_json::Value)>,
+}
+
+impl TopicService {
+    pub fn new(message_provider: Arc<RwLock<PubsubMessageProvider>>, schemas: Arc<SchemaRegistry>) -> Self {
+        Self {
+            message_provider,
+            schemas,
+            subscriptions: HashMap::new(),
+        }
+    }
+}
+
+impl WebsocketResponder for TopicService {
+
+    fn name(&self) -> String {
+        "Topic".into()
+    }
+
+    fn execute(
+        &mut self,
+        params: &serde_json::Value,
+        ctx: &mut ws::WebsocketContext<WebsocketGateway>
+    ) -> Result<(), RequestError> {
+        match params["header"]["path"].as_str() {
+            Some("Topic/List") => {
+                let topics = self.schemas.topics().into_iter().map(|(topic, type_name)| {
+                    json!({ "topic": topic, "type": type_name })
+                })
+                .collect::<Vec<_>>();
+
+                let message = json!({
+                    "path": "Topic/List",
+                    "data": { "topics": topics },
+                });
+                send_json(ctx, "Topic/List", message)
+            }
+            Some("Topic/Decode") => {
+                let topic = validate::topic(params, "topic", |topic| self.schemas.message_type(topic).is_some())?;
+                // latest message if timestamp is not given
+                let timestamp = params["timestamp"].as_u64();
+                let format = params["format"].as_str().unwrap_or("json");
+
+                let message = self.message_provider.read()?.get(topic, timestamp)
+                    .ok_or_else(|| RequestError::NotFound(format!("Error: Not Found {} {:?}", topic, timestamp)))?;
+                TopicReply::new(&self.schemas, "Topic/Decode", topic, &message, format).send(ctx);
+            }
+            Some("Topic/Subscribe") => {
+                let topic = params["topic"].as_str().unwrap_or("").to_string();
+                let format = params["format"].as_str().unwrap_or("json").to_string();
+                validate::number(params, "max_rate", 0.0, 0.0, validate::MAX_RATE)?;
+                let options = live_forward::ForwardOptions::from_params(params);
+
+                let receiver = self.message_provider.read()?.subscribe(&topic)
+                    .ok_or_else(|| RequestError::NotFound(format!("Error: Not Found {}", topic)))?;
+
+                // subscribing again replaces the options
+                if let Some((handle, _)) = self.subscriptions.remove(&topic) {
+                    ctx.cancel_future(handle);
                 }
-                ws_response_stream!("Status/Debug", "/status/debug", start_time, end_time, items).send(ctx);
+
+                let task = {
+                    let address = ctx.address();
+                    let schemas = self.schemas.clone();
+                    let topic = topic.clone();
+                    live_forward::forward(receiver, options, move |message| {
+                        let reply = TopicReply::new(&schemas, "Topic/Message", &topic, &message, &format);
+                        address.do_send(reply);
+                    })
+                };
+                let task = actix::fut::wrap_future(task.in_current_span());
+                let handle = ctx.spawn(task);
+                self.subscriptions.insert(topic, (handle, params.clone()));
             }
-            _ => {
-                return;
+            Some("Topic/Unsubscribe") => {
+                let topic = params["topic"].as_str().unwrap_or("");
+                if let Some((handle, _)) = self.subscriptions.remove(topic) {
+                    ctx.cancel_future(handle);
+                }
             }
+            path => return Err(RequestError::unknown_path(path.unwrap_or(""))),
+        }
+        Ok(())
+    }
+
+    fn close(
+        &mut self,
+        ctx: &mut ws::WebsocketContext<WebsocketGateway>
+    ) {
+        for (_, (handle, _)) in self.subscriptions.drain() {
+            ctx.cancel_future(handle);
+        }
+    }
+
+    // subscribe again on the new connection
+    fn suspend(&mut self) -> Option<serde_json::Value> {
+        let requests = self.subscriptions.values().map(|(_, params)| params.clone()).collect::<Vec<_>>();
+        (!requests.is_empty()).then(|| json!(requests))
+    }
+
+    fn resume(
+        &mut self,
+        state: serde_json::Value,
+        ctx: &mut ws::WebsocketContext<WebsocketGateway>
+    ) {
+        for params in state.as_array().into_iter().flatten() {
+            if let Err(err) = self.execute(params, ctx) {
+                warn!("Resume {}", err);
+            }
+        }
+    }
+}
+
+pub struct SeriesService {
+    recording: Recording,
+    schemas: Arc<SchemaRegistry>,
+    workers: ImageWorkerPool,
+}
+
+impl SeriesService {
+    pub fn new(recording: Recording, schemas: Arc<SchemaRegistry>, workers: ImageWorkerPool) -> Self {
+        Self {
+            recording,
+            schemas,
+            workers,
+        }
+    }
+
+    // (timestamp, value) of a numeric field over the recorded range
+    fn collect_values(
+        recording: &Recording,
+        schemas: &SchemaRegistry,
+        topic: &str,
+        field: &str,
+        start_time: u64,
+        end_time: u64,
+    ) -> Vec<(u64, f64)> {
+        recording
+            .collect_timestamps(topic, start_time, end_time)
+            .into_iter()
+            .filter_map(|timestamp| {
+                let message = recording.read(topic, timestamp)?;
+                let value = schemas.decode(topic, &message.data).ok()?;
+                let value = viz_mapping::field_value(&value, field)?.as_f64()?;
+                Some((timestamp, value))
+            })
+            .collect()
+    }
+
+    fn build_streamsets(points: &[(u64, f64)], method: &str, start_time: u64, end_time: u64, count: usize) -> Vec<api::proto::response::StreamSet> {
+        let buckets = match method {
+            "lttb" => series::lttb(points, count)
+                .into_iter()
+                .map(|(timestamp, value)| series::Bucket {
+                    start_time: timestamp,
+                    end_time: timestamp,
+                    min: value,
+                    max: value,
+                    mean: value,
+                    count: 1,
+                })
+                .collect(),
+            _ => series::buckets(points, start_time, end_time, count),
+        };
+
+        buckets
+            .into_iter()
+            .map(|bucket| {
+                let mut series_bucket = api::proto::response::SeriesBucket::new();
+                series_bucket.set_end_time(bucket.end_time);
+                series_bucket.set_min(bucket.min);
+                series_bucket.set_max(bucket.max);
+                series_bucket.set_mean(bucket.mean);
+                series_bucket.set_count(bucket.count);
+
+                let mut streamset = api::proto::response::StreamSet::new();
+                streamset.set_timestamp(bucket.start_time);
+                streamset.set_series_bucket(series_bucket);
+                streamset
+            })
+            .collect()
+    }
+}
+
+impl WebsocketResponder for SeriesService {
+
+    fn name(&self) -> String {
+        "Series".into()
+    }
+
+    fn execute(
+        &mut self,
+        params: &serde_json::Value,
+        ctx: &mut ws::WebsocketContext<WebsocketGateway>
+    ) -> Result<(), RequestError> {
+        let path = params["header"]["path"].as_str().unwrap_or("");
+        if path != "Series/Query" {
+            return Err(RequestError::unknown_path(path));
         }
+
+        let (start_time, end_time) = validate::range(params)?;
+        let topic = validate::topic(params, "topic", |topic| self.schemas.message_type(topic).is_some())?.to_string();
+        let count = validate::count(params, "count", 500, validate::MAX_SERIES_POINTS)? as usize;
+        let field = params["field"].as_str().unwrap_or("").to_string();
+        let method = params["method"].as_str().unwrap_or("minmax").to_string();
+
+        // decoding a long range is slow, keep it off the actor thread
+        let task = {
+            let recipient = ctx.address().recipient();
+            let recording = self.recording.clone();
+            let schemas = self.schemas.clone();
+            let workers = self.workers.clone();
+            async move {
+                let job = {
+                    let topic = topic.clone();
+                    move || {
+                        let points = Self::collect_values(&recording, &schemas, &topic, &field, start_time, end_time);
+                        Self::build_streamsets(&points, &method, start_time, end_time, count)
+                    }
+                };
+                let items = workers.run(job).await.unwrap_or_default();
+
+                let stream = ws_response_stream!("Series/Query", &topic, start_time, end_time, items);
+                let _ = recipient.do_send(SendStream { stream });
+            }
+        };
+        let task = actix::fut::wrap_future(task.in_current_span());
+        ctx.spawn(task);
+        Ok(())
+    }
+}
+
+// Responders registered once at startup, created for each connection which asks for them,
+// with the server side name of the connection (WebsocketGateway::client) for responders which audit
+type ResponderFactory = Box<dyn Fn(&str) -> Box<dyn WebsocketResponder> + Send + Sync>;
+
+#[derive(Default)]
+pub struct ServiceRegistry {
+    factories: Vec<(String, ResponderFactory)>,
+}
+
+impl ServiceRegistry {
+    // name: first part of the request paths
+    pub fn register<F>(&mut self, name: &str, factory: F)
+    where
+        F: Fn(&str) -> Box<dyn WebsocketResponder> + Send + Sync + 'static,
+    {
+        self.factories.push((name.to_string(), Box::new(factory)));
     }
 
+    // services: "Status,Image", every service if not given
+    pub fn create(&self, services: Option<&str>, client: &str) -> Result<Vec<Box<dyn WebsocketResponder>>, String> {
+        let names = match services {
+            Some(services) => services.split(',').map(|name| name.trim()).filter(|name| !name.is_empty()).collect(),
+            None => self.factories.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(),
+        };
+
+        names.into_iter().map(|name| {
+            self.factories.iter()
+                .find(|(registered, _)| registered == name)
+                .map(|(_, factory)| factory(client))
+                .ok_or_else(|| format!("Error: unknown service {}", name))
+        })
+        .collect()
+    }
 }
 
+// One endpoint for every service: /ws?services=Status,Command,Image&resume=<token>
 async fn ws(
     req: HttpRequest,
     stream: web::Payload,
+    query: web::Query<HashMap<String, String>>,
     state: web::Data<AppState>,
 ) -> Result<HttpResponse, Error> {
+    let identity = state.auth.authorize(&req, Role::Viewer)?;
+
+    let peer = req.peer_addr().map(|addr| addr.to_string()).unwrap_or_default();
+    let mut gateway = WebsocketGateway::new(
+        state.sessions.clone(), state.events.clone(), state.image_workers.clone(), peer, identity.clone());
+    // reconnect: /ws?resume=<token of Session/Started>, only by the user of the session
+    if let Some(resume_token) = query.get("resume") {
+        match state.sessions.resume(resume_token, &identity) {
+            Some(parked) => gateway.resume(resume_token.clone(), parked),
+            None => info!("Session not resumed: {}", resume_token),
+        }
+    }
 
-    // create services
-    let status_service = match  StatusService::new(state.message_provider.clone()).await {
-        Ok(status_service) => status_service,
+    // create services the client needs, after resuming for the session id of changed_by
+    let responders = match state.services.create(query.get("services").map(|services| services.as_str()), &gateway.client()) {
+        Ok(responders) => responders,
         Err(err) => {
-            println!("{}", err);
-            return Err(actix_web::error::ErrorServiceUnavailable(err));
+            warn!("{}", err);
+            return Err(actix_web::error::ErrorBadRequest(err));
         }
     };
-
-    let command_service = 
-        CommandService::new(
-            state.pubsub_address.clone(),
-            state.message_provider.clone()
-        ).await;
-    
-    // register services
-    let mut gateway = WebsocketGateway::new();
-    gateway.register(Box::new(status_service));
-    gateway.register(Box::new(command_service));
+    for responder in responders {
+        gateway.register(responder);
+    }
 
     let resp = ws::start(gateway, &req, stream);
     
-    println!("/ws accept: {:?}", resp);
+    debug!("/ws accept: {:?}", resp);
     resp
 }
 
@@ -423,15 +1169,44 @@ impl Image {
     }
 }
 
+#[derive(Clone)]
+pub struct ImageOptions {
+    scale_x: f64,
+    scale_y: f64,
+    // annotation topic
+    annotation: Option<String>,
+    // burn annotations into the image
+    overlay: bool,
+}
+
+impl ImageOptions {
+    pub fn from_params(params: &serde_json::Value) -> Self {
+        Self {
+            scale_x: params["scale_x"].as_f64().unwrap_or(1.0),
+            scale_y: params["scale_y"].as_f64().unwrap_or(1.0),
+            annotation: params["annotation"].as_str().filter(|topic| !topic.is_empty()).map(|topic| topic.to_owned()),
+            overlay: params["overlay"].as_bool().unwrap_or(false),
+        }
+    }
+}
+
 pub struct ImageService {
     message_provider: Arc<RwLock<PubsubMessageProvider>>,
+    workers: ImageWorkerPool,
+    // shared by every connection
+    cache: Arc<ImageCache>,
     spawn_handle: HashMap<String, actix::SpawnHandle>,
+    // StreamImage requests by client_id, start_time follows the sent frames
+    streaming: Arc<std::sync::Mutex<HashMap<String, serde_json::Value>>>,
+    // live subscriptions of this connection by topic, with the request
+    subscriptions: HashMap<String, (actix::SpawnHandle, serde_json::Value)>,
 }
 
 impl ImageService {
     // Local dicrectory
     pub fn prepare_image_proto(resource_name: &str, timestamp: u64, scale_x: f64, scale_y: f64)
     -> Option<api::proto::primitives::Image> {
+        let resource_name = validate::path_component(resource_name).map_err(|err| warn!("{}", err)).ok()?;
         // FIXME!!!!!!
         let image = Image::new(format!(
             "./backend/assets/{}/{}.jpg",
@@ -456,64 +1231,267 @@ impl ImageService {
         Some(image_proto)
     }
 
-    pub fn prepare_image_proto_from_imagedata(
+    // Reject requests which would read unknown topics or allocate huge images
+    fn validate(&self, params: &serde_json::Value) -> Result<(), RequestError> {
+        let path = params["header"]["path"].as_str().unwrap_or("");
+        let message_provider = self.message_provider.read()?;
+        validate::topic(params, "resource", |topic| message_provider.has_topic(topic))?;
+        if !params["annotation"].as_str().unwrap_or("").is_empty() {
+            validate::topic(params, "annotation", |topic| message_provider.has_topic(topic))?;
+        }
+        validate::scale(params, "scale_x")?;
+        validate::scale(params, "scale_y")?;
+        validate::number(params, "max_rate", 0.0, 0.0, validate::MAX_RATE)?;
+        if path == "Image/Image" || path == "Image/Thumbnails" {
+            validate::range(params)?;
+        }
+        if path == "Image/Thumbnails" {
+            validate::count(params, "count", 16, validate::MAX_THUMBNAILS)?;
+            validate::count(params, "size", 160, validate::MAX_THUMBNAIL_SIZE)?;
+        }
+        Ok(())
+    }
+
+    pub fn load_image(
         message_provider: &Arc<RwLock<PubsubMessageProvider>>, 
         resource_name: &str,
-        timestamp: Option<u64>,
+        timestamp: Option<u64>)
+    -> Result<image::DynamicImage, RequestError>
+    {
+        let message = { 
+            message_provider.read()?.get(resource_name, timestamp)
+        };
+
+        message.ok_or_else(|| {
+            RequestError::NotFound(format!("Error: Not Found {} {:?}", resource_name, timestamp))
+        })
+        .and_then(|message| Self::decode_image(&message.data))
+    }
+
+    // PubsubMessage.data (Image proto) to image
+    pub fn decode_image(data: &[u8]) -> Result<image::DynamicImage, RequestError> {
+        let image: protobuf::ProtobufResult<api::proto::primitives::Image> = 
+            protobuf::Message::parse_from_bytes(data);
+        
+        image.map_err(RequestError::from).and_then(|image| {
+            if raw_image::is_raw(&image) {
+                return raw_image::decode(&image).map_err(RequestError::Decode);
+            }
+            image::io::Reader::new(std::io::Cursor::new(image.data)).with_guessed_format()
+            .map_err(|err| RequestError::Decode(err.to_string()))
+            .and_then(|reader| {
+                reader.decode().map_err(|_err| RequestError::Decode("decode error".to_string()))
+            })
+        })
+    }
+
+    pub fn encode_image(image: &image::DynamicImage) -> Result<api::proto::primitives::Image, RequestError> {
+        let mut bytes: Vec<u8> = Vec::new();
+        image.write_to(&mut bytes, image::ImageOutputFormat::Jpeg(70))
+            .map_err(|err| RequestError::Internal(err.to_string()))
+            .map(|_| {
+                let mut image_proto = api::proto::primitives::Image::new();                    
+                image_proto.set_data(bytes);
+                image_proto.set_mime_type("image/jpeg".into());
+                image_proto
+            })
+    }
+
+    // Resize, burn annotations and encode for the client
+    pub fn render_image(
+        image: image::DynamicImage,
         scale_x: f64,
-        scale_y: f64)
-    -> Option<api::proto::primitives::Image>
+        scale_y: f64,
+        overlay: Option<&api::proto::primitives::ImageAnnotations>)
+    -> Result<api::proto::primitives::Image, RequestError>
+    {
+        let width = (scale_x * image.width() as f64) as u32;
+        let height = (scale_y * image.height() as f64) as u32;
+        let image = image.resize(width, height, image::imageops::FilterType::Nearest);
+        let image = match overlay {
+            Some(annotations) => image_overlay::draw(image, annotations),
+            None => image,
+        };
+        Self::encode_image(&image)
+    }
+
+    // Pick up to `count` recorded timestamps, evenly spaced in time over [start_time, end_time]
+    pub fn sample_timestamps(timestamps: &[u64], start_time: u64, end_time: u64, count: usize) -> Vec<u64> {
+        if timestamps.is_empty() || count == 0 || end_time < start_time {
+            return Vec::new();
+        }
+
+        let mut samples: Vec<u64> = Vec::new();
+        for i in 0..count {
+            let target = if count == 1 {
+                start_time + (end_time - start_time) / 2
+            } else {
+                start_time + ((end_time - start_time) as u128 * i as u128 / (count - 1) as u128) as u64
+            };
+            // nearest recorded timestamp
+            let index = timestamps.partition_point(|&t| t < target);
+            let nearest = match (index.checked_sub(1).map(|i| timestamps[i]), timestamps.get(index)) {
+                (Some(before), Some(&after)) => if target - before <= after - target { before } else { after },
+                (Some(before), None) => before,
+                (None, Some(&after)) => after,
+                (None, None) => continue,
+            };
+            if samples.last() != Some(&nearest) {
+                samples.push(nearest);
+            }
+        }
+        samples
+    }
+
+    // Small images for the sampled timestamps, or one tiled contact sheet
+    pub fn prepare_thumbnails(
+        message_provider: &Arc<RwLock<PubsubMessageProvider>>,
+        resource_name: &str,
+        timestamps: &[u64],
+        size: u32,
+        contact_sheet: bool)
+    -> Vec<api::proto::response::StreamSet>
     {
+        let thumbnails = timestamps.iter().filter_map(|&timestamp| {
+            Self::load_image(message_provider, resource_name, Some(timestamp))
+                .map(|image| (timestamp, image.thumbnail(size, size)))
+                .map_err(|err| warn!("{}", err))
+                .ok()
+        })
+        .collect::<Vec<_>>();
+
+        if !contact_sheet {
+            return thumbnails.into_iter().filter_map(|(timestamp, thumbnail)| {
+                Self::encode_image(&thumbnail).ok().map(|image_proto| Self::build_streamset(timestamp, image_proto))
+            })
+            .collect();
+        }
+
+        let (first_timestamp, first) = match thumbnails.first() {
+            Some((timestamp, thumbnail)) => (*timestamp, thumbnail),
+            None => return Vec::new(),
+        };
+        let columns = (thumbnails.len() as f64).sqrt().ceil() as u32;
+        let rows = (thumbnails.len() as u32).div_ceil(columns);
+        let (cell_width, cell_height) = (first.width(), first.height());
+
+        let mut sheet = image::RgbImage::new(columns * cell_width, rows * cell_height);
+        for (i, (_, thumbnail)) in thumbnails.iter().enumerate() {
+            let (column, row) = (i as u32 % columns, i as u32 / columns);
+            image::imageops::overlay(&mut sheet, &thumbnail.to_rgb8(), column * cell_width, row * cell_height);
+        }
+
+        Self::encode_image(&image::DynamicImage::ImageRgb8(sheet))
+            .map(|image_proto| vec![Self::build_streamset(first_timestamp, image_proto)])
+            .unwrap_or_default()
+    }
 
+    // One frame, annotations are burnt in with `overlay`
+    pub fn render_frame(
+        message_provider: &Arc<RwLock<PubsubMessageProvider>>,
+        resource_name: &str,
+        timestamp: Option<u64>,
+        options: &ImageOptions)
+    -> Result<api::proto::primitives::Image, RequestError>
+    {
+        let _timer = metrics().transcode_duration.start_timer();
+        let overlay = options.annotation.as_ref().filter(|_| options.overlay)
+            .and_then(|topic| Self::get_annotations(message_provider, topic, timestamp))
+            .map(|annotations| image_overlay::scale(&annotations, options.scale_x, options.scale_y));
+        let image = Self::load_image(message_provider, resource_name, timestamp)?;
+        Self::render_image(image, options.scale_x, options.scale_y, overlay.as_ref())
+    }
+
+    pub fn get_annotations(
+        message_provider: &Arc<RwLock<PubsubMessageProvider>>,
+        topic: &str,
+        timestamp: Option<u64>)
+    -> Option<api::proto::primitives::ImageAnnotations>
+    {
         let message = { 
-            message_provider.read().unwrap().get(resource_name, timestamp)
+            message_provider.read().ok()?.get(topic, timestamp)
         };
 
-        let image = 
-            message.ok_or_else(|| {
-                format!("Error: Not Found {} {:?}", resource_name, timestamp)
-            })
-            .and_then(|message| {
-                let image: protobuf::ProtobufResult<api::proto::primitives::Image> = 
-                    protobuf::Message::parse_from_bytes(&message.data);
-                image.map_err(|err| err.to_string())
-            });
-        
-        let dyn_image = 
-            image.and_then(|image| {
-                image::io::Reader::new(std::io::Cursor::new(image.data)).with_guessed_format()
-                .map_err(|err| { err.to_string() })
-                .and_then(|reader| {
-                    reader.decode().map_err(|_err| "decode error".to_string())
-                })
-            });
+        message.and_then(|message| {
+            protobuf::Message::parse_from_bytes(&message.data)
+                .map_err(|err| warn!("Error: annotations {} {:?} {}", topic, timestamp, err))
+                .ok()
+        })
+    }
 
-        let res = 
-            dyn_image.and_then(|image| {
-                let width = (scale_x * image.width() as f64) as u32;
-                let height = (scale_y * image.height() as f64) as u32;
-                let image = image.resize(width, height, image::imageops::FilterType::Nearest);
-                let mut bytes: Vec<u8> = Vec::new();
-                image.write_to(&mut bytes, image::ImageOutputFormat::Jpeg(70))
-                    .map_err(|err| err.to_string())
-                    .and_then(|_| {
-                        let mut image_proto = api::proto::primitives::Image::new();                    
-                        image_proto.set_data(bytes);
-                        image_proto.set_mime_type("image/jpeg".into());
-                        Ok(image_proto)        
-                    })               
-            });
+    pub fn prepare_streamset(
+        message_provider: &Arc<RwLock<PubsubMessageProvider>>,
+        cache: &ImageCache,
+        resource_name: &str,
+        timestamp: u64,
+        options: &ImageOptions)
+    -> Option<api::proto::response::StreamSet>
+    {
+        // annotations follow the resized image coordinates
+        let annotations = options.annotation.as_ref().and_then(|topic| {
+            Self::get_annotations(message_provider, topic, Some(timestamp))
+        })
+        .map(|annotations| image_overlay::scale(&annotations, options.scale_x, options.scale_y));
 
-        
-        match res {
-            Ok(image_proto) => Some(image_proto),
-            Err(err) => {
-                println!("{}", err);
-                None
+        let message = message_provider.read().ok()?.get(resource_name, Some(timestamp));
+        let message = match message {
+            Some(message) => message,
+            None => {
+                warn!("Error: Not Found {} {}", resource_name, timestamp);
+                return None;
             }
+        };
+
+        let overlay = annotations.as_ref().filter(|_| options.overlay);
+        let key = image_cache::key(resource_name, timestamp, &message.data, options.scale_x, options.scale_y, overlay);
+        let image_proto = match cache.get(&key) {
+            Some(image_proto) => image_proto,
+            None => {
+                let timer = metrics().transcode_duration.start_timer();
+                let image_proto = Self::decode_image(&message.data)
+                    .and_then(|image| Self::render_image(image, options.scale_x, options.scale_y, overlay))
+                    .map_err(|err| warn!(trace_id = %message.trace_id, "{}", err))
+                    .ok()?;
+                timer.observe_duration();
+                cache.insert(key, image_proto.clone());
+                image_proto
+            }
+        };
+
+        match annotations {
+            Some(annotations) if !options.overlay => Some(Self::build_annotated_streamset(timestamp, image_proto, annotations)),
+            _ => Some(Self::build_streamset(timestamp, image_proto)),
         }
     }
 
+    // Live frame: the image from the message, the latest annotations
+    pub fn prepare_live_streamset(
+        message_provider: &Arc<RwLock<PubsubMessageProvider>>,
+        message: &pubsub::proto::pubsub::PubsubMessage,
+        options: &ImageOptions)
+    -> Option<api::proto::response::StreamSet>
+    {
+        let annotations = options.annotation.as_ref().and_then(|topic| {
+            Self::get_annotations(message_provider, topic, None)
+        })
+        .map(|annotations| image_overlay::scale(&annotations, options.scale_x, options.scale_y));
+
+        let overlay = annotations.as_ref().filter(|_| options.overlay);
+        let timer = metrics().transcode_duration.start_timer();
+        let image_proto = Self::decode_image(&message.data)
+            .and_then(|image| Self::render_image(image, options.scale_x, options.scale_y, overlay))
+            .map_err(|err| warn!(trace_id = %message.trace_id, "{}", err))
+            .ok()?;
+        timer.observe_duration();
+
+        let mut streamset = match annotations {
+            Some(annotations) if !options.overlay => Self::build_annotated_streamset(message.timestamp, image_proto, annotations),
+            _ => Self::build_streamset(message.timestamp, image_proto),
+        };
+        streamset.set_trace_id(message.trace_id.clone());
+        Some(streamset)
+    }
+
     pub fn build_streamset(
         timestamp: u64,
         image_proto: api::proto::primitives::Image)
@@ -522,7 +1500,23 @@ impl ImageService {
         let mut streamset = api::proto::response::StreamSet::new();
         streamset.set_timestamp(timestamp);
         streamset.set_image(image_proto);
-        return streamset;
+        streamset
+    }
+
+    pub fn build_annotated_streamset(
+        timestamp: u64,
+        image_proto: api::proto::primitives::Image,
+        annotations: api::proto::primitives::ImageAnnotations)
+     -> api::proto::response::StreamSet
+    {
+        let mut annotated_image = api::proto::response::AnnotatedImage::new();
+        annotated_image.set_image(image_proto);
+        annotated_image.set_annotations(annotations);
+
+        let mut streamset = api::proto::response::StreamSet::new();
+        streamset.set_timestamp(timestamp);
+        streamset.set_annotated_image(annotated_image);
+        streamset
     }
 }
 
@@ -532,40 +1526,74 @@ impl WebsocketResponder for ImageService {
         "Image".into()
     }
 
+    fn priority(&self) -> Priority {
+        Priority::Low
+    }
+
     fn execute(
         &mut self,
         params: &serde_json::Value,
         ctx: &mut ws::WebsocketContext<WebsocketGateway>,
-    ) {
-        let scale_x: f64 = params["scale_x"].as_f64().unwrap_or(1.0);
-        let scale_y: f64 = params["scale_y"].as_f64().unwrap_or(1.0);
+    ) -> Result<(), RequestError> {
+        let path = params["header"]["path"].as_str().unwrap_or("");
+        if path != "Image/StopStreamImage" {
+            self.validate(params)?;
+        }
+
+        let options = ImageOptions::from_params(params);
         let start_time: u64 = params["start_time"].as_u64().unwrap_or(0);
         let end_time: u64 = params["end_time"].as_u64().unwrap_or(0);
         let resource_name = params["resource"].as_str().unwrap_or("").to_owned();
-        /*  
-        println!(
+        debug!(
             "resource:{} time:{}, {} scale:{}, {}",
-            &resource_name, start_time, end_time, scale_x, scale_y
+            &resource_name, start_time, end_time, options.scale_x, options.scale_y
         );
-        */
         match params["header"]["path"].as_str() {
             Some("Image/Image") => {
-                let mut items = Vec::new();
+                let timestamps = {
+                    self.message_provider.read()?.collect_timestamps(&resource_name, start_time, end_time)
+                };
+                validate::item_count(timestamps.len(), validate::MAX_IMAGES)?;
+
+                // decoded and encoded chunk by chunk on the worker pool, as the client grants credits
+                let items = {
+                    let message_provider = self.message_provider.clone();
+                    let cache = self.cache.clone();
+                    let resource_name = resource_name.clone();
+                    timestamps.into_iter().filter_map(move |timestamp| {
+                        trace!("timestamp: {}", timestamp);
+                        Self::prepare_streamset(&message_provider, &cache, &resource_name, timestamp, &options)
+                    })
+                };
+                ws_response_stream!("Image/Image", &resource_name, start_time, end_time, items).send(ctx);
+            }
+            Some("Image/Thumbnails") => {
+                let count = params["count"].as_u64().unwrap_or(16) as usize;
+                let size = params["size"].as_u64().unwrap_or(160) as u32;
+                let contact_sheet = params["contact_sheet"].as_bool().unwrap_or(false);
 
                 let timestamps = {
-                    self.message_provider.read().unwrap().collect_timestamps(&resource_name, start_time, end_time)
+                    let timestamps = self.message_provider.read()?.collect_timestamps(&resource_name, start_time, end_time);
+                    Self::sample_timestamps(&timestamps, start_time, end_time, count)
                 };
 
-                for timestamp in timestamps{
-                    println!("timestamp: {}", timestamp);
-                    //if let Some(image_proto) = Self::prepare_image_proto(&resource_name, timestamp, scale_x, scale_y) {
-                    if let Some(image_proto) 
-                        = Self::prepare_image_proto_from_imagedata(&self.message_provider, &resource_name, Some(timestamp), scale_x, scale_y) {
-                        items.push(Self::build_streamset(timestamp,image_proto));
+                let task = {
+                    let recipient = ctx.address().recipient();
+                    let message_provider = self.message_provider.clone();
+                    let workers = self.workers.clone();
+                    async move {
+                        let job = {
+                            let resource_name = resource_name.clone();
+                            move || Self::prepare_thumbnails(&message_provider, &resource_name, &timestamps, size, contact_sheet)
+                        };
+                        let items = workers.run(job).await.unwrap_or_default();
+
+                        let stream = ws_response_stream!("Image/Thumbnails", &resource_name, start_time, end_time, items);
+                        let _ = recipient.do_send(SendStream { stream });
                     }
-                }
-                
-                ws_response_stream!("Image/Image", &resource_name, start_time, end_time, items).send(ctx);
+                };
+                let task = actix::fut::wrap_future(task.in_current_span());
+                ctx.spawn(task);
             }
             Some("Image/StopStreamImage") => {
                 let _ = params["client_id"].as_str().map(|client_id| {
@@ -573,22 +1601,23 @@ impl WebsocketResponder for ImageService {
                         ctx.cancel_future(*handle);
                     });
                     self.spawn_handle.remove(client_id);
+                    if let Ok(mut streaming) = self.streaming.lock() {
+                        streaming.remove(client_id);
+                    }
+                    ctx.address().do_send(EndLive { key: format!("Image/StreamImage {}", client_id) });
                 });
             }
             Some("Image/StreamImage") => {
-                let client_id = if let Some(client_id) = params["client_id"].as_str(){
-                    client_id
-                } else{
-                    return;
-                };
+                let client_id = params["client_id"].as_str()
+                    .ok_or_else(|| RequestError::InvalidRequest("Error: client_id is required".into()))?;
                 
                 if let Some(_) = self.spawn_handle.get(client_id) {
-                    println!("Already streaming.");
-                    return;
+                    debug!("Already streaming.");
+                    return Ok(());
                 }
 
                 let mut timestamps = {
-                    self.message_provider.read().unwrap().collect_all_timestamps(&resource_name)
+                    self.message_provider.read()?.collect_all_timestamps(&resource_name)
                 };
 
                 timestamps.reverse();
@@ -597,15 +1626,23 @@ impl WebsocketResponder for ImageService {
 
                 // Give up to use run_interval, Because dev mode is too slow. 
                 // run_interval function takes time more than 33 msec, then other actor future is not assigned to call
+                self.streaming.lock()?.insert(client_id.into(), params.clone());
+
                 let task = {
-                    let recipient = ctx.address().recipient();
+                    let address = ctx.address();
                     let message_provider = self.message_provider.clone();
+                    let workers = self.workers.clone();
+                    let streaming = self.streaming.clone();
+                    let cache = self.cache.clone();
+                    let client_id = client_id.to_string();
+                    let key = format!("Image/StreamImage {}", client_id);
                     let mut start_time = std::time::Instant::now();
                     async move {
+                        let mut in_flight: Option<tokio::task::JoinHandle<()>> = None;
                         loop{
                             let elapsed = start_time.elapsed();
                             let dur = elapsed.as_millis();
-                            println!("Duration: {:?}", dur);
+                            trace!("Duration: {:?}", dur);
                             // 1msec: for switting to other task
                             let mut sleep_time = 1; 
                             if dur < 33 {
@@ -615,72 +1652,372 @@ impl WebsocketResponder for ImageService {
                             
                             start_time = std::time::Instant::now();
                             
-                            let mut items = Vec::new();
                             let timestamp = if let Some(timestamp) = timestamps.pop() {
                                 timestamp
                             } else {
                                 break;
                             };                     
 
-                            if let Some(image_proto) = 
-                                Self::prepare_image_proto_from_imagedata(&message_provider, &resource_name, Some(timestamp), scale_x, scale_y){
-                                items.push(Self::build_streamset(timestamp, image_proto));
+                            // the previous frame is still encoded: drop the frame rather than queue it
+                            if in_flight.as_ref().is_some_and(|job| !job.is_finished()) {
+                                debug!("Drop frame: {}", timestamp);
+                                continue;
                             }
 
-                            let response = ws_response_stream!("Image/StreamImage", &resource_name, timestamp, timestamp, items);
-                            let _ = recipient.do_send(SendWSResponse { response });            
+                            let job = {
+                                let address = address.clone();
+                                let key = key.clone();
+                                let message_provider = message_provider.clone();
+                                let cache = cache.clone();
+                                let resource_name = resource_name.clone();
+                                let options = options.clone();
+                                let streaming = streaming.clone();
+                                let client_id = client_id.clone();
+                                move || {
+                                    // dropped by the gateway when the client is behind
+                                    if let Some(item) =
+                                        Self::prepare_streamset(&message_provider, &cache, &resource_name, timestamp, &options) {
+                                        let path = "Image/StreamImage".to_string();
+                                        address.do_send(SendLive { key, path, subject: resource_name, item });
+                                    }
+
+                                    // a resumed session continues after this frame
+                                    if let Ok(mut streaming) = streaming.lock() {
+                                        if let Some(params) = streaming.get_mut(&client_id) {
+                                            params["start_time"] = json!(timestamp + 1);
+                                        }
+                                    }
+                                }
+                            };
+
+                            in_flight = workers.try_run(job);
+                            if in_flight.is_none() {
+                                debug!("Drop frame: {} (workers busy)", timestamp);
+                            }
                         }
+                        address.do_send(EndLive { key });
                     }
                 };
-                let task = actix::fut::wrap_future(task);
+                let task = actix::fut::wrap_future(task.in_current_span());
                 let handle = ctx.spawn(task);
                 self.spawn_handle.insert(client_id.into(), handle);
             }
-            _ => (),
+            Some("Image/Subscribe") => {
+                let mut forward_options = live_forward::ForwardOptions::from_params(params);
+                // an old frame is useless, skip to the newest by default
+                forward_options.latest_only = params["latest_only"].as_bool().unwrap_or(true);
+
+                let receiver = self.message_provider.read()?.subscribe(&resource_name)
+                    .ok_or_else(|| RequestError::NotFound(format!("Error: Not Found {}", resource_name)))?;
+
+                // subscribing again replaces the rate and scale
+                if let Some((handle, _)) = self.subscriptions.remove(&resource_name) {
+                    ctx.cancel_future(handle);
+                }
+
+                let task = {
+                    let address = ctx.address();
+                    let message_provider = self.message_provider.clone();
+                    let workers = self.workers.clone();
+                    let resource_name = resource_name.clone();
+                    let mut in_flight: Option<tokio::task::JoinHandle<()>> = None;
+                    live_forward::forward(receiver, forward_options, move |message| {
+                        // the previous frame is still encoded: drop the frame rather than queue it
+                        if in_flight.as_ref().is_some_and(|job| !job.is_finished()) {
+                            return;
+                        }
+
+                        let job = {
+                            let address = address.clone();
+                            let message_provider = message_provider.clone();
+                            let resource_name = resource_name.clone();
+                            let options = options.clone();
+                            move || {
+                                // dropped by the gateway when the client is behind
+                                if let Some(item) = Self::prepare_live_streamset(&message_provider, &message, &options) {
+                                    let key = format!("Image/Live {}", resource_name);
+                                    let path = "Image/Live".to_string();
+                                    address.do_send(SendLive { key, path, subject: resource_name, item });
+                                }
+                            }
+                        };
+                        in_flight = workers.try_run(job);
+                    })
+                };
+                let task = actix::fut::wrap_future(task.in_current_span());
+                let handle = ctx.spawn(task);
+                self.subscriptions.insert(resource_name, (handle, params.clone()));
+            }
+            Some("Image/Unsubscribe") => {
+                if let Some((handle, _)) = self.subscriptions.remove(&resource_name) {
+                    ctx.cancel_future(handle);
+                }
+                ctx.address().do_send(EndLive { key: format!("Image/Live {}", resource_name) });
+            }
+            path => return Err(RequestError::unknown_path(path.unwrap_or(""))),
+        }
+        Ok(())
+    }
+
+    fn close(
+        &mut self,
+        ctx: &mut ws::WebsocketContext<WebsocketGateway>
+    ) {
+        let subscriptions = self.subscriptions.drain().map(|(_, (handle, _))| handle);
+        for handle in subscriptions.chain(self.spawn_handle.drain().map(|(_, handle)| handle)) {
+            ctx.cancel_future(handle);
+        }
+    }
+
+    // streams continue from the last sent frame, subscriptions start again
+    fn suspend(&mut self) -> Option<serde_json::Value> {
+        let streams = self.streaming.lock().ok()?.drain().map(|(_, params)| params).collect::<Vec<_>>();
+        let subscriptions = self.subscriptions.values().map(|(_, params)| params.clone()).collect::<Vec<_>>();
+        (!streams.is_empty() || !subscriptions.is_empty()).then(|| json!({
+            "streams": streams,
+            "subscriptions": subscriptions,
+        }))
+    }
+
+    fn resume(
+        &mut self,
+        state: serde_json::Value,
+        ctx: &mut ws::WebsocketContext<WebsocketGateway>
+    ) {
+        let requests = state["streams"].as_array().into_iter().chain(state["subscriptions"].as_array()).flatten();
+        for params in requests {
+            if let Err(err) = self.execute(params, ctx) {
+                warn!("Resume {}", err);
+            }
         }
     }
 }
 
 
-async fn image_service(req: HttpRequest, stream: web::Payload, state: web::Data<AppState>) -> Result<HttpResponse, Error> {
-    // create services
-    let image_service = ImageService{
-        message_provider: state.message_provider.clone(), 
-        spawn_handle: HashMap::new()
-     };
-    
-    // register services
-    let mut gateway = WebsocketGateway::new();
-    gateway.register(Box::new(image_service));
+// GET /record: record state and who changed it
+async fn record(req: HttpRequest, state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    state.auth.authorize(&req, Role::Viewer)?;
+    let record = state.message_provider.read().map_err(RequestError::from)?.record_control();
+    let history = record.history().iter().map(|state| state.to_json()).collect::<Vec<_>>();
+    Ok(HttpResponse::Ok().json(json!({ "state": record.state().to_json(), "history": history })))
+}
 
-    let resp = ws::start(gateway, &req, stream);
+// GET /sessions: active websocket clients
+async fn sessions(req: HttpRequest, state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    state.auth.authorize(&req, Role::Operator)?;
+    let sessions = state.sessions.list().iter().map(|info| info.to_json()).collect::<Vec<_>>();
+    Ok(HttpResponse::Ok().json(json!({ "sessions": sessions })))
+}
 
-    println!("ImageService connect request: {:?}", req);
-    resp
+// GET /healthz: the process is alive
+async fn healthz() -> HttpResponse {
+    HttpResponse::Ok().json(json!({ "status": "ok" }))
+}
+
+// GET /readyz: 503 until the broker is reachable, every topic subscribed and the recording writable
+async fn readyz(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let readiness = state.message_provider.read().map_err(RequestError::from)?.readiness();
+    let mut response = if readiness.is_ready() {
+        HttpResponse::Ok()
+    } else {
+        HttpResponse::ServiceUnavailable()
+    };
+    Ok(response.json(readiness.to_json()))
+}
+
+// GET /metrics: Prometheus text format
+async fn metrics_text(req: HttpRequest, state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    state.auth.authorize_metrics(&req)?;
+    let text = metrics::render().map_err(actix_web::error::ErrorInternalServerError)?;
+    Ok(HttpResponse::Ok().content_type("text/plain; version=0.0.4").body(text))
+}
+
+// POST /auth/token?role=viewer: signed session token for the caller, optionally with a lower role
+async fn auth_token(
+    req: HttpRequest,
+    query: web::Query<HashMap<String, String>>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let mut identity = state.auth.authorize(&req, Role::Viewer)?;
+    if let Some(role) = query.get("role") {
+        match Role::parse(role) {
+            Some(role) if role <= identity.role => identity.role = role,
+            _ => return Err(actix_web::error::ErrorBadRequest(format!("Error: role {}", role))),
+        }
+    }
+
+    match state.auth.issue(&identity) {
+        Some(token) => Ok(HttpResponse::Ok().json(json!({ "token": token, "identity": identity.to_json() }))),
+        None => Err(actix_web::error::ErrorNotFound("session tokens are not configured")),
+    }
+}
+
+// GET /export/{topic}?start_time=&end_time=&scale=
+async fn export(
+    req: HttpRequest,
+    query: web::Query<HashMap<String, String>>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    state.auth.authorize(&req, Role::Viewer)?;
+    let topic = format!("/{}", req.match_info().query("topic"));
+    let params = rest_api::query_params(&query)?;
+    // no open ended exports, the range is bounded like Image/Image
+    if params["end_time"].is_null() {
+        return Err(RequestError::InvalidRequest("Error: end_time is required".into()).into());
+    }
+    let (start_time, end_time) = validate::range(&params)?;
+    let scale = validate::scale(&params, "scale")?;
+    let recording = {
+        let message_provider = state.message_provider.read().map_err(RequestError::from)?;
+        if !message_provider.has_topic(&topic) {
+            return Err(RequestError::NotFound(format!("Error: unknown topic {:?}", topic)).into());
+        }
+        message_provider.recording().clone()
+    };
+    let timestamps = recording.collect_timestamps(&topic, start_time, end_time);
+    validate::item_count(timestamps.len(), validate::MAX_IMAGES)?;
+    let job = {
+        let topic = topic.clone();
+        move || {
+            let mut bytes = Vec::new();
+            video_export::export_gif(&recording, &topic, &timestamps, scale, &mut bytes)
+                .map(|_| bytes)
+        }
+    };
+
+    let bytes = match state.image_workers.run(job).await {
+        Some(Ok(bytes)) => bytes,
+        Some(Err(err)) => return Err(actix_web::error::ErrorNotFound(err)),
+        None => return Err(actix_web::error::ErrorInternalServerError("export failed")),
+    };
+
+    let file_name = format!("{}_{}_{}.gif", topic.trim_start_matches('/').replace("/", "_"), start_time, end_time);
+    Ok(HttpResponse::Ok()
+        .content_type("image/gif")
+        .insert_header(("Content-Disposition", format!("attachment; filename=\"{}\"", file_name)))
+        .body(bytes))
 }
 
 pub struct AppState {
-    pubsub_address: String,
     message_provider: Arc<RwLock<PubsubMessageProvider>>,
+    image_workers: ImageWorkerPool,
+    services: ServiceRegistry,
+    sessions: Arc<SessionRegistry>,
+    events: Arc<EventBus>,
+    auth: Auth,
+    schemas: Arc<SchemaRegistry>,
 }
 
 impl AppState {
-    pub async fn new(pubsub_address: String) -> Result<Self, String> {
+    pub async fn new(pubsub_address: String, tls: &TlsConfig) -> Result<Self, String> {
+        let schemas = SchemaRegistry::load("./schemas")?;
+        let mappings = VizMappings::load("./schemas/visualizations.json", &schemas)?;
+        let auth = Auth::load("./config/auth.json")?;
+
+        // subscribe every topic which has a schema
+        let topics = schemas.topics().into_iter().map(|(topic, _)| topic).collect::<Vec<_>>();
+        // broker metrics are labelled by these topics only
+        pubsub::metrics::metrics().add_topics(topics.clone());
+        let events = Arc::new(EventBus::default());
         let message_provider = PubsubMessageProvider::new(
             pubsub_address.clone(),
-            vec![
-                "/status/status".into(),
-                "/status/image".into(),
-            ])
-            .await.unwrap();
+            topics,
+            events.clone(),
+            tls.pubsub_client.as_ref())
+            .await?;
+        Self::publish_events(&message_provider, &events);
+        let record = message_provider.record_control();
+        let recording = message_provider.recording().clone();
 
 
+        let image_workers = ImageWorkerPool::new(
+            std::thread::available_parallelism().map(|n| n.get()).unwrap_or(4)
+        );
+
+        let message_provider = Arc::new(RwLock::new(message_provider));
+        let schemas = Arc::new(schemas);
+        let services = Self::register_services(
+            &message_provider, record, recording, &image_workers, schemas.clone(), Arc::new(mappings));
+        let sessions = Arc::new(SessionRegistry::default());
+        tokio::spawn(session::sweep(sessions.clone()));
+
         Ok(Self{
-            pubsub_address,
-            message_provider: Arc::new(RwLock::new(message_provider)),
+            message_provider,
+            image_workers,
+            services,
+            sessions,
+            events,
+            auth,
+            schemas,
         })
     }
 
+    // Events which are not published by the message provider itself
+    fn publish_events(message_provider: &PubsubMessageProvider, events: &Arc<EventBus>) {
+        let mut changes = message_provider.record_control().subscribe();
+        tokio::spawn({
+            let events = events.clone();
+            async move {
+                loop {
+                    match changes.recv().await {
+                        Ok(state) => events.publish(event_bus::record_changed(&state)),
+                        Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                    }
+                }
+            }
+        });
+
+        tokio::spawn(disk_space::watch(events.clone(), pubsub_message_provider::RECORD_ROOT.to_string()));
+    }
+
+    fn register_services(
+        message_provider: &Arc<RwLock<PubsubMessageProvider>>,
+        record: Arc<RecordControl>,
+        recording: Recording,
+        image_workers: &ImageWorkerPool,
+        schemas: Arc<SchemaRegistry>,
+        mappings: Arc<VizMappings>,
+    ) -> ServiceRegistry {
+        let mut services = ServiceRegistry::default();
+
+        for scope in mappings.scopes() {
+            let (message_provider, schemas, mappings) = (message_provider.clone(), schemas.clone(), mappings.clone());
+            services.register(&scope.clone(), move |_| {
+                Box::new(StatusService::new(scope.clone(), message_provider.clone(), schemas.clone(), mappings.clone()))
+            });
+        }
+
+        services.register("Command", {
+            move |client| Box::new(CommandService::new(record.clone(), client.to_string()))
+        });
+
+        services.register("Topic", {
+            let (message_provider, schemas) = (message_provider.clone(), schemas.clone());
+            move |_| Box::new(TopicService::new(message_provider.clone(), schemas.clone()))
+        });
+
+        services.register("Series", {
+            let (schemas, workers) = (schemas.clone(), image_workers.clone());
+            move |_| Box::new(SeriesService::new(recording.clone(), schemas.clone(), workers.clone()))
+        });
+
+        services.register("Image", {
+            let (message_provider, workers) = (message_provider.clone(), image_workers.clone());
+            let cache = Arc::new(ImageCache::new(image_cache::IMAGE_CACHE_SIZE));
+            move |_| Box::new(ImageService {
+                message_provider: message_provider.clone(),
+                workers: workers.clone(),
+                cache: cache.clone(),
+                spawn_handle: HashMap::new(),
+                streaming: Arc::new(std::sync::Mutex::new(HashMap::new())),
+                subscriptions: HashMap::new(),
+            })
+        });
+
+        services
+    }
+
     pub async fn destroy(&self){
         self.message_provider.write().unwrap().close().await;
     }
@@ -691,29 +2028,54 @@ async fn main() -> std::io::Result<()> {
     const SERVER_ADDRESS: &str = "127.0.0.1:4567";
     const PUBSUB_ADDRESS: &str = "[::1]:50051";
 
+    logging::init(&LogConfig::from_env());
+
+    // Subcommand: export recorded images without starting the server
+    let args = std::env::args().collect::<Vec<_>>();
+    if args.get(1).map(|arg| arg.as_str()) == Some("export") {
+        let recording = Recording::new(pubsub_message_provider::RECORD_ROOT);
+        return video_export::export_command(&recording, &args[2..])
+            .map_err(std::io::Error::other);
+    }
+
+    let tls = TlsConfig::load("./config/tls.json").map_err(std::io::Error::other)?;
+
     // PubSub Server
-    pubsub_service::Server::start(PUBSUB_ADDRESS);
+    match &tls.pubsub {
+        Some(pubsub_tls) => pubsub_service::Server::start_tls(PUBSUB_ADDRESS, pubsub_tls).map_err(std::io::Error::other)?,
+        None => pubsub_service::Server::start(PUBSUB_ADDRESS).map_err(std::io::Error::other)?,
+    }
     // nop to send response
     tokio::time::sleep(std::time::Duration::from_millis(100)).await;
 
     let state = 
-        web::Data::new(AppState::new(format!("{}{}", "http://", PUBSUB_ADDRESS)).await.unwrap());
+        web::Data::new(AppState::new(format!("{}{}", tls.pubsub_scheme(), PUBSUB_ADDRESS), &tls).await.map_err(std::io::Error::other)?);
+    let http_tls = tls.http_config().map_err(std::io::Error::other)?;
 
 
     // Start server
-    let _res = HttpServer::new({
+    let server = HttpServer::new({
         let state = state.clone();
         move || {
             App::new()
                 .app_data(state.clone())
                 .route("/ws", web::get().to(ws))
-                .route("/image_service", web::get().to(image_service))
-                .service(actix_files::Files::new("/", "./web/dist").show_files_listing())
+                .route("/export/{topic:.*}", web::get().to(export))
+                .route("/sessions", web::get().to(sessions))
+                .route("/record", web::get().to(record))
+                .route("/metrics", web::get().to(metrics_text))
+                .route("/healthz", web::get().to(healthz))
+                .route("/readyz", web::get().to(readyz))
+                .configure(rest_api::configure)
+                .route("/auth/token", web::post().to(auth_token))
+                .service(actix_files::Files::new("/", "./web/dist").index_file("index.html"))
         }
-    })
-    .bind(SERVER_ADDRESS)?
-    .run()
-    .await;
+    });
+    let server = match http_tls {
+        Some(config) => server.bind_rustls(SERVER_ADDRESS, config)?,
+        None => server.bind(SERVER_ADDRESS)?,
+    };
+    let _res = server.run().await;
 
     state.destroy().await;
 
@@ -0,0 +1,93 @@
+use prometheus::{Encoder, Histogram, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts, TextEncoder};
+use pubsub::metrics::register;
+use std::sync::OnceLock;
+
+// Server metrics, GET /metrics also has the pubsub_* metrics of the broker
+pub struct Metrics {
+    pub sessions: IntGauge,
+    // time of the request on the actor, background work of the request is not included
+    pub request_duration: HistogramVec,
+    pub request_errors: IntCounterVec,
+    pub record_write_duration: Histogram,
+    pub disk_bytes: IntGaugeVec,
+    // decode, resize, overlay and encode of one image
+    pub transcode_duration: Histogram,
+    pub image_cache: IntCounterVec,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        Self {
+            sessions: register(IntGauge::new("websocket_sessions", "Connected websocket clients")),
+            request_duration: register(HistogramVec::new(
+                HistogramOpts::new("websocket_request_duration_seconds", "Time to handle a websocket request"),
+                &["path"],
+            )),
+            request_errors: register(IntCounterVec::new(
+                Opts::new("websocket_request_errors_total", "Failed websocket requests"),
+                &["code"],
+            )),
+            record_write_duration: register(Histogram::with_opts(HistogramOpts::new(
+                "recorder_write_duration_seconds",
+                "Time to write one message to the recording",
+            ))),
+            disk_bytes: register(IntGaugeVec::new(
+                Opts::new("recorder_disk_bytes", "Space of the recording disk"),
+                &["kind"],
+            )),
+            transcode_duration: register(Histogram::with_opts(
+                HistogramOpts::new("image_transcode_duration_seconds", "Time to prepare one image for the client")
+                    .buckets(vec![0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0]),
+            )),
+            image_cache: register(IntCounterVec::new(
+                Opts::new("image_cache_requests_total", "Replay image lookups in the image cache"),
+                &["result"],
+            )),
+        }
+    }
+
+    pub fn set_disk_space(&self, available: u64, total: u64) {
+        self.disk_bytes.with_label_values(&["available"]).set(available as i64);
+        self.disk_bytes.with_label_values(&["total"]).set(total as i64);
+    }
+
+    pub fn image_cache_lookup(&self, hit: bool) {
+        let result = if hit { "hit" } else { "miss" };
+        self.image_cache.with_label_values(&[result]).inc();
+    }
+}
+
+pub fn metrics() -> &'static Metrics {
+    static METRICS: OnceLock<Metrics> = OnceLock::new();
+    METRICS.get_or_init(Metrics::new)
+}
+
+// Prometheus text format of every registered metric
+pub fn render() -> Result<String, String> {
+    // the broker may not have been used yet
+    pubsub::metrics::metrics();
+    metrics();
+
+    let mut buffer = Vec::new();
+    TextEncoder::new()
+        .encode(&prometheus::gather(), &mut buffer)
+        .map_err(|err| format!("Error: metrics {}", err))?;
+    String::from_utf8(buffer).map_err(|err| format!("Error: metrics {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        // the registry is shared by the tests, only this path is not touched by others
+        metrics().request_duration.with_label_values(&["Test/Render"]).observe(0.01);
+        metrics().image_cache_lookup(true);
+
+        let text = render().unwrap();
+        assert!(text.contains("# TYPE websocket_sessions gauge"));
+        assert!(text.contains("websocket_request_duration_seconds_count{path=\"Test/Render\"} 1"));
+        assert!(text.contains("image_cache_requests_total{result=\"hit\"}"));
+    }
+}
@@ -1,52 +1,159 @@
 use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::collections::HashMap;
 use std::io::{Read, Write};
+use tokio::sync::broadcast;
+use tracing::Instrument;
 use uuid::Uuid;
 use prost::Message as _;
 
 use pubsub::pubsub_service;
 use pubsub::proto::pubsub::PubsubMessage;
 
+use crate::event_bus::{self, EventBus};
+use crate::health::{self, Check, Readiness};
+use crate::metrics::metrics;
+use crate::record_control::RecordControl;
+use crate::validate;
+
+pub const RECORD_ROOT: &str = "/tmp/PubsubMessageProvider";
+// Live messages kept for slow subscribers, older ones are dropped
+const LIVE_BUFFER: usize = 64;
+
+// Recorded messages on disk: <root>/<topic>/<timestamp>
+#[derive(Clone)]
+pub struct Recording {
+    root: std::path::PathBuf,
+}
+
+impl Recording {
+    pub fn new(root: &str) -> Self {
+        Self {
+            root: std::path::PathBuf::from(root),
+        }
+    }
+
+    // None if the topic would name a directory outside of the root
+    fn topic_to_path(&self, topic: &str) -> Option<std::path::PathBuf> {
+        let mut path = self.root.clone();
+        let dic = topic.replace("/", "_");
+        path.push(validate::path_component(&dic).ok()?);
+        Some(path)
+    }
+
+    pub fn check_writable(&self) -> Result<(), String> {
+        health::check_writable(&self.root)
+    }
+
+    pub fn collect_timestamps(&self, topic:&str, start_time: u64, end_time: u64) -> Vec<u64> {
+        let timestamps = self.collect_all_timestamps(topic);
+        timestamps.into_iter().filter(|&t| start_time <= t && t <= end_time).collect::<Vec<_>>()
+    }
+
+
+    pub fn collect_all_timestamps(&self, topic: &str) -> Vec<u64> {
+        let mut timestamps= Vec::new();
+        let path = match self.topic_to_path(topic) {
+            Some(path) => path,
+            None => return timestamps,
+        };
+        let res = std::fs::read_dir(path);
+        if let Ok(readdir) = res {
+            for entry in readdir {
+                let _ = entry.map(|entry| {
+                    entry.path().file_name().map(|file_name| {
+                        file_name.to_str().map(|file_name| {
+                            file_name.parse::<u64>().ok().map(|num | {
+                                timestamps.push(num);
+                            })
+                        })
+                    })
+                });
+            }
+        }
+
+        timestamps.sort();
+        timestamps
+    }
+
+    pub fn read(&self, topic: &str, timestamp: u64) -> Option<PubsubMessage> {
+        let mut path = self.topic_to_path(topic)?;
+        path.push(std::path::Path::new(&timestamp.to_string()));
+        
+        match std::fs::File::open(path) {
+            Ok(mut file) => {
+                let mut buf = Vec::new();
+                let res = file.read_to_end(&mut buf);
+                res.ok().and_then(|_| {
+                    let buf = std::io::Cursor::new(buf);
+                    let res: Result<PubsubMessage, _> = prost::Message::decode(buf);
+                    res.ok()
+                })
+            }
+            Err(_e) => None,
+        }
+    }
+}
+
 pub struct PubsubMessageProvider {
     pubsub: pubsub_service::Client,
     messages: Arc<RwLock<HashMap<String, Option<Box<PubsubMessage>>>>>,
-    record: Arc<RwLock<bool>>,
-    record_root: std::path::PathBuf,
+    record: Arc<RecordControl>,
+    recording: Recording,
+    live: HashMap<String, broadcast::Sender<Arc<PubsubMessage>>>,
+    // subscription id by topic
+    subscriptions: HashMap<String, String>,
 }
 
 impl PubsubMessageProvider {
     pub async fn new(
         pubsub_address: String,
-        topics: Vec<String>
+        topics: Vec<String>,
+        events: Arc<EventBus>,
+        tls: Option<&pubsub_service::ClientTls>,
     ) -> Result<Self, String> {
-        let record = Arc::new(RwLock::new(true));
-        let record_root = std::path::PathBuf::from("/tmp/PubsubMessageProvider");
-        if !std::path::Path::new(&record_root).exists() {
-            let res = std::fs::create_dir_all(&record_root);
+        let record = Arc::new(RecordControl::new(true));
+        let recording = Recording::new(RECORD_ROOT);
+        if !recording.root.exists() {
+            let res = std::fs::create_dir_all(&recording.root);
             if res.is_err() {
-                return Err("PubsubMessageProvider: Fail to create /tmp/PubsubMessageProvider directory".to_string());
+                return Err(format!("PubsubMessageProvider: Fail to create {} directory", RECORD_ROOT));
             }
         }
 
-        let mut pubsub = match pubsub_service::Client::connect(pubsub_address).await {
+        let connection = match tls {
+            Some(tls) => pubsub_service::Client::connect_tls(pubsub_address, tls).await.map_err(|err| err.to_string()),
+            None => pubsub_service::Client::connect(pubsub_address).await.map_err(|err| err.to_string()),
+        };
+        let mut pubsub = match connection {
             Ok(client) => client,
             Err(err) => {
-                println!("{}", err);
+                tracing::error!("{}", err);
                 return Err("Fail to  connect pubusub server".into());
             }
         };
 
         // For live message
         let messages =  Arc::new(RwLock::new(HashMap::new()));
+        let mut live = HashMap::new();
+        // shared by the subscriptions, one event per connection change
+        let connected = Arc::new(AtomicBool::new(true));
+        let mut subscriptions = HashMap::new();
         for topic in &topics {
+            // fan out to websocket subscribers
+            let (sender, _) = broadcast::channel(LIVE_BUFFER);
+            live.insert(topic.clone(), sender.clone());
+
             // create entry
-            messages.write().unwrap().insert(topic.into(), None);
+            messages.write().map_err(|err| err.to_string())?.insert(topic.into(), None);
             // create record directory for topic
-            let mut record_path = Self::topic_to_path(&record_root, &*topic);
+            let mut record_path = recording
+                .topic_to_path(topic)
+                .ok_or_else(|| format!("PubsubMessageProvider: invalid topic {}", topic))?;
             if !std::path::Path::new(&record_path).exists() {
                 let res = std::fs::create_dir_all(&record_path);
                 if res.is_err() {
-                    return Err(format!("PubsubMessageProvider: Fail to create {} directory", record_path.to_str().unwrap()));
+                    return Err(format!("PubsubMessageProvider: Fail to create {} directory", record_path.display()));
                 }
             }
     
@@ -58,6 +165,7 @@ impl PubsubMessageProvider {
                 Ok(_) => (),
                 Err(e)=> return Err(format!("Error: create subscription {}", e))
             }
+            subscriptions.insert(topic.clone(), sub_id.clone());
 
             let _res = pubsub
                 .subscribe(&sub_id, {
@@ -66,15 +174,25 @@ impl PubsubMessageProvider {
                     // put dummy file_name for set_file_name
                     record_path.push("0");
                     let topic = topic.clone();
+                    let (events, connected) = (events.clone(), connected.clone());
     
                     move |msg| {
-                        let msg = if let Ok(message) = msg {
-                            message
-                        } else {
-                            return;
+                        let msg = match msg {
+                            Ok(message) => message,
+                            Err(status) => {
+                                if connected.swap(false, Ordering::SeqCst) {
+                                    tracing::warn!("Pubsub disconnected: {}", status);
+                                    events.publish(event_bus::pubsub_connection(false, status.message()));
+                                }
+                                return;
+                            }
                         };
+                        if !connected.swap(true, Ordering::SeqCst) {
+                            events.publish(event_bus::pubsub_connection(true, ""));
+                        }
                         
-                        if *record.read().unwrap() {
+                        if record.is_enabled() {
+                            let _timer = metrics().record_write_duration.start_timer();
                             record_path.set_file_name(&msg.timestamp.to_string());
 
                             let mut buf: Vec<u8> = Vec::new();
@@ -84,17 +202,31 @@ impl PubsubMessageProvider {
                                     let _ = file.write_all(&buf).map(|_| file.flush());
                                 });
                             }).map_err(|err| {
-                                println!("message encodeing is fail");
+                                tracing::error!(trace_id = %msg.trace_id, "message encodeing is fail");
                                 err
                             });
                         }
                         
-                        println!("Record timestamp: {}", msg.timestamp);
-                        let mut db = messages.write().unwrap();
-                        *db.get_mut(&topic).unwrap() = Some(Box::new(msg));
+                        tracing::trace!(trace_id = %msg.trace_id, "Record timestamp: {}", msg.timestamp);
+                        // no receiver is not an error
+                        let _ = sender.send(Arc::new(msg.clone()));
+                        let mut db = match messages.write() {
+                            Ok(db) => db,
+                            Err(err) => {
+                                tracing::error!("{} {}", topic, err);
+                                return;
+                            }
+                        };
+                        if let Some(entry) = db.get_mut(&topic) {
+                            if entry.is_none() {
+                                events.publish(event_bus::topic_added(&topic));
+                            }
+                            *entry = Some(Box::new(msg));
+                        }
                         
                     }
                 })
+                .instrument(tracing::info_span!("subscription", topic = %topic, id = %sub_id))
                 .await;
         }    
 
@@ -102,7 +234,9 @@ impl PubsubMessageProvider {
             pubsub,
             messages,
             record,
-            record_root,
+            recording,
+            live,
+            subscriptions,
         })
     }
 
@@ -110,50 +244,73 @@ impl PubsubMessageProvider {
         let _res = self.pubsub.close().await;
     }
 
-    fn topic_to_path(root: &std::path::PathBuf, topic: &str) -> std::path::PathBuf{
-        let mut path = root.clone();
-        let dic = topic.replace("/", "_");
-        path.push(dic);
-        path
+    // broker reachable, every topic streaming, recording writable
+    pub fn readiness(&self) -> Readiness {
+        let pubsub = if self.pubsub.is_connected() {
+            Ok(())
+        } else {
+            Err("Error: pubsub broker is not reachable".to_string())
+        };
+
+        let mut missing = self
+            .subscriptions
+            .iter()
+            .filter(|(_, sub_id)| !self.pubsub.is_streaming(sub_id))
+            .map(|(topic, _)| topic.as_str())
+            .collect::<Vec<_>>();
+        missing.sort_unstable();
+        let subscriptions = if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Error: not subscribed {}", missing.join(", ")))
+        };
+
+        Readiness {
+            checks: vec![
+                Check::new("pubsub", pubsub),
+                Check::new("subscriptions", subscriptions),
+                Check::new("record_directory", self.recording.check_writable()),
+            ],
+        }
+    }
+
+    pub fn recording(&self) -> &Recording {
+        &self.recording
     }
 
     pub fn collect_timestamps(&self, topic:&str, start_time: u64, end_time: u64) -> Vec<u64> {
-        let timestamps = self.collect_all_timestamps(topic);
-        timestamps.into_iter().filter(|&t| start_time <= t && t <= end_time).collect::<Vec<_>>()
+        self.recording.collect_timestamps(topic, start_time, end_time)
     }
 
 
     pub fn collect_all_timestamps(&self, topic: &str) -> Vec<u64> {
-        let mut timestamps= Vec::new();
-        let path = Self::topic_to_path(&self.record_root, topic);
-        let res = std::fs::read_dir(path);
-        if let Ok(readdir) = res {
-            for entry in readdir {
-                let _ = entry.map(|entry| {
-                    entry.path().file_name().map(|file_name| {
-                        file_name.to_str().map(|file_name| {
-                            file_name.parse::<u64>().ok().map(|num | {
-                                timestamps.push(num);
-                            })
-                        })
-                    })
-                });
-            }
-        }
-
-        timestamps.sort();
-        timestamps
+        self.recording.collect_all_timestamps(topic)
     }
     
-    pub fn enable_record(&self, enable: bool) {
-        *self.record.write().unwrap() = enable;
+    // Topic of the schemas, subscribed at startup
+    pub fn has_topic(&self, topic: &str) -> bool {
+        self.live.contains_key(topic)
+    }
+
+    // Every new message of the topic from now on
+    pub fn subscribe(&self, topic: &str) -> Option<broadcast::Receiver<Arc<PubsubMessage>>> {
+        self.live.get(topic).map(|sender| sender.subscribe())
+    }
+
+    // publishes on the connection of the subscriptions
+    pub fn publisher(&self) -> pubsub_service::Client {
+        self.pubsub.handle()
+    }
+
+    pub fn record_control(&self) -> Arc<RecordControl> {
+        self.record.clone()
     }
 
     fn fetch(&self, topic: &str, timestamp: Option<u64>) -> Option<PubsubMessage>{
         let data = match timestamp {
             None => {
-                println!("live topic: {}", topic);
-                let values = self.messages.read().unwrap();
+                tracing::trace!("live topic: {}", topic);
+                let values = self.messages.read().ok()?;
                 values.get(topic).and_then(|data| {
                     if let Some(message) = data {
                         Some(message.as_ref().clone())
@@ -164,21 +321,7 @@ impl PubsubMessageProvider {
             }
             Some(timestamp) => {
                 //println!("file topic: {}", topic);
-                let mut path = Self::topic_to_path(&self.record_root, topic);
-                path.push(std::path::Path::new(&timestamp.to_string()));
-                
-                match std::fs::File::open(path) {
-                    Ok(mut file) => {
-                        let mut buf = Vec::new();
-                        let res = file.read_to_end(&mut buf);
-                        res.ok().and_then(|_| {
-                            let buf = std::io::Cursor::new(buf);
-                            let res: Result<PubsubMessage, _> = prost::Message::decode(buf);
-                            res.ok()
-                        })
-                    }
-                    Err(_e) => None,
-                }
+                self.recording.read(topic, timestamp)
             }
         };
 
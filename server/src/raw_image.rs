@@ -0,0 +1,148 @@
+use image::{DynamicImage, GrayImage, RgbImage};
+
+use crate::api::proto::primitives::{Image, ImageEncoding};
+
+pub fn is_raw(image: &Image) -> bool {
+    image.get_encoding() != ImageEncoding::ENCODED
+}
+
+// Convert a raw pixel buffer to a displayable image
+pub fn decode(image: &Image) -> Result<DynamicImage, String> {
+    let encoding = image.get_encoding();
+    let (width, height) = (image.width as usize, image.height as usize);
+    let bytes_per_pixel = match encoding {
+        ImageEncoding::ENCODED => return Err("Error: not a raw image".into()),
+        ImageEncoding::RGB8 | ImageEncoding::BGR8 => 3,
+        ImageEncoding::MONO8 => 1,
+        ImageEncoding::MONO16 | ImageEncoding::DEPTH16 => 2,
+    };
+
+    if width == 0 || height == 0 {
+        return Err(format!("Error: raw image size {}x{}", width, height));
+    }
+
+    let too_small = || {
+        format!(
+            "Error: raw image buffer is too small {:?} {}x{} stride {} len {}",
+            encoding, width, height, image.stride, image.data.len()
+        )
+    };
+    let row_bytes = width.checked_mul(bytes_per_pixel).ok_or_else(too_small)?;
+    let stride = if image.stride == 0 { row_bytes } else { image.stride as usize };
+    // checked, width/height/stride come from the client
+    let needed = stride.checked_mul(height - 1).and_then(|n| n.checked_add(row_bytes)).ok_or_else(too_small)?;
+    if stride < row_bytes || image.data.len() < needed {
+        return Err(too_small());
+    }
+
+    // rows without stride padding
+    let rows = image.data.chunks(stride).take(height).map(|row| &row[..row_bytes]);
+
+    match encoding {
+        ImageEncoding::RGB8 => {
+            let buf = rows.flatten().copied().collect::<Vec<u8>>();
+            RgbImage::from_raw(width as u32, height as u32, buf).map(DynamicImage::ImageRgb8)
+        }
+        ImageEncoding::BGR8 => {
+            let buf = rows
+                .flat_map(|row| row.chunks(3).flat_map(|bgr| vec![bgr[2], bgr[1], bgr[0]]))
+                .collect::<Vec<u8>>();
+            RgbImage::from_raw(width as u32, height as u32, buf).map(DynamicImage::ImageRgb8)
+        }
+        ImageEncoding::MONO8 => {
+            let buf = rows.flatten().copied().collect::<Vec<u8>>();
+            GrayImage::from_raw(width as u32, height as u32, buf).map(DynamicImage::ImageLuma8)
+        }
+        ImageEncoding::MONO16 => {
+            // keep the upper 8 bits
+            let buf = rows
+                .flat_map(|row| row.chunks(2).map(|px| u16::from_le_bytes([px[0], px[1]]) >> 8))
+                .map(|v| v as u8)
+                .collect::<Vec<u8>>();
+            GrayImage::from_raw(width as u32, height as u32, buf).map(DynamicImage::ImageLuma8)
+        }
+        ImageEncoding::DEPTH16 => {
+            let depth = rows
+                .flat_map(|row| row.chunks(2).map(|px| u16::from_le_bytes([px[0], px[1]])))
+                .collect::<Vec<u16>>();
+            RgbImage::from_raw(width as u32, height as u32, colorize_depth(&depth)).map(DynamicImage::ImageRgb8)
+        }
+        ImageEncoding::ENCODED => None,
+    }
+    .ok_or_else(|| format!("Error: raw image conversion {:?}", encoding))
+}
+
+// Jet colormap over the valid depth range, invalid depth (0) is black
+fn colorize_depth(depth: &[u16]) -> Vec<u8> {
+    let valid = depth.iter().copied().filter(|&d| d != 0);
+    let min = valid.clone().min().unwrap_or(0) as f32;
+    let max = valid.max().unwrap_or(0) as f32;
+    let range = (max - min).max(1.0);
+
+    depth
+        .iter()
+        .flat_map(|&d| {
+            if d == 0 {
+                return [0, 0, 0];
+            }
+            jet((d as f32 - min) / range)
+        })
+        .collect()
+}
+
+// t: 0.0 (near, blue) - 1.0 (far, red)
+fn jet(t: f32) -> [u8; 3] {
+    let channel = |offset: f32| {
+        let v = 1.5 - (4.0 * t - offset).abs();
+        (v.clamp(0.0, 1.0) * 255.0) as u8
+    };
+    [channel(3.0), channel(2.0), channel(1.0)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(encoding: ImageEncoding, width: u32, height: u32, stride: u32, data: Vec<u8>) -> Image {
+        let mut image = Image::new();
+        image.set_encoding(encoding);
+        image.set_width(width);
+        image.set_height(height);
+        image.set_stride(stride);
+        image.set_data(data);
+        image
+    }
+
+    #[test]
+    fn bgr8_test() {
+        // 2x1 with 2 bytes row padding
+        let image = raw(ImageEncoding::BGR8, 2, 1, 8, vec![1, 2, 3, 4, 5, 6, 0, 0]);
+        let rgb = decode(&image).unwrap().to_rgb8();
+        assert_eq!(rgb.get_pixel(0, 0).0, [3, 2, 1]);
+        assert_eq!(rgb.get_pixel(1, 0).0, [6, 5, 4]);
+    }
+
+    #[test]
+    fn depth16_test() {
+        let data = [0u16, 1000, 2000].iter().flat_map(|d| d.to_le_bytes().to_vec()).collect();
+        let image = raw(ImageEncoding::DEPTH16, 3, 1, 0, data);
+        let rgb = decode(&image).unwrap().to_rgb8();
+        assert_eq!(rgb.get_pixel(0, 0).0, [0, 0, 0]);
+        assert_eq!(rgb.get_pixel(1, 0).0, jet(0.0));
+        assert_eq!(rgb.get_pixel(2, 0).0, jet(1.0));
+    }
+
+    #[test]
+    fn short_buffer_test() {
+        let image = raw(ImageEncoding::RGB8, 2, 2, 0, vec![0; 11]);
+        assert!(decode(&image).is_err());
+    }
+
+    #[test]
+    fn overflow_test() {
+        let image = raw(ImageEncoding::RGB8, u32::MAX, u32::MAX, 0, vec![0; 12]);
+        assert!(decode(&image).unwrap_err().contains("too small"));
+        let image = raw(ImageEncoding::MONO16, u32::MAX, u32::MAX, u32::MAX, vec![0; 12]);
+        assert!(decode(&image).unwrap_err().contains("too small"));
+    }
+}
@@ -0,0 +1,113 @@
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::sync::{PoisonError, RwLock};
+use tokio::sync::broadcast;
+
+use crate::session::now_millis;
+
+// Changes kept for GET /record
+const HISTORY_SIZE: usize = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordState {
+    pub enabled: bool,
+    // user, session id and peer, see changed_by(); "server" at startup
+    pub changed_by: String,
+    // msec since the epoch
+    pub changed_at: u64,
+}
+
+impl RecordState {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "enabled": self.enabled,
+            "changed_by": self.changed_by,
+            "changed_at": self.changed_at,
+        })
+    }
+}
+
+// Who changed the record state: the session id is "http" for the HTTP API
+pub fn changed_by(user: &str, session_id: &str, peer: &str) -> String {
+    format!("{} {} {}", user, session_id, peer)
+}
+
+// Server-wide recording switch: every change is logged and sent to the subscribers
+pub struct RecordControl {
+    state: RwLock<RecordState>,
+    history: RwLock<VecDeque<RecordState>>,
+    changes: broadcast::Sender<RecordState>,
+}
+
+impl RecordControl {
+    pub fn new(enabled: bool) -> Self {
+        let state = RecordState {
+            enabled,
+            changed_by: "server".into(),
+            changed_at: now_millis(),
+        };
+        let (changes, _) = broadcast::channel(16);
+        Self {
+            history: RwLock::new(vec![state.clone()].into()),
+            state: RwLock::new(state),
+            changes,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.state.read().unwrap_or_else(PoisonError::into_inner).enabled
+    }
+
+    pub fn state(&self) -> RecordState {
+        self.state.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    // oldest first
+    pub fn history(&self) -> Vec<RecordState> {
+        self.history.read().unwrap_or_else(PoisonError::into_inner).iter().cloned().collect()
+    }
+
+    pub fn set(&self, enabled: bool, changed_by: &str) -> RecordState {
+        let state = RecordState {
+            enabled,
+            changed_by: changed_by.to_string(),
+            changed_at: now_millis(),
+        };
+        tracing::info!("Record: {} by {}", enabled, changed_by);
+
+        *self.state.write().unwrap_or_else(PoisonError::into_inner) = state.clone();
+        let mut history = self.history.write().unwrap_or_else(PoisonError::into_inner);
+        if history.len() == HISTORY_SIZE {
+            history.pop_front();
+        }
+        history.push_back(state.clone());
+
+        // no subscriber is not an error
+        let _ = self.changes.send(state.clone());
+        state
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<RecordState> {
+        self.changes.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_test() {
+        let control = RecordControl::new(true);
+        let mut changes = control.subscribe();
+
+        control.set(false, &changed_by("alice", "session-a", "127.0.0.1:5000"));
+        assert!(!control.is_enabled());
+        assert_eq!(control.state().changed_by, "alice session-a 127.0.0.1:5000");
+        assert_eq!(changes.try_recv().unwrap().changed_by, "alice session-a 127.0.0.1:5000");
+
+        let history = control.history();
+        assert_eq!(history.iter().map(|state| state.enabled).collect::<Vec<_>>(), [true, false]);
+        assert_eq!(history[0].changed_by, "server");
+    }
+}
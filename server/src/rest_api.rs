@@ -0,0 +1,301 @@
+use actix_web::{web, Error, HttpMessage, HttpRequest, HttpResponse};
+use pubsub::proto::pubsub::PubsubMessage;
+use pubsub::pubsub_service;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use tracing::debug;
+
+use crate::auth::Role;
+use crate::error::Error as RequestError;
+use crate::session::now_millis;
+use crate::{record_control, validate, AppState, ImageOptions, ImageService, TopicReply};
+
+// Body of POST /topics/{topic}/publish, a few full HD frames
+const MAX_PUBLISH_SIZE: usize = 16 * 1024 * 1024;
+// Query params which must be numbers when given, rather than falling back to the default
+const NUMERIC_PARAMS: [&str; 6] = ["timestamp", "start_time", "end_time", "scale", "scale_x", "scale_y"];
+// Query params which are booleans, any other param stays a string
+const BOOLEAN_PARAMS: [&str; 1] = ["overlay"];
+
+// Plain HTTP versions of the websocket queries, for scripts
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/topics/{topic:.*}/publish")
+            .app_data(web::PayloadConfig::new(MAX_PUBLISH_SIZE))
+            .route(web::post().to(publish)),
+    );
+    cfg.route("/api/topics", web::get().to(topics))
+        .route("/api/recordings", web::get().to(recordings))
+        .route("/api/timestamps/{topic:.*}", web::get().to(timestamps))
+        .route("/api/messages/{topic:.*}", web::get().to(message))
+        .route("/api/images/{topic:.*}", web::get().to(image))
+        .route("/api/record", web::get().to(record_state))
+        .route("/api/record", web::post().to(set_record));
+}
+
+// Query string as websocket request params: known numbers and booleans are parsed, so the same validation applies
+pub fn query_params(query: &HashMap<String, String>) -> Result<Value, RequestError> {
+    let params = query
+        .iter()
+        .map(|(name, value)| {
+            let name = name.as_str();
+            let value = if NUMERIC_PARAMS.contains(&name) {
+                value
+                    .parse::<u64>()
+                    .map(|number| json!(number))
+                    .or_else(|_| value.parse::<f64>().map(|number| json!(number)))
+                    .map_err(|_| RequestError::InvalidRequest(format!("Error: {} must be a number, not {:?}", name, value)))?
+            } else if BOOLEAN_PARAMS.contains(&name) {
+                value
+                    .parse::<bool>()
+                    .map(|flag| json!(flag))
+                    .map_err(|_| RequestError::InvalidRequest(format!("Error: {} must be true or false, not {:?}", name, value)))?
+            } else {
+                json!(value)
+            };
+            Ok((name.to_owned(), value))
+        })
+        .collect::<Result<serde_json::Map<_, _>, RequestError>>()?;
+    Ok(Value::Object(params))
+}
+
+// {topic:.*} of the route, topics start with '/'
+fn path_topic(req: &HttpRequest) -> String {
+    format!("/{}", req.match_info().query("topic"))
+}
+
+fn known_topic(state: &AppState, topic: &str) -> Result<(), RequestError> {
+    if state.message_provider.read()?.has_topic(topic) {
+        Ok(())
+    } else {
+        Err(RequestError::NotFound(format!("Error: unknown topic {:?}", topic)))
+    }
+}
+
+// GET /api/topics: same as Topic/List
+async fn topics(req: HttpRequest, state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    state.auth.authorize(&req, Role::Viewer)?;
+    let topics = state
+        .schemas
+        .topics()
+        .into_iter()
+        .map(|(topic, type_name)| json!({ "topic": topic, "type": type_name }))
+        .collect::<Vec<_>>();
+    Ok(HttpResponse::Ok().json(json!({ "topics": topics })))
+}
+
+// GET /api/recordings: recorded message count and time range of each topic
+async fn recordings(req: HttpRequest, state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    state.auth.authorize(&req, Role::Viewer)?;
+    let recording = state.message_provider.read().map_err(RequestError::from)?.recording().clone();
+    let recordings = state
+        .schemas
+        .topics()
+        .into_iter()
+        .map(|(topic, _)| {
+            let timestamps = recording.collect_all_timestamps(&topic);
+            json!({
+                "topic": topic,
+                "count": timestamps.len(),
+                "start_time": timestamps.first(),
+                "end_time": timestamps.last(),
+            })
+        })
+        .collect::<Vec<_>>();
+    Ok(HttpResponse::Ok().json(json!({ "recordings": recordings })))
+}
+
+// GET /api/timestamps/{topic}?start_time=&end_time=
+async fn timestamps(
+    req: HttpRequest,
+    query: web::Query<HashMap<String, String>>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    state.auth.authorize(&req, Role::Viewer)?;
+    let topic = path_topic(&req);
+    known_topic(&state, &topic)?;
+    let (start_time, end_time) = validate::range(&query_params(&query)?)?;
+
+    let timestamps = state
+        .message_provider
+        .read()
+        .map_err(RequestError::from)?
+        .collect_timestamps(&topic, start_time, end_time);
+    validate::item_count(timestamps.len(), validate::MAX_ITEMS)?;
+    Ok(HttpResponse::Ok().json(json!({ "topic": topic, "timestamps": timestamps })))
+}
+
+// GET /api/messages/{topic}?timestamp=&format=json|raw, the latest message without timestamp
+async fn message(
+    req: HttpRequest,
+    query: web::Query<HashMap<String, String>>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    state.auth.authorize(&req, Role::Viewer)?;
+    let topic = path_topic(&req);
+    known_topic(&state, &topic)?;
+    let params = query_params(&query)?;
+    let timestamp = params["timestamp"].as_u64();
+
+    let message = state
+        .message_provider
+        .read()
+        .map_err(RequestError::from)?
+        .get(&topic, timestamp)
+        .ok_or_else(|| RequestError::NotFound(format!("Error: Not Found {} {:?}", topic, timestamp)))?;
+
+    match params["format"].as_str().unwrap_or("json") {
+        "json" => Ok(HttpResponse::Ok().json(TopicReply::message_json(&state.schemas, &topic, &message)?)),
+        "raw" => Ok(HttpResponse::Ok()
+            .content_type("application/octet-stream")
+            .insert_header(("X-Timestamp", message.timestamp.to_string()))
+            .insert_header(("X-Trace-Id", message.trace_id.clone()))
+            .body(message.data)),
+        format => Err(RequestError::InvalidRequest(format!("Error: format {:?}, json or raw", format)).into()),
+    }
+}
+
+// GET /api/images/{topic}?timestamp=&scale_x=&scale_y=&annotation=&overlay=true, same options as Image/Image
+async fn image(
+    req: HttpRequest,
+    query: web::Query<HashMap<String, String>>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    state.auth.authorize(&req, Role::Viewer)?;
+    let topic = path_topic(&req);
+    known_topic(&state, &topic)?;
+    let params = query_params(&query)?;
+    validate::scale(&params, "scale_x")?;
+    validate::scale(&params, "scale_y")?;
+    if let Some(annotation) = params["annotation"].as_str().filter(|annotation| !annotation.is_empty()) {
+        known_topic(&state, annotation)?;
+    }
+    let timestamp = params["timestamp"].as_u64();
+    let options = ImageOptions::from_params(&params);
+
+    let job = {
+        let message_provider = state.message_provider.clone();
+        let topic = topic.clone();
+        move || ImageService::render_frame(&message_provider, &topic, timestamp, &options)
+    };
+    let image = state
+        .image_workers
+        .run(job)
+        .await
+        .ok_or_else(|| RequestError::Internal("Error: image worker failed".into()))??;
+
+    Ok(HttpResponse::Ok().content_type(image.get_mime_type()).body(image.data))
+}
+
+// GET /api/record: same as GET /record
+async fn record_state(req: HttpRequest, state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    state.auth.authorize(&req, Role::Viewer)?;
+    let record = state.message_provider.read().map_err(RequestError::from)?.record_control();
+    Ok(HttpResponse::Ok().json(record.state().to_json()))
+}
+
+// POST /api/record { "enable": bool }: same as Command/Record
+async fn set_record(
+    req: HttpRequest,
+    body: web::Json<Value>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let identity = state.auth.authorize(&req, Role::Operator)?;
+    let enable = body["enable"]
+        .as_bool()
+        .ok_or_else(|| RequestError::InvalidRequest("Error: enable must be a boolean".into()))?;
+    let record = state.message_provider.read().map_err(RequestError::from)?.record_control();
+    let peer = req.peer_addr().map(|addr| addr.to_string()).unwrap_or_default();
+    let changed = record.set(enable, &record_control::changed_by(&identity.name, "http", &peer));
+    Ok(HttpResponse::Ok().json(changed.to_json()))
+}
+
+// POST /topics/{topic}/publish?timestamp=: the body is the message data,
+// or JSON of the topic schema with Content-Type application/json
+async fn publish(
+    req: HttpRequest,
+    query: web::Query<HashMap<String, String>>,
+    body: web::Bytes,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let identity = state.auth.authorize(&req, Role::Operator)?;
+    let topic = path_topic(&req);
+    known_topic(&state, &topic)?;
+    let timestamp = query_params(&query)?["timestamp"].as_u64().unwrap_or_else(now_millis);
+
+    let data = if req.content_type() == "application/json" {
+        let value = serde_json::from_slice::<Value>(&body)
+            .map_err(|err| RequestError::InvalidRequest(format!("Error: {}", err)))?;
+        state.schemas.encode(&topic, &value).map_err(RequestError::InvalidRequest)?
+    } else {
+        body.to_vec()
+    };
+
+    let message = PubsubMessage {
+        timestamp,
+        trace_id: pubsub_service::new_trace_id(),
+        data,
+    };
+    let response = json!({
+        "topic": topic,
+        "timestamp": timestamp,
+        "trace_id": message.trace_id,
+        "size": message.data.len(),
+    });
+    debug!(trace_id = %message.trace_id, user = %identity.name, "Publish {} {}", topic, timestamp);
+
+    let mut publisher = state.message_provider.read().map_err(RequestError::from)?.publisher();
+    publisher
+        .publish(&topic, message)
+        .await
+        .map_err(|status| actix_web::error::ErrorServiceUnavailable(format!("Error: publish {}", status.message())))?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_params_test() {
+        let query = [("start_time", "10"), ("scale_x", "0.5"), ("overlay", "true"), ("annotation", "/camera/boxes")]
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<HashMap<_, _>>();
+        let params = query_params(&query).unwrap();
+        assert_eq!(
+            params,
+            json!({ "start_time": 10, "scale_x": 0.5, "overlay": true, "annotation": "/camera/boxes" })
+        );
+        // end_time defaults to 0
+        assert_eq!(validate::range(&params).unwrap_err().code(), "invalid_request");
+        assert_eq!(validate::scale(&params, "scale_x"), Ok(0.5));
+
+        let options = ImageOptions::from_params(&params);
+        assert!(options.overlay);
+        assert_eq!(options.annotation.as_deref(), Some("/camera/boxes"));
+
+        // numbers which don't parse are rejected, not ignored
+        for (name, value) in [("timestamp", "abc"), ("start_time", "-1e"), ("scale_x", "")].iter() {
+            let query = [(name.to_string(), value.to_string())].iter().cloned().collect::<HashMap<_, _>>();
+            assert_eq!(query_params(&query).unwrap_err().code(), "invalid_request");
+        }
+        let query = [("format".to_string(), "raw".to_string())].iter().cloned().collect::<HashMap<_, _>>();
+        assert_eq!(query_params(&query).unwrap(), json!({ "format": "raw" }));
+        // string params stay strings, even if they look like numbers
+        let query = [("format", "1"), ("annotation", "1"), ("overlay", "false")]
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(query_params(&query).unwrap(), json!({ "format": "1", "annotation": "1", "overlay": false }));
+        let query = [("overlay".to_string(), "1".to_string())].iter().cloned().collect::<HashMap<_, _>>();
+        assert_eq!(query_params(&query).unwrap_err().code(), "invalid_request");
+    }
+
+    #[test]
+    fn publish_route_test() {
+        let mut path = actix_web::dev::Path::new("/topics/camera/image/publish");
+        assert!(actix_web::dev::ResourceDef::new("/topics/{topic:.*}/publish").capture_match_info(&mut path));
+        assert_eq!(path.get("topic"), Some("camera/image"));
+    }
+}
@@ -0,0 +1,520 @@
+use protobuf::descriptor::{
+    DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FieldDescriptorProto_Label,
+    FieldDescriptorProto_Type, FileDescriptorProto, FileDescriptorSet,
+};
+use protobuf::wire_format::WireType;
+use protobuf::{CodedInputStream, CodedOutputStream};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+// Nested messages, recursive schemas would otherwise overflow the stack on crafted data
+const MAX_DEPTH: usize = 64;
+
+// Topic -> protobuf message descriptor, for decoding any PubsubMessage.data without generated code.
+//
+// Descriptors come from the compiled-in protos and from FileDescriptorSet files
+// (`protoc --include_imports -o foo.pb foo.proto`), topics are mapped by topics.json.
+pub struct SchemaRegistry {
+    // fully qualified name: ".package.Message"
+    messages: HashMap<String, DescriptorProto>,
+    enums: HashMap<String, EnumDescriptorProto>,
+    // topic -> fully qualified message name
+    topics: HashMap<String, String>,
+}
+
+impl SchemaRegistry {
+    pub fn new() -> Self {
+        Self {
+            messages: HashMap::new(),
+            enums: HashMap::new(),
+            topics: HashMap::new(),
+        }
+    }
+
+    // Compiled-in schemas, and *.pb / topics.json in `dir` if it exists
+    pub fn load(dir: &str) -> Result<Self, String> {
+        let mut registry = Self::new();
+        registry.add_file(crate::api::proto::primitives::file_descriptor_proto());
+        registry.add_file(crate::service_status::file_descriptor_proto());
+
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => return Ok(registry),
+        };
+
+        let mut paths = entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect::<Vec<_>>();
+        paths.sort();
+        for path in paths.iter().filter(|path| path.extension().is_some_and(|ext| ext == "pb")) {
+            registry.load_descriptor_set(path)?;
+        }
+
+        let topics_path = std::path::Path::new(dir).join("topics.json");
+        if topics_path.exists() {
+            let topics = std::fs::read_to_string(&topics_path)
+                .map_err(|err| format!("Error: {:?} {}", topics_path, err))
+                .and_then(|text| serde_json::from_str::<Value>(&text).map_err(|err| format!("Error: {:?} {}", topics_path, err)))?;
+            for (topic, type_name) in topics.as_object().into_iter().flatten() {
+                let type_name = type_name
+                    .as_str()
+                    .ok_or_else(|| format!("Error: {:?} type of {} must be a string", topics_path, topic))?;
+                registry.register_topic(topic, type_name)?;
+            }
+        }
+
+        Ok(registry)
+    }
+
+    pub fn load_descriptor_set(&mut self, path: &std::path::Path) -> Result<(), String> {
+        let bytes = std::fs::read(path).map_err(|err| format!("Error: {:?} {}", path, err))?;
+        let set: FileDescriptorSet = protobuf::Message::parse_from_bytes(&bytes)
+            .map_err(|err| format!("Error: {:?} {}", path, err))?;
+        for file in set.get_file() {
+            self.add_file(file);
+        }
+        Ok(())
+    }
+
+    pub fn add_file(&mut self, file: &FileDescriptorProto) {
+        let scope = if file.get_package().is_empty() {
+            String::new()
+        } else {
+            format!(".{}", file.get_package())
+        };
+        for message in file.get_message_type() {
+            self.add_message(&scope, message);
+        }
+        for enum_type in file.get_enum_type() {
+            self.enums.insert(format!("{}.{}", scope, enum_type.get_name()), enum_type.clone());
+        }
+    }
+
+    fn add_message(&mut self, scope: &str, message: &DescriptorProto) {
+        let name = format!("{}.{}", scope, message.get_name());
+        for nested in message.get_nested_type() {
+            self.add_message(&name, nested);
+        }
+        for enum_type in message.get_enum_type() {
+            self.enums.insert(format!("{}.{}", name, enum_type.get_name()), enum_type.clone());
+        }
+        self.messages.insert(name, message.clone());
+    }
+
+    // type_name: "package.Message" or ".package.Message"
+    pub fn register_topic(&mut self, topic: &str, type_name: &str) -> Result<(), String> {
+        let type_name = Self::qualify(type_name);
+        if !self.messages.contains_key(&type_name) {
+            return Err(format!("Error: unknown message type {} for {}", type_name, topic));
+        }
+        self.topics.insert(topic.into(), type_name);
+        Ok(())
+    }
+
+    fn qualify(type_name: &str) -> String {
+        if type_name.starts_with('.') {
+            type_name.to_string()
+        } else {
+            format!(".{}", type_name)
+        }
+    }
+
+    // fully qualified message name without the leading '.'
+    pub fn message_type(&self, topic: &str) -> Option<&str> {
+        self.topics.get(topic).map(|type_name| type_name.trim_start_matches('.'))
+    }
+
+    pub fn topics(&self) -> Vec<(String, String)> {
+        let mut topics = self
+            .topics
+            .iter()
+            .map(|(topic, type_name)| (topic.clone(), type_name.trim_start_matches('.').to_string()))
+            .collect::<Vec<_>>();
+        topics.sort();
+        topics
+    }
+
+    pub fn decode(&self, topic: &str, data: &[u8]) -> Result<Value, String> {
+        let type_name = self
+            .topics
+            .get(topic)
+            .ok_or_else(|| format!("Error: no schema for {}", topic))?;
+        self.decode_message(type_name, data)
+    }
+
+    pub fn decode_message(&self, type_name: &str, data: &[u8]) -> Result<Value, String> {
+        let descriptor = self
+            .messages
+            .get(&Self::qualify(type_name))
+            .ok_or_else(|| format!("Error: unknown message type {}", type_name))?;
+        let mut is = CodedInputStream::from_bytes(data);
+        self.decode_fields(descriptor, &mut is, 0).map(Value::Object)
+    }
+
+    fn decode_fields(&self, descriptor: &DescriptorProto, is: &mut CodedInputStream, depth: usize) -> Result<Map<String, Value>, String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("Error: {} is nested deeper than {}", descriptor.get_name(), MAX_DEPTH));
+        }
+        let mut object = Map::new();
+
+        // defaults, as proto3 does not send them
+        for field in descriptor.get_field() {
+            let default = if field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED {
+                json!([])
+            } else {
+                self.default_value(field)
+            };
+            if !default.is_null() {
+                object.insert(field.get_name().into(), default);
+            }
+        }
+
+        while !is.eof().map_err(|err| err.to_string())? {
+            let (number, wire_type) = is.read_tag_unpack().map_err(|err| err.to_string())?;
+            let field = match descriptor.get_field().iter().find(|field| field.get_number() as u32 == number) {
+                Some(field) => field,
+                None => {
+                    // unknown field
+                    is.skip_field(wire_type).map_err(|err| err.to_string())?;
+                    continue;
+                }
+            };
+
+            let repeated = field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED;
+            let values = if repeated && wire_type == WireType::WireTypeLengthDelimited && Self::is_packable(field) {
+                // packed repeated scalars
+                let bytes = is.read_bytes().map_err(|err| err.to_string())?;
+                let mut packed = CodedInputStream::from_bytes(&bytes);
+                let mut values = Vec::new();
+                while !packed.eof().map_err(|err| err.to_string())? {
+                    values.push(self.decode_value(field, Self::scalar_wire_type(field), &mut packed, depth)?);
+                }
+                values
+            } else {
+                vec![self.decode_value(field, wire_type, is, depth)?]
+            };
+
+            if repeated {
+                if let Some(Value::Array(array)) = object.get_mut(field.get_name()) {
+                    array.extend(values);
+                }
+            } else if let Some(value) = values.into_iter().last() {
+                object.insert(field.get_name().into(), value);
+            }
+        }
+
+        Ok(object)
+    }
+
+    fn decode_value(
+        &self,
+        field: &FieldDescriptorProto,
+        wire_type: WireType,
+        is: &mut CodedInputStream,
+        depth: usize,
+    ) -> Result<Value, String> {
+        use FieldDescriptorProto_Type::*;
+
+        let expected = Self::scalar_wire_type(field);
+        if wire_type != expected {
+            return Err(format!("Error: field {} has wire type {:?}, expected {:?}", field.get_name(), wire_type, expected));
+        }
+
+        let value = match field.get_field_type() {
+            TYPE_DOUBLE => json!(f64::from_bits(is.read_fixed64().map_err(|err| err.to_string())?)),
+            TYPE_FLOAT => json!(f32::from_bits(is.read_fixed32().map_err(|err| err.to_string())?)),
+            TYPE_FIXED64 => json!(is.read_fixed64().map_err(|err| err.to_string())?),
+            TYPE_SFIXED64 => json!(is.read_fixed64().map_err(|err| err.to_string())? as i64),
+            TYPE_FIXED32 => json!(is.read_fixed32().map_err(|err| err.to_string())?),
+            TYPE_SFIXED32 => json!(is.read_fixed32().map_err(|err| err.to_string())? as i32),
+            _ if wire_type == WireType::WireTypeVarint => {
+                let varint = is.read_raw_varint64().map_err(|err| err.to_string())?;
+                match field.get_field_type() {
+                    TYPE_INT64 => json!(varint as i64),
+                    TYPE_UINT64 => json!(varint),
+                    TYPE_INT32 => json!(varint as i32),
+                    TYPE_UINT32 => json!(varint as u32),
+                    TYPE_BOOL => json!(varint != 0),
+                    // zigzag
+                    TYPE_SINT32 => json!(((varint as u32) >> 1) as i32 ^ -((varint & 1) as i32)),
+                    TYPE_SINT64 => json!((varint >> 1) as i64 ^ -((varint & 1) as i64)),
+                    // enum: name if known
+                    _ => self
+                        .enums
+                        .get(field.get_type_name())
+                        .and_then(|enum_type| enum_type.get_value().iter().find(|value| value.get_number() as i64 == varint as i32 as i64))
+                        .map(|value| json!(value.get_name()))
+                        .unwrap_or_else(|| json!(varint as i32)),
+                }
+            }
+            TYPE_STRING => json!(is.read_string().map_err(|err| err.to_string())?),
+            TYPE_BYTES => json!(base64::encode(is.read_bytes().map_err(|err| err.to_string())?)),
+            TYPE_MESSAGE => {
+                let bytes = is.read_bytes().map_err(|err| err.to_string())?;
+                match self.messages.get(field.get_type_name()) {
+                    Some(descriptor) => {
+                        let mut nested = CodedInputStream::from_bytes(&bytes);
+                        Value::Object(self.decode_fields(descriptor, &mut nested, depth + 1)?)
+                    }
+                    None => json!(base64::encode(bytes)),
+                }
+            }
+            TYPE_GROUP => return Err("Error: group is not supported".into()),
+            _ => return Err(format!("Error: unsupported type {:?}", field.get_field_type())),
+        };
+        Ok(value)
+    }
+
+    // JSON in the form of decode() to message bytes, e.g. for publishing from HTTP
+    pub fn encode(&self, topic: &str, value: &Value) -> Result<Vec<u8>, String> {
+        let type_name = self
+            .topics
+            .get(topic)
+            .ok_or_else(|| format!("Error: no schema for {}", topic))?;
+        self.encode_message(type_name, value)
+    }
+
+    pub fn encode_message(&self, type_name: &str, value: &Value) -> Result<Vec<u8>, String> {
+        let descriptor = self
+            .messages
+            .get(&Self::qualify(type_name))
+            .ok_or_else(|| format!("Error: unknown message type {}", type_name))?;
+        let object = value
+            .as_object()
+            .ok_or_else(|| format!("Error: {} must be a JSON object", type_name))?;
+
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            self.encode_fields(descriptor, object, &mut os, 0)?;
+            os.flush().map_err(|err| err.to_string())?;
+        }
+        Ok(bytes)
+    }
+
+    fn encode_fields(
+        &self,
+        descriptor: &DescriptorProto,
+        object: &Map<String, Value>,
+        os: &mut CodedOutputStream,
+        depth: usize,
+    ) -> Result<(), String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("Error: {} is nested deeper than {}", descriptor.get_name(), MAX_DEPTH));
+        }
+        for (name, value) in object {
+            let field = descriptor
+                .get_field()
+                .iter()
+                .find(|field| field.get_name() == name || field.get_json_name() == name)
+                .ok_or_else(|| format!("Error: unknown field {} of {}", name, descriptor.get_name()))?;
+
+            match value {
+                Value::Null => (),
+                // repeated fields are not packed, parsers accept both
+                Value::Array(values) if field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED => {
+                    for value in values {
+                        self.encode_value(field, value, os, depth)?;
+                    }
+                }
+                _ if field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED => {
+                    return Err(format!("Error: field {} must be an array", name));
+                }
+                _ => self.encode_value(field, value, os, depth)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_value(
+        &self,
+        field: &FieldDescriptorProto,
+        value: &Value,
+        os: &mut CodedOutputStream,
+        depth: usize,
+    ) -> Result<(), String> {
+        use FieldDescriptorProto_Type::*;
+
+        let number = field.get_number() as u32;
+        let invalid = || format!("Error: field {} can't be {:?} {}", field.get_name(), field.get_field_type(), value);
+        let float = || value.as_f64().ok_or_else(invalid);
+        let int = || value.as_i64().ok_or_else(invalid);
+        let uint = || value.as_u64().ok_or_else(invalid);
+        let int32 = || int().and_then(|int| i32::try_from(int).map_err(|_| invalid()));
+        let uint32 = || uint().and_then(|uint| u32::try_from(uint).map_err(|_| invalid()));
+
+        let res = match field.get_field_type() {
+            TYPE_DOUBLE => os.write_double(number, float()?),
+            TYPE_FLOAT => os.write_float(number, float()? as f32),
+            TYPE_INT64 => os.write_int64(number, int()?),
+            TYPE_UINT64 => os.write_uint64(number, uint()?),
+            TYPE_INT32 => os.write_int32(number, int32()?),
+            TYPE_UINT32 => os.write_uint32(number, uint32()?),
+            TYPE_SINT32 => os.write_sint32(number, int32()?),
+            TYPE_SINT64 => os.write_sint64(number, int()?),
+            TYPE_FIXED64 => os.write_fixed64(number, uint()?),
+            TYPE_FIXED32 => os.write_fixed32(number, uint32()?),
+            TYPE_SFIXED64 => os.write_sfixed64(number, int()?),
+            TYPE_SFIXED32 => os.write_sfixed32(number, int32()?),
+            TYPE_BOOL => os.write_bool(number, value.as_bool().ok_or_else(invalid)?),
+            TYPE_STRING => os.write_string(number, value.as_str().ok_or_else(invalid)?),
+            TYPE_BYTES => {
+                let bytes = value.as_str().and_then(|text| base64::decode(text).ok()).ok_or_else(invalid)?;
+                os.write_bytes(number, &bytes)
+            }
+            // enum: name or number
+            TYPE_ENUM => {
+                let number_of_name = value.as_str().and_then(|name| {
+                    self.enums
+                        .get(field.get_type_name())
+                        .and_then(|enum_type| enum_type.get_value().iter().find(|value| value.get_name() == name))
+                        .map(|value| value.get_number())
+                });
+                let value = match number_of_name {
+                    Some(value) => value,
+                    None => int32()?,
+                };
+                os.write_enum(number, value)
+            }
+            TYPE_MESSAGE => {
+                let descriptor = self
+                    .messages
+                    .get(field.get_type_name())
+                    .ok_or_else(|| format!("Error: unknown message type {}", field.get_type_name()))?;
+                let object = value.as_object().ok_or_else(invalid)?;
+                let mut bytes = Vec::new();
+                {
+                    let mut nested = CodedOutputStream::vec(&mut bytes);
+                    self.encode_fields(descriptor, object, &mut nested, depth + 1)?;
+                    nested.flush().map_err(|err| err.to_string())?;
+                }
+                os.write_bytes(number, &bytes)
+            }
+            TYPE_GROUP => return Err("Error: group is not supported".into()),
+        };
+        res.map_err(|err| err.to_string())
+    }
+
+    fn default_value(&self, field: &FieldDescriptorProto) -> Value {
+        use FieldDescriptorProto_Type::*;
+        match field.get_field_type() {
+            TYPE_DOUBLE | TYPE_FLOAT => json!(0.0),
+            TYPE_BOOL => json!(false),
+            TYPE_STRING | TYPE_BYTES => json!(""),
+            TYPE_ENUM => self
+                .enums
+                .get(field.get_type_name())
+                .and_then(|enum_type| enum_type.get_value().first())
+                .map(|value| json!(value.get_name()))
+                .unwrap_or_else(|| json!(0)),
+            TYPE_MESSAGE | TYPE_GROUP => Value::Null,
+            _ => json!(0),
+        }
+    }
+
+    fn is_packable(field: &FieldDescriptorProto) -> bool {
+        use FieldDescriptorProto_Type::*;
+        !matches!(field.get_field_type(), TYPE_STRING | TYPE_BYTES | TYPE_MESSAGE | TYPE_GROUP)
+    }
+
+    fn scalar_wire_type(field: &FieldDescriptorProto) -> WireType {
+        use FieldDescriptorProto_Type::*;
+        match field.get_field_type() {
+            TYPE_DOUBLE | TYPE_FIXED64 | TYPE_SFIXED64 => WireType::WireTypeFixed64,
+            TYPE_FLOAT | TYPE_FIXED32 | TYPE_SFIXED32 => WireType::WireTypeFixed32,
+            TYPE_STRING | TYPE_BYTES | TYPE_MESSAGE => WireType::WireTypeLengthDelimited,
+            TYPE_GROUP => WireType::WireTypeStartGroup,
+            _ => WireType::WireTypeVarint,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::Message as _;
+
+    #[test]
+    fn decode_test() {
+        let mut registry = SchemaRegistry::load("./not_exists").unwrap();
+        registry.register_topic("/status/status", "Status").unwrap();
+
+        let mut position = crate::service_status::Position32f::new();
+        position.set_x(1.0);
+        position.set_y(2.5);
+        let mut status = crate::service_status::Status::new();
+        status.set_timestamp(33);
+        status.set_debug("hello".into());
+        status.set_position(position);
+        let bytes = status.write_to_bytes().unwrap();
+
+        let value = registry.decode("/status/status", &bytes).unwrap();
+        assert_eq!(value["timestamp"], json!(33));
+        assert_eq!(value["debug"], json!("hello"));
+        assert_eq!(value["position"]["y"], json!(2.5));
+        assert_eq!(value["position"]["z"], json!(0.0));
+
+        assert!(registry.register_topic("/foo", "NotExists").is_err());
+        assert!(registry.decode("/foo", &bytes).is_err());
+    }
+
+    #[test]
+    fn encode_test() {
+        let mut registry = SchemaRegistry::load("./not_exists").unwrap();
+        registry.register_topic("/status/status", "Status").unwrap();
+
+        let bytes = registry
+            .encode("/status/status", &json!({ "timestamp": 33, "debug": "hello", "position": { "x": 1.0, "y": 2.5 } }))
+            .unwrap();
+        let status = crate::service_status::Status::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(status.get_timestamp(), 33);
+        assert_eq!(status.get_debug(), "hello");
+        assert_eq!(status.get_position().get_y(), 2.5);
+
+        // decode() output encodes to the same message
+        let value = registry.decode("/status/status", &bytes).unwrap();
+        assert_eq!(registry.decode("/status/status", &registry.encode("/status/status", &value).unwrap()).unwrap(), value);
+
+        assert!(registry.encode("/status/status", &json!({ "unknown": 1 })).is_err());
+        assert!(registry.encode("/status/status", &json!({ "debug": 1 })).is_err());
+        assert!(registry.encode("/status/status", &json!([])).is_err());
+        assert!(registry.encode("/foo", &json!({})).is_err());
+    }
+
+    #[test]
+    fn depth_test() {
+        // message Node { Node child = 1; int32 value = 2; }
+        let mut child = FieldDescriptorProto::new();
+        child.set_name("child".into());
+        child.set_number(1);
+        child.set_field_type(FieldDescriptorProto_Type::TYPE_MESSAGE);
+        child.set_type_name(".test.Node".into());
+        let mut value = FieldDescriptorProto::new();
+        value.set_name("value".into());
+        value.set_number(2);
+        value.set_field_type(FieldDescriptorProto_Type::TYPE_INT32);
+        let mut node = DescriptorProto::new();
+        node.set_name("Node".into());
+        node.set_field(vec![child, value].into());
+        let mut file = FileDescriptorProto::new();
+        file.set_package("test".into());
+        file.set_message_type(vec![node].into());
+
+        let mut registry = SchemaRegistry::new();
+        registry.add_file(&file);
+        registry.register_topic("/tree", "test.Node").unwrap();
+
+        let nested = |depth: usize| (0..depth).fold(json!({ "value": 1 }), |inner, _| json!({ "child": inner }));
+        let bytes = registry.encode("/tree", &nested(MAX_DEPTH)).unwrap();
+        assert!(registry.decode("/tree", &bytes).is_ok());
+        assert!(registry.encode("/tree", &nested(MAX_DEPTH + 1)).is_err());
+
+        // crafted data one level deeper than the limit: child = 1 around the valid message
+        let mut deeper = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut deeper);
+            os.write_bytes(1, &bytes).unwrap();
+            os.flush().unwrap();
+        }
+        assert!(registry.decode("/tree", &deeper).unwrap_err().contains("nested deeper"));
+    }
+}
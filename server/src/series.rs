@@ -0,0 +1,126 @@
+// Downsampling of numeric time series for charts
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bucket {
+    pub start_time: u64,
+    pub end_time: u64,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub count: u32,
+}
+
+// Split [start_time, end_time] into `count` equal buckets, empty buckets are skipped.
+// points must be sorted by timestamp.
+pub fn buckets(points: &[(u64, f64)], start_time: u64, end_time: u64, count: usize) -> Vec<Bucket> {
+    if count == 0 || end_time < start_time {
+        return Vec::new();
+    }
+
+    // saturates for the full u64 range, the last bucket is clamped to end_time anyway
+    let span = (end_time - start_time).saturating_add(1);
+    let width = span.div_ceil(count as u64).max(1);
+
+    let mut res: Vec<Bucket> = Vec::new();
+    for &(timestamp, value) in points.iter().filter(|(t, _)| start_time <= *t && *t <= end_time) {
+        let bucket_start = start_time + (timestamp - start_time) / width * width;
+        match res.last_mut() {
+            Some(bucket) if bucket.start_time == bucket_start => {
+                bucket.min = bucket.min.min(value);
+                bucket.max = bucket.max.max(value);
+                // running mean
+                bucket.count += 1;
+                bucket.mean += (value - bucket.mean) / bucket.count as f64;
+            }
+            _ => res.push(Bucket {
+                start_time: bucket_start,
+                end_time: bucket_start.saturating_add(width - 1).min(end_time),
+                min: value,
+                max: value,
+                mean: value,
+                count: 1,
+            }),
+        }
+    }
+    res
+}
+
+// Largest-Triangle-Three-Buckets, keeps the visual shape with `threshold` points.
+// points must be sorted by timestamp.
+pub fn lttb(points: &[(u64, f64)], threshold: usize) -> Vec<(u64, f64)> {
+    if threshold >= points.len() || threshold < 3 {
+        return points.to_vec();
+    }
+
+    let mut res = Vec::with_capacity(threshold);
+    // first and last points are always kept
+    let every = (points.len() - 2) as f64 / (threshold - 2) as f64;
+    let mut a = 0;
+    res.push(points[0]);
+
+    for i in 0..threshold - 2 {
+        // average of the next bucket
+        let next_start = ((i + 1) as f64 * every) as usize + 1;
+        let next_end = (((i + 2) as f64 * every) as usize + 1).min(points.len());
+        let next = &points[next_start..next_end];
+        let avg_x = next.iter().map(|p| p.0 as f64).sum::<f64>() / next.len() as f64;
+        let avg_y = next.iter().map(|p| p.1).sum::<f64>() / next.len() as f64;
+
+        // point of this bucket with the largest triangle
+        let start = (i as f64 * every) as usize + 1;
+        let end = next_start;
+        let (ax, ay) = (points[a].0 as f64, points[a].1);
+        let mut max_area = -1.0;
+        let mut max_index = start;
+        for (j, point) in points.iter().enumerate().take(end).skip(start) {
+            let area = ((ax - avg_x) * (point.1 - ay) - (ax - point.0 as f64) * (avg_y - ay)).abs();
+            if area > max_area {
+                max_area = area;
+                max_index = j;
+            }
+        }
+
+        res.push(points[max_index]);
+        a = max_index;
+    }
+
+    res.push(points[points.len() - 1]);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buckets_test() {
+        let points = (0..10).map(|t| (t, t as f64)).collect::<Vec<_>>();
+        let res = buckets(&points, 0, 9, 2);
+        assert_eq!(res.len(), 2);
+        assert_eq!((res[0].start_time, res[0].end_time), (0, 4));
+        assert_eq!((res[0].min, res[0].max, res[0].mean, res[0].count), (0.0, 4.0, 2.0, 5));
+        assert_eq!((res[1].min, res[1].max, res[1].mean, res[1].count), (5.0, 9.0, 7.0, 5));
+
+        // empty bucket is skipped
+        let res = buckets(&[(0, 1.0), (9, 2.0)], 0, 9, 5);
+        assert_eq!(res.len(), 2);
+
+        // no overflow at the end of the time range
+        let res = buckets(&[(0, 1.0), (u64::MAX, 2.0)], 0, u64::MAX, 2);
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[1].end_time, u64::MAX);
+        let res = buckets(&[(u64::MAX, 2.0)], u64::MAX, u64::MAX, 1);
+        assert_eq!((res[0].start_time, res[0].end_time), (u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn lttb_test() {
+        let points = (0..100).map(|t| (t, if t == 50 { 100.0 } else { 0.0 })).collect::<Vec<_>>();
+        let res = lttb(&points, 10);
+        assert_eq!(res.len(), 10);
+        assert_eq!(res[0], points[0]);
+        assert_eq!(res[9], points[99]);
+        // spike is kept
+        assert!(res.contains(&(50, 100.0)));
+    }
+}
@@ -0,0 +1,193 @@
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::auth::Identity;
+use crate::chunked_stream::ChunkedStream;
+
+// Ping the client this often
+pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+// Close the connection if nothing is heard from the client for this long
+pub const CLIENT_TIMEOUT: Duration = Duration::from_secs(15);
+// Keep the state of a disconnected session this long for the client to resume
+pub const RESUME_GRACE: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+pub struct SessionInfo {
+    pub id: String,
+    // remote address
+    pub peer: String,
+    pub services: Vec<String>,
+    // msec since the epoch
+    pub connected_at: u64,
+}
+
+impl SessionInfo {
+    pub fn new(id: String, peer: String, mut services: Vec<String>) -> Self {
+        services.sort();
+        Self {
+            id,
+            peer,
+            services,
+            connected_at: now_millis(),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "id": self.id,
+            "peer": self.peer,
+            "services": self.services,
+            "connected_at": self.connected_at,
+        })
+    }
+}
+
+// State of a disconnected session, waiting for the client to reconnect
+pub struct ParkedSession {
+    pub session_id: String,
+    // only the same user can resume
+    pub identity: Identity,
+    // responder name -> WebsocketResponder::suspend
+    pub states: Vec<(String, Value)>,
+    // unfinished range responses
+    pub streams: Vec<ChunkedStream>,
+    parked_at: Instant,
+}
+
+impl ParkedSession {
+    pub fn new(session_id: String, identity: Identity, states: Vec<(String, Value)>, streams: Vec<ChunkedStream>) -> Self {
+        Self {
+            session_id,
+            identity,
+            states,
+            streams,
+            parked_at: Instant::now(),
+        }
+    }
+}
+
+// Active websocket sessions
+#[derive(Default)]
+pub struct SessionRegistry {
+    sessions: RwLock<HashMap<String, SessionInfo>>,
+    // by resume token
+    parked: Mutex<HashMap<String, ParkedSession>>,
+}
+
+impl SessionRegistry {
+    pub fn insert(&self, info: SessionInfo) {
+        self.sessions.write().unwrap_or_else(PoisonError::into_inner).insert(info.id.clone(), info);
+    }
+
+    pub fn remove(&self, id: &str) {
+        self.sessions.write().unwrap_or_else(PoisonError::into_inner).remove(id);
+    }
+
+    pub fn park(&self, resume_token: String, session: ParkedSession) {
+        let mut parked = self.parked.lock().unwrap_or_else(PoisonError::into_inner);
+        parked.retain(|_, session| session.parked_at.elapsed() < RESUME_GRACE);
+        parked.insert(resume_token, session);
+    }
+
+    // Drop sessions past the grace period, with their unfinished streams. Returns how many.
+    pub fn purge_expired(&self) -> usize {
+        let mut parked = self.parked.lock().unwrap_or_else(PoisonError::into_inner);
+        let count = parked.len();
+        parked.retain(|_, session| session.parked_at.elapsed() < RESUME_GRACE);
+        count - parked.len()
+    }
+
+    // None if the token is unknown, the grace period is over or the session is someone else's,
+    // which stays parked for its owner
+    pub fn resume(&self, resume_token: &str, identity: &Identity) -> Option<ParkedSession> {
+        let mut parked = self.parked.lock().unwrap_or_else(PoisonError::into_inner);
+        parked.retain(|_, session| session.parked_at.elapsed() < RESUME_GRACE);
+        if parked.get(resume_token)?.identity != *identity {
+            tracing::warn!("Resume refused: {} is not the owner of the session", identity.name);
+            return None;
+        }
+        parked.remove(resume_token)
+    }
+
+    // oldest first
+    pub fn list(&self) -> Vec<SessionInfo> {
+        let mut sessions = self.sessions.read().unwrap_or_else(PoisonError::into_inner).values().cloned().collect::<Vec<_>>();
+        sessions.sort_by_key(|info| info.connected_at);
+        sessions
+    }
+}
+
+// Purge parked sessions even if no client connects or disconnects meanwhile
+pub async fn sweep(sessions: Arc<SessionRegistry>) {
+    let mut interval = tokio::time::interval(RESUME_GRACE);
+    loop {
+        interval.tick().await;
+        let purged = sessions.purge_expired();
+        if purged > 0 {
+            tracing::debug!("Purged {} parked sessions", purged);
+        }
+    }
+}
+
+pub fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::Role;
+
+    #[test]
+    fn registry_test() {
+        let sessions = SessionRegistry::default();
+        let mut first = SessionInfo::new("a".into(), "127.0.0.1:1".into(), vec!["Status".into(), "Image".into()]);
+        first.connected_at = 1;
+        sessions.insert(first);
+        sessions.insert(SessionInfo::new("b".into(), "127.0.0.1:2".into(), vec![]));
+
+        let list = sessions.list();
+        assert_eq!(list.iter().map(|info| info.id.as_str()).collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(list[0].services, ["Image", "Status"]);
+
+        sessions.remove("a");
+        assert_eq!(sessions.list().len(), 1);
+    }
+
+    #[test]
+    fn resume_test() {
+        let alice = Identity { name: "alice".into(), role: Role::Operator };
+        let bob = Identity { name: "bob".into(), role: Role::Operator };
+        let sessions = SessionRegistry::default();
+        let state = json!({ "live": false });
+        sessions.park("token".into(), ParkedSession::new("a".into(), alice.clone(), vec![("Status".into(), state.clone())], vec![]));
+
+        assert!(sessions.resume("other", &alice).is_none());
+        // someone else's token is refused, the owner can still resume
+        assert!(sessions.resume("token", &bob).is_none());
+        let parked = sessions.resume("token", &alice).unwrap();
+        assert_eq!(parked.session_id, "a");
+        assert_eq!(parked.states, [("Status".to_string(), state)]);
+        // only once
+        assert!(sessions.resume("token", &alice).is_none());
+
+        let mut expired = ParkedSession::new("b".into(), alice.clone(), vec![], vec![]);
+        expired.parked_at -= RESUME_GRACE;
+        sessions.park("expired".into(), expired);
+        assert!(sessions.resume("expired", &alice).is_none());
+    }
+
+    #[test]
+    fn purge_test() {
+        let alice = Identity { name: "alice".into(), role: Role::Operator };
+        let sessions = SessionRegistry::default();
+        sessions.park("token".into(), ParkedSession::new("a".into(), alice.clone(), vec![], vec![]));
+        assert_eq!(sessions.purge_expired(), 0);
+
+        sessions.parked.lock().unwrap().get_mut("token").unwrap().parked_at -= RESUME_GRACE;
+        assert_eq!(sessions.purge_expired(), 1);
+        assert!(sessions.parked.lock().unwrap().is_empty());
+    }
+}
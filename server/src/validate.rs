@@ -0,0 +1,143 @@
+use serde_json::Value;
+
+use crate::error::Error;
+
+// Image scale factors, 4x of a full HD frame is already 60 MB of pixels
+pub const MIN_SCALE: f64 = 0.01;
+pub const MAX_SCALE: f64 = 4.0;
+// Longest time range of one request (msec)
+pub const MAX_RANGE_SPAN: u64 = 24 * 60 * 60 * 1000;
+// Items of one range response
+pub const MAX_ITEMS: usize = 100_000;
+// Decoded images of one range response
+pub const MAX_IMAGES: usize = 1_000;
+pub const MAX_THUMBNAILS: u64 = 256;
+pub const MAX_THUMBNAIL_SIZE: u64 = 1024;
+pub const MAX_SERIES_POINTS: u64 = 10_000;
+// Live messages per second
+pub const MAX_RATE: f64 = 120.0;
+
+// Optional number in [min, max]
+pub fn number(params: &Value, name: &str, default: f64, min: f64, max: f64) -> Result<f64, Error> {
+    let value = match &params[name] {
+        Value::Null => return Ok(default),
+        value => value.as_f64().ok_or_else(|| Error::InvalidRequest(format!("Error: {} must be a number", name)))?,
+    };
+    if !(min..=max).contains(&value) {
+        return Err(Error::InvalidRequest(format!("Error: {} {} is out of range [{}, {}]", name, value, min, max)));
+    }
+    Ok(value)
+}
+
+// Optional count in [1, max]
+pub fn count(params: &Value, name: &str, default: u64, max: u64) -> Result<u64, Error> {
+    let value = match &params[name] {
+        Value::Null => return Ok(default),
+        value => value.as_u64().ok_or_else(|| Error::InvalidRequest(format!("Error: {} must be a positive integer", name)))?,
+    };
+    if value == 0 || value > max {
+        return Err(Error::InvalidRequest(format!("Error: {} {} is out of range [1, {}]", name, value, max)));
+    }
+    Ok(value)
+}
+
+pub fn scale(params: &Value, name: &str) -> Result<f64, Error> {
+    number(params, name, 1.0, MIN_SCALE, MAX_SCALE)
+}
+
+// start_time and end_time, at most MAX_RANGE_SPAN apart
+pub fn range(params: &Value) -> Result<(u64, u64), Error> {
+    let time = |name: &str| match &params[name] {
+        Value::Null => Ok(0),
+        value => value.as_u64().ok_or_else(|| Error::InvalidRequest(format!("Error: {} must be a positive integer", name))),
+    };
+    let (start_time, end_time) = (time("start_time")?, time("end_time")?);
+    check_range(start_time, end_time)?;
+    Ok((start_time, end_time))
+}
+
+pub fn check_range(start_time: u64, end_time: u64) -> Result<(), Error> {
+    if end_time < start_time {
+        return Err(Error::InvalidRequest(format!("Error: end_time {} is before start_time {}", end_time, start_time)));
+    }
+    if end_time - start_time > MAX_RANGE_SPAN {
+        return Err(Error::InvalidRequest(format!("Error: range {}..{} is longer than {} msec", start_time, end_time, MAX_RANGE_SPAN)));
+    }
+    Ok(())
+}
+
+// Items found in a range, before they are loaded
+pub fn item_count(count: usize, max: usize) -> Result<(), Error> {
+    if count > max {
+        return Err(Error::InvalidRequest(format!("Error: {} items in range, at most {} per request", count, max)));
+    }
+    Ok(())
+}
+
+// Topic which the server subscribes
+pub fn topic<'a, F>(params: &'a Value, name: &str, is_known: F) -> Result<&'a str, Error>
+where
+    F: Fn(&str) -> bool,
+{
+    let topic = params[name].as_str().unwrap_or("");
+    if !is_known(topic) {
+        return Err(Error::NotFound(format!("Error: unknown topic {:?}", topic)));
+    }
+    Ok(topic)
+}
+
+// One file or directory name, no way out of the parent directory
+pub fn path_component(name: &str) -> Result<&str, Error> {
+    let invalid = name.is_empty()
+        || name == "."
+        || name == ".."
+        || name.contains(['/', '\\', '\0'].as_ref());
+    if invalid {
+        return Err(Error::InvalidRequest(format!("Error: invalid name {:?}", name)));
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn number_test() {
+        let params = json!({ "scale_x": 0.5, "scale_y": 1000, "max_rate": "fast", "count": 0, "size": 64 });
+        assert_eq!(scale(&params, "scale_x"), Ok(0.5));
+        assert!(scale(&params, "scale_y").is_err());
+        assert_eq!(scale(&params, "missing"), Ok(1.0));
+        assert!(number(&params, "max_rate", 0.0, 0.0, MAX_RATE).is_err());
+
+        assert!(count(&params, "count", 16, MAX_THUMBNAILS).is_err());
+        assert_eq!(count(&params, "size", 160, MAX_THUMBNAIL_SIZE), Ok(64));
+        assert_eq!(count(&params, "missing", 160, MAX_THUMBNAIL_SIZE), Ok(160));
+    }
+
+    #[test]
+    fn range_test() {
+        assert_eq!(range(&json!({ "start_time": 10, "end_time": 20 })), Ok((10, 20)));
+        assert_eq!(range(&json!({})), Ok((0, 0)));
+        assert!(range(&json!({ "start_time": 20, "end_time": 10 })).is_err());
+        assert!(range(&json!({ "start_time": 0, "end_time": MAX_RANGE_SPAN + 1 })).is_err());
+        assert!(range(&json!({ "start_time": -1, "end_time": 10 })).is_err());
+
+        assert!(item_count(MAX_IMAGES, MAX_IMAGES).is_ok());
+        assert!(item_count(MAX_IMAGES + 1, MAX_IMAGES).is_err());
+    }
+
+    #[test]
+    fn name_test() {
+        let params = json!({ "topic": "/camera", "resource": "../../etc" });
+        assert_eq!(topic(&params, "topic", |topic| topic == "/camera"), Ok("/camera"));
+        assert!(topic(&params, "resource", |topic| topic == "/camera").is_err());
+        assert!(topic(&params, "missing", |topic| topic == "/camera").is_err());
+
+        assert_eq!(path_component("camera_1"), Ok("camera_1"));
+        for name in &["", ".", "..", "a/b", "..\\a", "a\0"] {
+            assert!(path_component(name).is_err(), "{:?}", name);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame, GenericImageView};
+
+use crate::pubsub_message_provider::Recording;
+use crate::{validate, ImageService};
+
+// frame duration for the last frame or a single frame
+const DEFAULT_FRAME_MSEC: u64 = 33;
+// GIF delays are 16 bit centiseconds, longer gaps are shown for this long
+const MAX_FRAME_MSEC: u64 = u16::MAX as u64 * 10;
+
+// Write recorded images of the sorted timestamps as an animated GIF.
+// Each frame is shown until the next recorded timestamp, so playback keeps the original timing.
+pub fn export_gif<W: std::io::Write>(
+    recording: &Recording,
+    topic: &str,
+    timestamps: &[u64],
+    scale: f64,
+    writer: W,
+) -> Result<usize, String> {
+    if timestamps.is_empty() {
+        return Err(format!("Error: No recorded images {}", topic));
+    }
+
+    // speed 10: fast color quantization, export should not take longer than the clip
+    let mut encoder = GifEncoder::new_with_speed(writer, 10);
+    encoder.set_repeat(Repeat::Infinite).map_err(|err| err.to_string())?;
+
+    let mut frames = 0;
+    for (i, &timestamp) in timestamps.iter().enumerate() {
+        let image = match recording
+            .read(topic, timestamp)
+            .ok_or_else(|| format!("Error: Not Found {} {}", topic, timestamp))
+            .and_then(|message| ImageService::decode_image(&message.data).map_err(|err| err.to_string()))
+        {
+            Ok(image) => image,
+            Err(err) => {
+                tracing::warn!("{}", err);
+                continue;
+            }
+        };
+
+        let width = ((scale * image.width() as f64) as u32).max(1);
+        let height = ((scale * image.height() as f64) as u32).max(1);
+        let image = image.resize(width, height, image::imageops::FilterType::Triangle);
+
+        let delay = Delay::from_numer_denom_ms(frame_msec(timestamp, timestamps.get(i + 1).copied()), 1);
+        encoder
+            .encode_frame(Frame::from_parts(image.to_rgba8(), 0, 0, delay))
+            .map_err(|err| err.to_string())?;
+        frames += 1;
+    }
+
+    Ok(frames)
+}
+
+// Time until the next frame
+fn frame_msec(timestamp: u64, next: Option<u64>) -> u32 {
+    let duration = next.map(|next| next.saturating_sub(timestamp)).unwrap_or(DEFAULT_FRAME_MSEC);
+    duration.min(MAX_FRAME_MSEC) as u32
+}
+
+// server export <topic> <start_time> <end_time> <output.gif> [scale]
+pub fn export_command(recording: &Recording, args: &[String]) -> Result<(), String> {
+    let usage = "Usage: server export <topic> <start_time> <end_time> <output.gif> [scale]";
+    if args.len() < 4 {
+        return Err(usage.into());
+    }
+
+    let topic = &args[0];
+    let start_time = args[1].parse::<u64>().map_err(|_| usage.to_string())?;
+    let end_time = args[2].parse::<u64>().map_err(|_| usage.to_string())?;
+    let output = &args[3];
+    let scale = match args.get(4) {
+        Some(scale) => scale.parse::<f64>().map_err(|_| usage.to_string())?,
+        None => 1.0,
+    };
+    // same limits as GET /export
+    validate::check_range(start_time, end_time).map_err(|err| err.to_string())?;
+    let scale = validate::scale(&serde_json::json!({ "scale": scale }), "scale").map_err(|err| err.to_string())?;
+
+    let timestamps = recording.collect_timestamps(topic, start_time, end_time);
+    if timestamps.is_empty() {
+        return Err(format!("Error: No recorded images {} [{}, {}]", topic, start_time, end_time));
+    }
+    validate::item_count(timestamps.len(), validate::MAX_IMAGES).map_err(|err| err.to_string())?;
+    let file = std::fs::File::create(output).map_err(|err| format!("Error: {} {}", output, err))?;
+    let frames = export_gif(recording, topic, &timestamps, scale, std::io::BufWriter::new(file))?;
+    tracing::info!("Export {} frames to {}", frames, output);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pubsub::proto::pubsub::PubsubMessage;
+    use prost::Message as _;
+
+    // <temp>/<uuid>/_camera/<timestamp> of 4x4 images
+    fn recording(timestamps: &[u64]) -> (Recording, std::path::PathBuf) {
+        let root = std::env::temp_dir().join(uuid::Uuid::new_v4().to_simple().to_string());
+        std::fs::create_dir_all(root.join("_camera")).unwrap();
+        let image = ImageService::encode_image(&image::DynamicImage::new_rgb8(4, 4)).unwrap();
+        for timestamp in timestamps {
+            let message = PubsubMessage {
+                timestamp: *timestamp,
+                data: protobuf::Message::write_to_bytes(&image).unwrap(),
+                ..Default::default()
+            };
+            let mut bytes = Vec::new();
+            message.encode(&mut bytes).unwrap();
+            std::fs::write(root.join("_camera").join(timestamp.to_string()), bytes).unwrap();
+        }
+        (Recording::new(root.to_str().unwrap()), root)
+    }
+
+    #[test]
+    fn frame_msec_test() {
+        assert_eq!(frame_msec(1000, Some(1040)), 40);
+        assert_eq!(frame_msec(1000, None), DEFAULT_FRAME_MSEC as u32);
+        assert_eq!(frame_msec(1000, Some(1000)), 0);
+        // a gap of days, or out of order timestamps
+        assert_eq!(frame_msec(0, Some(u64::MAX)), 655_350);
+        assert_eq!(frame_msec(1000, Some(10)), 0);
+    }
+
+    #[test]
+    fn export_test() {
+        let (recording, root) = recording(&[100]);
+        let mut bytes = Vec::new();
+        assert_eq!(export_gif(&recording, "/camera", &[100], 1.0, &mut bytes), Ok(1));
+        assert!(bytes.starts_with(b"GIF89a"));
+
+        // empty range, and a missing frame is skipped
+        assert!(export_gif(&recording, "/camera", &[], 1.0, Vec::new()).is_err());
+        assert_eq!(export_gif(&recording, "/camera", &[100, 200], 0.5, Vec::new()), Ok(1));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn export_command_test() {
+        let (recording, root) = recording(&[100]);
+        let output = root.join("out.gif").to_string_lossy().into_owned();
+        let args = |end_time: &str, scale: &str| {
+            ["/camera", "0", end_time, &output, scale].iter().map(|arg| arg.to_string()).collect::<Vec<_>>()
+        };
+        assert!(export_command(&recording, &args("1000", "100")).unwrap_err().contains("out of range"));
+        assert!(export_command(&recording, &args("100000000000", "1")).unwrap_err().contains("longer than"));
+        assert_eq!(export_command(&recording, &args("1000", "0.5")), Ok(()));
+        assert!(std::fs::read(&output).unwrap().starts_with(b"GIF89a"));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
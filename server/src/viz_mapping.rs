@@ -0,0 +1,262 @@
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::api::proto::{primitives, response};
+use crate::schema_registry::SchemaRegistry;
+
+// Topic fields to viz primitives, from config (schemas/visualizations.json)
+//
+// [
+//   {
+//     "path": "Status/Status",         // request / response path
+//     "topic": "/status/status",
+//     "subject": "/status/status",
+//     "timestamp": "timestamp",        // optional, PubsubMessage.timestamp if not set
+//     "point2d": { "x": "timestamp", "y": "position.y" },
+//     "text": { "text": "debug" }
+//   }
+// ]
+//
+// One primitive is sent as it is, several primitives are combined into `Status`.
+#[derive(Debug, Clone, PartialEq)]
+enum Primitive {
+    Text,
+    Point2d,
+    Point3d,
+    Image,
+}
+
+impl Primitive {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(Primitive::Text),
+            "point2d" => Some(Primitive::Point2d),
+            "point3d" => Some(Primitive::Point3d),
+            "image" => Some(Primitive::Image),
+            _ => None,
+        }
+    }
+
+    fn components(&self) -> &'static [&'static str] {
+        match self {
+            Primitive::Text => &["text"],
+            Primitive::Point2d => &["x", "y"],
+            Primitive::Point3d => &["x", "y", "z"],
+            Primitive::Image => &["data", "mime_type"],
+        }
+    }
+}
+
+pub struct VizMapping {
+    pub path: String,
+    pub topic: String,
+    pub subject: String,
+    timestamp: Option<String>,
+    // primitive -> component -> field path
+    primitives: Vec<(Primitive, HashMap<String, String>)>,
+}
+
+impl VizMapping {
+    fn parse(config: &Value, schemas: &SchemaRegistry) -> Result<Self, String> {
+        let string = |key: &str| config[key].as_str().map(|value| value.to_string());
+        let path = string("path").ok_or("Error: visualization needs \"path\"")?;
+        let topic = string("topic").ok_or_else(|| format!("Error: {} needs \"topic\"", path))?;
+        if path.split('/').count() != 2 {
+            return Err(format!("Error: {} must be <Scope>/<Name>", path));
+        }
+        if schemas.message_type(&topic).is_none() {
+            return Err(format!("Error: {} no schema for {}", path, topic));
+        }
+
+        let mut primitives = Vec::new();
+        for (name, fields) in config.as_object().into_iter().flatten() {
+            let primitive = match Primitive::parse(name) {
+                Some(primitive) => primitive,
+                None => continue,
+            };
+            let mut components = HashMap::new();
+            for &component in primitive.components() {
+                let field = fields[component]
+                    .as_str()
+                    .ok_or_else(|| format!("Error: {} {}.{} needs a field path", path, name, component))?;
+                components.insert(component.to_string(), field.to_string());
+            }
+            primitives.push((primitive, components));
+        }
+
+        if primitives.is_empty() {
+            return Err(format!("Error: {} has no primitive", path));
+        }
+        if primitives.len() > 1 && primitives.iter().any(|(primitive, _)| *primitive == Primitive::Image) {
+            return Err(format!("Error: {} image can't be combined with other primitives", path));
+        }
+
+        Ok(Self {
+            subject: string("subject").unwrap_or_else(|| topic.clone()),
+            timestamp: string("timestamp"),
+            path,
+            topic,
+            primitives,
+        })
+    }
+
+    // message: decoded topic message (SchemaRegistry::decode)
+    pub fn build_streamset(&self, message: &Value, default_timestamp: u64) -> Option<response::StreamSet> {
+        let timestamp = match &self.timestamp {
+            Some(field) => field_value(message, field)?.as_u64()?,
+            None => default_timestamp,
+        };
+
+        let mut streamset = response::StreamSet::new();
+        streamset.set_timestamp(timestamp);
+
+        let mut status = response::Status::new();
+        for (primitive, fields) in &self.primitives {
+            let number = |component: &str| -> Option<f32> {
+                field_value(message, &fields[component])?.as_f64().map(|value| value as f32)
+            };
+            let string = |component: &str| -> Option<String> {
+                match field_value(message, &fields[component])? {
+                    Value::String(value) => Some(value.clone()),
+                    Value::Null => None,
+                    value => Some(value.to_string()),
+                }
+            };
+
+            match primitive {
+                Primitive::Text => {
+                    let mut text = primitives::Text::new();
+                    text.set_text(string("text")?);
+                    if self.primitives.len() == 1 {
+                        streamset.set_text(text);
+                    } else {
+                        status.set_text(text);
+                    }
+                }
+                Primitive::Point2d => {
+                    let mut point2d = primitives::Point2d::new();
+                    point2d.set_x(number("x")?);
+                    point2d.set_y(number("y")?);
+                    if self.primitives.len() == 1 {
+                        streamset.set_point2d(point2d);
+                    } else {
+                        status.set_point2d(point2d);
+                    }
+                }
+                Primitive::Point3d => {
+                    let mut point3d = primitives::Point3d::new();
+                    point3d.set_x(number("x")?);
+                    point3d.set_y(number("y")?);
+                    point3d.set_z(number("z")?);
+                    if self.primitives.len() == 1 {
+                        streamset.set_point3d(point3d);
+                    } else {
+                        status.set_point3d(point3d);
+                    }
+                }
+                Primitive::Image => {
+                    // bytes are base64 in the decoded message
+                    let mut image = primitives::Image::new();
+                    image.set_data(base64::decode(string("data")?).ok()?);
+                    image.set_mime_type(string("mime_type")?);
+                    streamset.set_image(image);
+                }
+            }
+        }
+
+        if self.primitives.len() > 1 {
+            streamset.set_status(status);
+        }
+        Some(streamset)
+    }
+}
+
+// "position.y" -> message["position"]["y"]
+pub fn field_value<'a>(message: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(message, |value, key| value.get(key))
+}
+
+pub struct VizMappings {
+    mappings: HashMap<String, VizMapping>,
+}
+
+impl VizMappings {
+    pub fn load(path: &str, schemas: &SchemaRegistry) -> Result<Self, String> {
+        let mut mappings = HashMap::new();
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(_) => return Ok(Self { mappings }),
+        };
+
+        let config: Value = serde_json::from_str(&text).map_err(|err| format!("Error: {} {}", path, err))?;
+        for item in config.as_array().ok_or_else(|| format!("Error: {} must be an array", path))? {
+            let mapping = VizMapping::parse(item, schemas)?;
+            mappings.insert(mapping.path.clone(), mapping);
+        }
+        Ok(Self { mappings })
+    }
+
+    pub fn get(&self, path: &str) -> Option<&VizMapping> {
+        self.mappings.get(path)
+    }
+
+    // first part of the paths: "Status/Status" -> "Status"
+    pub fn scopes(&self) -> Vec<String> {
+        let mut scopes = self
+            .mappings
+            .keys()
+            .filter_map(|path| path.split('/').next().map(|scope| scope.to_string()))
+            .collect::<Vec<_>>();
+        scopes.sort();
+        scopes.dedup();
+        scopes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn build_streamset_test() {
+        let mut schemas = SchemaRegistry::load("./not_exists").unwrap();
+        schemas.register_topic("/status/status", "Status").unwrap();
+
+        let message = json!({
+            "timestamp": 66,
+            "position": { "x": 1.0, "y": 2.0, "z": 0.0 },
+            "debug": "hello",
+        });
+
+        let config = json!({
+            "path": "Status/Status",
+            "topic": "/status/status",
+            "timestamp": "timestamp",
+            "text": { "text": "debug" },
+            "point2d": { "x": "timestamp", "y": "position.y" },
+        });
+        let streamset = VizMapping::parse(&config, &schemas).unwrap().build_streamset(&message, 0).unwrap();
+        assert_eq!(streamset.get_timestamp(), 66);
+        assert_eq!(streamset.get_status().get_text().get_text(), "hello");
+        assert_eq!(streamset.get_status().get_point2d().get_x(), 66.0);
+        assert_eq!(streamset.get_status().get_point2d().get_y(), 2.0);
+
+        let config = json!({
+            "path": "Status/Debug",
+            "topic": "/status/status",
+            "text": { "text": "debug" },
+        });
+        let streamset = VizMapping::parse(&config, &schemas).unwrap().build_streamset(&message, 33).unwrap();
+        assert_eq!(streamset.get_timestamp(), 33);
+        assert_eq!(streamset.get_text().get_text(), "hello");
+
+        // missing field
+        let config = json!({
+            "path": "Status/Speed",
+            "topic": "/status/status",
+            "point2d": { "x": "timestamp", "y": "speed" },
+        });
+        assert!(VizMapping::parse(&config, &schemas).unwrap().build_streamset(&message, 0).is_none());
+    }
+}
@@ -1,6 +1,7 @@
 import os
 import sys
 import time
+import uuid
 
 
 sys.path.append(os.path.join(os.path.dirname(__file__), "../../pubsub/src/proto"))
@@ -30,16 +31,19 @@ if __name__ == "__main__":
         stub = pubsub_pb2_grpc.PubsubStub(channel)
 
         for i in range(0, 2332):
+            # one trace for the messages of this frame, in the server logs and websocket responses
+            trace_id = uuid.uuid4().hex
+
             status = status_pb2.Status()
             status.timestamp = i * 33
             status.debug = "status service debug seq_num {}".format(i)
             status.position.CopyFrom(status_pb2.Position32f(x=float(i), y=float(i), z=0.0))
 
             #message = pubsub_pb2.PubsubMessage(data="Hello From Status Service {}".format(i).encode('utf-8'))
-            message = pubsub_pb2.PubsubMessage(timestamp=i*33, data=status.SerializeToString())
+            message = pubsub_pb2.PubsubMessage(timestamp=i*33, data=status.SerializeToString(), trace_id=trace_id)
             request = pubsub_pb2.PublishRequest(topic='/status/status', message=message)
             res = stub.Publish(request)
-            print(status.timestamp)
+            print(status.timestamp, trace_id)
             print(res)
 
             # image
@@ -52,11 +56,19 @@ if __name__ == "__main__":
             image_pb = primitives_pb2.Image()
             image_pb.data = enc_image.tobytes()
             image_pb.mime_type = "image/jpeg"
-            message = pubsub_pb2.PubsubMessage(timestamp=i*33, data=image_pb.SerializeToString())
+            message = pubsub_pb2.PubsubMessage(timestamp=i*33, data=image_pb.SerializeToString(), trace_id=trace_id)
             request = pubsub_pb2.PublishRequest(topic='/status/image', message=message)
             res = stub.Publish(request)
             print(res)
 
+            # image annotations
+            annotations = primitives_pb2.ImageAnnotations()
+            bbox = annotations.boxes.add(x=float(i % 200), y=40.0, width=120.0, height=80.0, label="object {}".format(i))
+            bbox.color.CopyFrom(primitives_pb2.Color(r=255, g=0, b=0, a=255))
+            message = pubsub_pb2.PubsubMessage(timestamp=i*33, data=annotations.SerializeToString(), trace_id=trace_id)
+            request = pubsub_pb2.PublishRequest(topic='/status/image/annotations', message=message)
+            res = stub.Publish(request)
+
             #image = cv2.imdecode(enc_image, cv2.IMREAD_COLOR)
 
             time.sleep(0.033)
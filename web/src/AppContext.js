@@ -6,18 +6,23 @@ import { WebsocketEndpoint } from 'lib/websocket_endpoint.js';
 import { createRequest, protobufRoot } from 'lib/utils.js';
 
 
+// open the page with ?token=<token> when the server has config/auth.json
+const token = new URLSearchParams(window.location.search).get("token");
+
 const config = {
-    stateServiceURI: 'ws://127.0.0.1:4567/ws',
-    imageServiceURI: 'ws://127.0.0.1:4567/image_service',
+    // one connection for status and images, services are declared at handshake
+    // wss:// when the page is served over https (config/tls.json)
+    serviceURI: (window.location.protocol === 'https:' ? 'wss' : 'ws')
+        + '://127.0.0.1:4567/ws?services=Status,Command,Topic,Series,Image'
+        + (token ? `&token=${encodeURIComponent(token)}` : ''),
 };
 
 
 export const createAppContext = () => {
-    const dataProvider = new WebsocketEndpoint(config.stateServiceURI);
+    const dataProvider = new WebsocketEndpoint(config.serviceURI);
     dataProvider.connect();
 
-    const imageService = new WebsocketEndpoint(config.imageServiceURI);
-    imageService.connect();
+    const imageService = dataProvider;
 
     const imageDataProvider = new RemoteImageDataProvider(imageService);
 
@@ -40,7 +45,6 @@ export const createAppContext = () => {
     window.onbeforeunload = (event) => {
         console.log("onbeforeunload");
         dataProvider.close();
-        imageService.close();
     };
 
 
@@ -82,9 +86,10 @@ export const AppContextReducer = (state, action) => {
             var message = createRequest(protobufRoot.lookupType("RequestCommandRecord"), {enable: action.enable});
             state.commandExecuter.sendMessage(message);
             return Object.assign({}, state, {record: action.enable});    
+        case 'RECORD_STATE':
+            return Object.assign({}, state, {record: action.enable});
         case 'LIVE':
-            var message = createRequest(protobufRoot.lookupType("RequestStatusLive"), {enable: action.enable});
-            state.dataProvider.sendMessage(message);
+            // requests carry the mode, see RequestStatus
             return Object.assign({}, state, {live: action.enable});
     }
 
@@ -192,6 +192,7 @@ function App(props) {
                         let message = createRequest(RequestStatus, {
                             start_time: startTime,
                             end_time: endTime,
+                            mode: player.loader === liveLoader ? "live" : "replay",
                         });            
                         return message;
                     },
@@ -242,6 +243,15 @@ function App(props) {
             app.messageBrokers.any.push(vizdata);
         });
 
+        // recording is server-wide, follow changes by other clients
+        app.dataProvider.on("Command/RecordState", (data) => {
+            dispatch({type: "RECORD_STATE", enable: data.data.enabled});
+        });
+
+        app.dataProvider.on("Event", (event) => {
+            console.info("Server event", event.data);
+        });
+
     }, []);
 
     
@@ -8,6 +8,8 @@ export class WebsocketEndpoint {
         this.websocket = null;
         this.handlers = {};
         this.receiver = null;
+        // given by Session/Started, to continue the session after a reconnect
+        this.resumeToken = null;
     }
     
     close(){
@@ -16,7 +18,7 @@ export class WebsocketEndpoint {
 
     connect(){
         try {
-            this.websocket = new WebSocket(this.serverAddress);
+            this.websocket = new WebSocket(this.resumeAddress());
             this.websocket.binaryType = 'arraybuffer';
         } catch (error) {
             console.error(`WebSocket: Failed to establish: ${error.message}`);
@@ -40,6 +42,9 @@ export class WebsocketEndpoint {
                 if (typeof event.data === 'string'){
                     const data = JSON.parse(event.data);
                     const messageType = data['path'];
+                    if(messageType === 'Session/Started'){
+                        this.resumeToken = data.data.resume_token;
+                    }
                     if(this.handlers[messageType]){
                         for(const handler of this.handlers[messageType]){
                             handler(data);
@@ -74,11 +79,27 @@ export class WebsocketEndpoint {
                     if(this.receiver){
                         this.receiver(vizdata);
                     }
+
+                    // range responses and live frames come in chunks, ask for the next one once handled
+                    if(schema === "Stream" && !messageData.endOfStream){
+                        this.sendData("Stream/Credit", {
+                            stream_id: messageData.streamId,
+                            credits: 1,
+                        });
+                    }
                 }
             }
         };
     }
 
+    resumeAddress(){
+        if(!this.resumeToken){
+            return this.serverAddress;
+        }
+        const separator = this.serverAddress.includes('?') ? '&' : '?';
+        return `${this.serverAddress}${separator}resume=${this.resumeToken}`;
+    }
+
     on(eventName, handler) {
         if(eventName === '*'){
             this.receiver = handler;